@@ -0,0 +1,31 @@
+//! One benchmark group per registered puzzle with a cached input, comparing its named
+//! implementations part by part. The inputs are read from the data directory, run
+//! `aoc solve` first to fetch them.
+
+use aoc::SolutionsMap;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_solutions(c: &mut Criterion) {
+    let solutions = SolutionsMap::init();
+    let data_dir = aoc::data_dir();
+
+    for key in solutions.keys() {
+        let Ok(Some(input)) = aoc::cached_data(&data_dir, key.year, key.day) else {
+            continue;
+        };
+
+        let mut group = c.benchmark_group(format!("{}_day_{}", key.year, key.day));
+        for registration in solutions.implementations(key) {
+            let solver = registration.solver();
+            for &part in registration.parts {
+                group.bench_function(format!("{}/part_{part}", registration.name), |b| {
+                    b.iter(|| solver.solve(part, &input))
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_solutions);
+criterion_main!(benches);
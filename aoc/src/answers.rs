@@ -0,0 +1,246 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context};
+use toml::{Table, Value};
+
+use crate::{
+    data::cached_data,
+    solutions::{Answer, PuzzleKey, SolutionsMap},
+};
+
+/// Right answers of the puzzles of a year, kept in a TOML file with a table per day:
+///
+/// ```toml
+/// [day_1]
+/// part_1 = "54697"
+/// part_2 = "54885"
+/// ```
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<usize, BTreeMap<u8, String>>,
+}
+
+impl Answers {
+    pub fn path(data_dir: &Path, year: usize) -> PathBuf {
+        data_dir.join(format!("{year}/answers.toml"))
+    }
+
+    /// Loads the answers saved in `path`, none when the file does not exist. The answers
+    /// written by hand can be numbers as well as strings.
+    pub fn load<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut days = BTreeMap::new();
+
+        if path.exists() {
+            let content = fs::read_to_string(&path).context("could not read the answers file")?;
+            let table = content
+                .parse::<Table>()
+                .context("could not parse the answers file")?;
+            for (day_key, parts) in table {
+                let day = day_key
+                    .strip_prefix("day_")
+                    .and_then(|day| day.parse().ok())
+                    .ok_or_else(|| anyhow!("invalid day `{day_key}` in the answers"))?;
+                let parts = parts
+                    .as_table()
+                    .ok_or_else(|| anyhow!("the answers of `{day_key}` are not a table"))?;
+
+                let mut answers = BTreeMap::new();
+                for (part_key, answer) in parts {
+                    let part = part_key
+                        .strip_prefix("part_")
+                        .and_then(|part| part.parse().ok())
+                        .ok_or_else(|| anyhow!("invalid part `{part_key}` of `{day_key}`"))?;
+                    let answer = match answer {
+                        Value::String(answer) => answer.clone(),
+                        Value::Integer(answer) => answer.to_string(),
+                        _ => return Err(anyhow!("invalid answer of `{day_key}.{part_key}`")),
+                    };
+                    answers.insert(part, answer);
+                }
+                days.insert(day, answers);
+            }
+        }
+
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut content = String::new();
+        for (day, parts) in &self.days {
+            content += &format!("[day_{day}]\n");
+            for (part, answer) in parts {
+                content += &format!("part_{part} = {}\n", Value::from(answer.as_str()));
+            }
+            content += "\n";
+        }
+        fs::write(&self.path, content.trim_end().to_string() + "\n")
+            .context("could not save the answers")
+    }
+
+    pub fn get(&self, day: usize, part: u8) -> Option<&str> {
+        self.days.get(&day)?.get(&part).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: usize, part: u8, answer: &str) {
+        self.days
+            .entry(day)
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+}
+
+/// Answer of an implementation of a puzzle on its cached input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub name: &'static str,
+    pub part: u8,
+    /// the answer, or the error of the solution
+    pub answer: Result<Answer, String>,
+    /// the recorded answer, if any
+    pub expected: Option<String>,
+}
+
+impl Verification {
+    /// Whether the answer is the recorded one, `None` without a recorded answer. A
+    /// failing solution is never correct.
+    pub fn is_correct(&self) -> Option<bool> {
+        match (&self.answer, &self.expected) {
+            (Err(_), _) => Some(false),
+            (Ok(answer), Some(expected)) => Some(answer.to_string() == *expected),
+            (Ok(_), None) => None,
+        }
+    }
+}
+
+/// Runs every implementation of the puzzle on its cached input, to compare the answers
+/// with the recorded ones, both kept in `data_dir`. `None` when the input was not fetched.
+pub fn verify(
+    solutions: &SolutionsMap,
+    data_dir: &Path,
+    key: PuzzleKey,
+) -> anyhow::Result<Option<Vec<Verification>>> {
+    let Some(input) = cached_data(data_dir, key.year, key.day)? else {
+        return Ok(None);
+    };
+    let answers = Answers::load(Answers::path(data_dir, key.year))?;
+
+    let mut verifications = vec![];
+    for registration in solutions.implementations(key) {
+        let solver = registration.solver();
+        for &part in registration.parts {
+            verifications.push(Verification {
+                name: registration.name,
+                part,
+                answer: solver.solve(part, &input).map_err(|err| format!("{err:#}")),
+                expected: answers.get(key.day, part).map(str::to_string),
+            });
+        }
+    }
+    Ok(Some(verifications))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_file() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let mut answers = Answers::load(&path).unwrap();
+        answers.set(10, 1, "6812");
+        answers.set(2, 2, "a \"quoted\" answer");
+        answers.set(2, 1, "7");
+        answers.save().unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("[day_2]\npart_1 = \"7\"\n"));
+
+        fs::write(&path, content + "\n[day_3]\npart_1 = 4361\n").unwrap();
+        let answers = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(answers.get(10, 1), Some("6812"));
+        assert_eq!(answers.get(2, 2), Some("a \"quoted\" answer"));
+        assert_eq!(answers.get(3, 1), Some("4361"));
+        assert_eq!(answers.get(3, 2), None);
+        assert_eq!(answers.get(4, 1), None);
+    }
+
+    /// Checks every solution of `key` against the answer recorded in the data directory.
+    /// The input must be cached and the answers recorded, so that a passing test did
+    /// check them.
+    fn check_recorded_answers(key: PuzzleKey) {
+        let solutions = SolutionsMap::init();
+        let verifications = verify(&solutions, &crate::data_dir(), key)
+            .unwrap()
+            .unwrap_or_else(|| panic!("{key}: the input is not cached, run `aoc fetch` first"));
+
+        assert!(!verifications.is_empty(), "{key}: no part is solved");
+        for verification in verifications {
+            assert_eq!(
+                verification.is_correct(),
+                Some(true),
+                "{key} part {} ({}): expected {:?}, got {:?}",
+                verification.part,
+                verification.name,
+                verification.expected,
+                verification.answer
+            );
+        }
+    }
+
+    /// One test per puzzle checking its recorded answers, ignored by default as they need
+    /// the cached inputs: `cargo test -- --ignored` runs them.
+    macro_rules! recorded_answers_tests {
+        ($($name:ident => ($year:literal, $day:literal),)*) => {
+            const RECORDED_PUZZLES: &[PuzzleKey] = &[$(PuzzleKey::new($year, $day)),*];
+
+            $(
+                #[test]
+                #[ignore = "needs the cached input and the recorded answers"]
+                fn $name() {
+                    check_recorded_answers(PuzzleKey::new($year, $day));
+                }
+            )*
+        };
+    }
+
+    recorded_answers_tests! {
+        test_recorded_answers_2023_day_1 => (2023, 1),
+        test_recorded_answers_2023_day_2 => (2023, 2),
+        test_recorded_answers_2023_day_3 => (2023, 3),
+        test_recorded_answers_2023_day_4 => (2023, 4),
+        test_recorded_answers_2023_day_5 => (2023, 5),
+        test_recorded_answers_2023_day_6 => (2023, 6),
+        test_recorded_answers_2023_day_7 => (2023, 7),
+        test_recorded_answers_2023_day_8 => (2023, 8),
+        test_recorded_answers_2023_day_9 => (2023, 9),
+        test_recorded_answers_2023_day_10 => (2023, 10),
+        test_recorded_answers_2023_day_11 => (2023, 11),
+    }
+
+    #[test]
+    fn test_recorded_answers_cover_every_puzzle() {
+        let solutions = SolutionsMap::init();
+        let solved: Vec<_> = solutions
+            .keys()
+            .filter(|&key| {
+                solutions
+                    .implementations(key)
+                    .iter()
+                    .any(|registration| !registration.parts.is_empty())
+            })
+            .collect();
+
+        assert_eq!(solved, RECORDED_PUZZLES);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::solutions::{Answer, Solver};
+
+/// Times of the runs of a solution part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        Some(Self {
+            runs,
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / runs as u32,
+        })
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.3?}  median {:>10.3?}  mean {:>10.3?}  ({} runs)",
+            self.min, self.median, self.mean, self.runs
+        )
+    }
+}
+
+/// Solves `part` of the puzzle `runs` times on `input`, stopping at the first error.
+/// Returns the answer of the last run and the times.
+pub fn time_solution(
+    solver: &dyn Solver,
+    part: u8,
+    input: &str,
+    runs: usize,
+) -> anyhow::Result<(Answer, Timings)> {
+    let mut answer = None;
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        answer = Some(solver.solve(part, input)?);
+        samples.push(start.elapsed());
+    }
+
+    Ok((answer.unwrap(), Timings::from_samples(samples).unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timings_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+
+        assert_eq!(
+            Timings::from_samples(samples),
+            Some(Timings {
+                runs: 4,
+                min: Duration::from_millis(1),
+                median: Duration::from_micros(2500),
+                mean: Duration::from_micros(2500),
+            })
+        );
+        assert_eq!(Timings::from_samples(vec![]), None);
+    }
+
+    #[derive(Debug)]
+    struct Echo;
+
+    impl Solver for Echo {
+        fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+            Ok(input.lines().collect::<Vec<_>>().join(",").into())
+        }
+
+        fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+            Ok(input.lines().count().into())
+        }
+    }
+
+    #[test]
+    fn test_time_solution() {
+        let input = "a\nb\n";
+
+        let (answer, timings) = time_solution(&Echo, 1, input, 5).unwrap();
+        assert_eq!(answer, Answer::from("a,b"));
+        assert_eq!(timings.runs, 5);
+        assert!(timings.min <= timings.median);
+
+        let (answer, _) = time_solution(&Echo, 2, input, 0).unwrap();
+        assert_eq!(answer, Answer::Number(2));
+        assert!(time_solution(&Echo, 3, input, 5).is_err());
+    }
+}
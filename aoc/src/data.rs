@@ -1,24 +1,42 @@
 use std::{
-    fs::File,
-    io::{BufRead, BufReader, Read, Write},
-    path::Path,
+    env,
+    fs::{self, File},
+    io::{Read, Write},
+    path::{Path, PathBuf},
     time::Duration,
 };
 
 use anyhow::Context;
 use reqwest::header::{ACCEPT, COOKIE};
 
-const AOC_URL: &str = "https://adventofcode.com";
+pub const AOC_URL: &str = "https://adventofcode.com";
 const COPY_TO_MANY_BUFFER_SIZE: usize = 8192;
 
-pub fn get_data(session: &str, year: usize, day: usize) -> anyhow::Result<Vec<String>> {
-    let fname = format!("data/{year}/day_{day}.data");
-    let filename = Path::new(&fname);
-    if filename.exists() {
-        let file = File::open(filename).context("could not open file for reading data")?;
-        let data = BufReader::new(file)
-            .lines()
-            .collect::<Result<Vec<_>, _>>()?;
+/// Directory of the cached inputs and of the recorded answers and submissions:
+/// `AOC_DATA_DIR` when set, else `aoc` in the XDG cache directory.
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("AOC_DATA_DIR") {
+        return PathBuf::from(dir);
+    }
+    let cache_dir = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")));
+    match cache_dir {
+        Some(dir) => dir.join("aoc"),
+        None => PathBuf::from("data"),
+    }
+}
+
+fn input_path(data_dir: &Path, year: usize, day: usize) -> PathBuf {
+    data_dir.join(format!("{year}/day_{day}.data"))
+}
+
+/// The input of the puzzle, fetched from the site with the session and cached in
+/// `data_dir` the first time.
+pub fn get_data(data_dir: &Path, session: &str, year: usize, day: usize) -> anyhow::Result<String> {
+    let filename = input_path(data_dir, year, day);
+    if let Some(data) = cached_data(data_dir, year, day)? {
         Ok(data)
     } else {
         let client = reqwest::blocking::Client::new();
@@ -32,20 +50,30 @@ pub fn get_data(session: &str, year: usize, day: usize) -> anyhow::Result<Vec<St
             .error_for_status()
             .context("api reponded with an invalid status")?;
 
-        let mut file = File::create(filename)
+        if let Some(dir) = filename.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = File::create(&filename)
             .context("could not create the file to cache the data")
-            .context(fname)?;
+            .with_context(|| filename.display().to_string())?;
         let mut buffvec = vec![];
         copy_to_multiple_writers(res, &mut file, &mut buffvec)
             .context("could not cache the api data into the file")?;
 
-        let data = BufReader::new(buffvec.as_slice())
-            .lines()
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(data)
+        String::from_utf8(buffvec).context("the data is not valid utf-8")
     }
 }
 
+/// The input of the puzzle saved by `get_data`, if it was fetched already.
+pub fn cached_data(data_dir: &Path, year: usize, day: usize) -> anyhow::Result<Option<String>> {
+    let filename = input_path(data_dir, year, day);
+    if !filename.exists() {
+        return Ok(None);
+    }
+    let data = fs::read_to_string(filename).context("could not open file for reading data")?;
+    Ok(Some(data))
+}
+
 fn copy_to_multiple_writers<R, W1, W2>(
     mut reader: R,
     writer1: &mut W1,
@@ -68,15 +96,23 @@ where
     Ok(())
 }
 
-// pub fn send_response(session: &str, year: usize, day: usize) {
-//     let client = reqwest::blocking::Client::new();
-//     let res = client
-//         .get(format!("{AOC_URL}/{year}/day/{day}/input"))
-//         .timeout(Duration::from_secs(10))
-//         .header(ACCEPT, "text/html")
-//         .header(COOKIE, format!("session={session}"))
-//         .send()
-//         .context("could not send the request to get the data from the api")?
-//         .error_for_status()
-//         .context("api reponded with an invalid status")?;
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_data() {
+        let dir = std::env::temp_dir().join(format!("aoc-data-{}", std::process::id()));
+        assert_eq!(cached_data(&dir, 2023, 1).unwrap(), None);
+
+        fs::create_dir_all(dir.join("2023")).unwrap();
+        fs::write(dir.join("2023/day_1.data"), "1abc2\n").unwrap();
+        let data = cached_data(&dir, 2023, 1).unwrap();
+        // cached, so the session is not used
+        let fetched = get_data(&dir, "", 2023, 1).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(data.as_deref(), Some("1abc2\n"));
+        assert_eq!(fetched, "1abc2\n");
+    }
+}
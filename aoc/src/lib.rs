@@ -1,5 +1,13 @@
+mod answers;
+mod bench;
 mod data;
+mod scaffold;
 mod solutions;
+mod submit;
 
-pub use data::get_data;
-pub use solutions::SolutionsMap;
+pub use answers::{verify, Answers, Verification};
+pub use bench::{time_solution, Timings};
+pub use data::{cached_data, data_dir, get_data, AOC_URL};
+pub use scaffold::new_day;
+pub use solutions::{Answer, NotImplemented, PuzzleKey, Registration, SolutionsMap, Solver};
+pub use submit::{parse_reply, submit_answer, Submissions, SubmitOutcome, Verdict};
@@ -1,8 +1,13 @@
-use std::env;
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
 
-use anyhow::{anyhow, Context};
-use aoc::SolutionsMap;
-use clap::{Parser, ValueEnum};
+use anyhow::{anyhow, bail, Context};
+use aoc::{Answer, Answers, PuzzleKey, SolutionsMap, Submissions, SubmitOutcome, Verdict};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use dotenvy::dotenv;
 
 #[derive(Clone, Debug)]
@@ -11,6 +16,15 @@ enum SolutionPart {
     Part2,
 }
 
+impl SolutionPart {
+    fn level(&self) -> u8 {
+        match self {
+            SolutionPart::Part1 => 1,
+            SolutionPart::Part2 => 2,
+        }
+    }
+}
+
 impl ValueEnum for SolutionPart {
     fn value_variants<'a>() -> &'a [Self] {
         &[Self::Part1, Self::Part2]
@@ -25,7 +39,17 @@ impl ValueEnum for SolutionPart {
 }
 
 #[derive(Debug, Parser)]
-struct Args {
+struct Cli {
+    /// directory of the inputs, answers and submissions, `AOC_DATA_DIR` or
+    /// `~/.cache/aoc` by default
+    #[arg(long, global = true)]
+    data_dir: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Args)]
+struct Puzzle {
     #[arg(short, long)]
     year: usize,
     #[arg(short, long)]
@@ -34,25 +58,318 @@ struct Args {
     part: SolutionPart,
 }
 
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Prints the answer of a puzzle
+    Solve {
+        #[command(flatten)]
+        puzzle: Puzzle,
+        /// file to solve instead of the puzzle input, `-` to read it from stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Sends the answer of a puzzle, unless it is known to be wrong
+    Submit {
+        #[command(flatten)]
+        puzzle: Puzzle,
+        /// answer to send instead of the one of the solution
+        #[arg(short, long)]
+        answer: Option<String>,
+    },
+    /// Records the right answer of a puzzle, found without submitting it
+    Answer {
+        #[command(flatten)]
+        puzzle: Puzzle,
+        #[arg(short, long)]
+        answer: String,
+    },
+    /// Checks the solutions against the recorded answers on their cached inputs
+    Verify {
+        #[arg(short, long)]
+        year: Option<usize>,
+        #[arg(short, long)]
+        day: Option<usize>,
+    },
+    /// Creates the module of a new day and fetches its input
+    New {
+        #[arg(short, long)]
+        year: usize,
+        #[arg(short, long)]
+        day: usize,
+    },
+    /// Prints the puzzles with a solution and the parts they answer
+    List,
+    /// Times the solutions on their cached inputs
+    Bench {
+        #[arg(short, long)]
+        year: Option<usize>,
+        #[arg(short, long)]
+        day: Option<usize>,
+        /// runs of each part of each implementation
+        #[arg(short, long, default_value_t = 10)]
+        runs: usize,
+    },
+}
+
 fn main() -> anyhow::Result<()> {
-    dotenv()?;
-    let aoc_session = env::var("AOC_SESSION").expect("AOC_SESSION must be set");
-    let args = Args::parse();
+    let cli = Cli::parse();
+    let data_dir = cli.data_dir.unwrap_or_else(aoc::data_dir);
+
+    match cli.command {
+        Command::Solve { puzzle, input } => {
+            let ans = solve(&data_dir, &puzzle, input.as_deref())?;
+            println!("answer: {ans}");
+        }
+        Command::Submit { puzzle, answer } => {
+            let answer = match answer {
+                Some(answer) => answer,
+                None => solve(&data_dir, &puzzle, None)?.to_string(),
+            };
+            submit(&data_dir, &aoc_session()?, &puzzle, &answer)?;
+        }
+        Command::Answer { puzzle, answer } => {
+            record_answer(&data_dir, &puzzle, &answer)?;
+            println!("answer: {answer} recorded");
+        }
+        Command::Verify { year, day } => verify(&data_dir, year, day)?,
+        Command::New { year, day } => new_day(&data_dir, year, day)?,
+        Command::List => list(),
+        Command::Bench { year, day, runs } => bench(&data_dir, year, day, runs)?,
+    }
+
+    Ok(())
+}
+
+/// The session cookie to reach the site, from the environment or the `.env` file.
+fn aoc_session() -> anyhow::Result<String> {
+    if let Err(err) = dotenv() {
+        if !err.not_found() {
+            return Err(err).context("could not load the .env file");
+        }
+    }
+    env::var("AOC_SESSION").context("AOC_SESSION must be set to reach the site")
+}
+
+/// The input of the puzzle, from the cache or fetched when it is missing, the session
+/// being needed only then.
+fn puzzle_input(data_dir: &Path, year: usize, day: usize) -> anyhow::Result<String> {
+    match aoc::cached_data(data_dir, year, day)? {
+        Some(input) => Ok(input),
+        None => aoc::get_data(data_dir, &aoc_session()?, year, day),
+    }
+}
+
+/// Reads the file at `path`, or stdin for `-`.
+fn read_input(path: &Path) -> anyhow::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("could not read the input from stdin")?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))
+}
+
+/// Solves the puzzle on its input, or on the file at `input` when it is given.
+fn solve(data_dir: &Path, puzzle: &Puzzle, input: Option<&Path>) -> anyhow::Result<Answer> {
     let solutions = SolutionsMap::init();
 
-    let key = format!("{}_{}", args.year, args.day);
-    let solver = solutions
-        .get(&key)
+    let key = PuzzleKey::new(puzzle.year, puzzle.day);
+    let part = puzzle.part.level();
+    let registration = solutions
+        .get(key)
         .ok_or(anyhow!("the solution is not present"))?;
+    if !registration.has_part(part) {
+        bail!("the part {part} of {key} is not solved");
+    }
 
-    let data =
-        aoc::get_data(&aoc_session, args.year, args.day).context("failed to fetch the data")?;
-
-    let ans = match args.part {
-        SolutionPart::Part1 => solver.solve_part1(data),
-        SolutionPart::Part2 => solver.solve_part2(data),
+    let input = match input {
+        Some(path) => read_input(path)?,
+        None => {
+            puzzle_input(data_dir, puzzle.year, puzzle.day).context("failed to fetch the data")?
+        }
     };
 
-    println!("answer: {ans}");
+    registration
+        .solver()
+        .solve(part, &input)
+        .with_context(|| format!("could not solve the part {part} of {key}"))
+}
+
+fn new_day(data_dir: &Path, year: usize, day: usize) -> anyhow::Result<()> {
+    let solutions_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/solutions");
+    for path in aoc::new_day(&solutions_dir, year, day)? {
+        println!("wrote {}", path.display());
+    }
+
+    let input = puzzle_input(data_dir, year, day)
+        .context("the day was created but its input could not be fetched")?;
+    println!("fetched the input, {} lines", input.lines().count());
+    Ok(())
+}
+
+fn list() {
+    let solutions = SolutionsMap::init();
+
+    let mut year = None;
+    for registration in solutions.iter() {
+        let key = registration.key;
+        if year != Some(key.year) {
+            println!("{}", key.year);
+            year = Some(key.year);
+        }
+        let mut parts = match registration.parts {
+            [] => "no parts".to_string(),
+            parts => parts
+                .iter()
+                .map(|part| format!("part {part}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+        let implementations = solutions.implementations(key);
+        if implementations.len() > 1 {
+            let names = implementations.iter().map(|r| r.name).collect::<Vec<_>>();
+            parts += &format!(" ({})", names.join(", "));
+        }
+        println!("  day {:>2}: {parts}", key.day);
+    }
+}
+
+/// The puzzles with a solution, of `year` and `day` when they are given.
+fn select_keys(
+    solutions: &SolutionsMap,
+    year: Option<usize>,
+    day: Option<usize>,
+) -> anyhow::Result<Vec<PuzzleKey>> {
+    let keys = solutions
+        .keys()
+        .filter(|key| year.is_none_or(|year| key.year == year))
+        .filter(|key| day.is_none_or(|day| key.day == day))
+        .collect::<Vec<_>>();
+    if keys.is_empty() {
+        bail!("there are no solutions for these puzzles");
+    }
+    Ok(keys)
+}
+
+fn bench(
+    data_dir: &Path,
+    year: Option<usize>,
+    day: Option<usize>,
+    runs: usize,
+) -> anyhow::Result<()> {
+    let solutions = SolutionsMap::init();
+
+    for key in select_keys(&solutions, year, day)? {
+        let Some(data) = aoc::cached_data(data_dir, key.year, key.day)? else {
+            println!("{key}: no cached input, solve it first to fetch it");
+            continue;
+        };
+        for registration in solutions.implementations(key) {
+            let solver = registration.solver();
+            for &part in registration.parts {
+                match aoc::time_solution(solver.as_ref(), part, &data, runs) {
+                    Ok((answer, timings)) => println!(
+                        "{key} part {part} {:<12} {timings}  answer: {answer}",
+                        registration.name
+                    ),
+                    Err(err) => {
+                        println!("{key} part {part} {:<12} error: {err:#}", registration.name)
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(data_dir: &Path, year: Option<usize>, day: Option<usize>) -> anyhow::Result<()> {
+    let solutions = SolutionsMap::init();
+
+    let mut wrong = 0;
+    for key in select_keys(&solutions, year, day)? {
+        let Some(verifications) = aoc::verify(&solutions, data_dir, key)? else {
+            println!("{key}: skipped, no cached input");
+            continue;
+        };
+        for verification in verifications {
+            let answer = match &verification.answer {
+                Ok(answer) => answer.to_string(),
+                Err(err) => format!("error: {err}"),
+            };
+            let status = match (verification.is_correct(), &verification.expected) {
+                (Some(true), _) => "ok".to_string(),
+                (Some(false), expected) => {
+                    wrong += 1;
+                    match expected {
+                        Some(expected) => format!("WRONG, expected {expected}"),
+                        None => "FAILED".to_string(),
+                    }
+                }
+                (None, _) => "no recorded answer".to_string(),
+            };
+            println!(
+                "{key} part {} {:<12} {answer:<20} {status}",
+                verification.part, verification.name
+            );
+        }
+    }
+
+    if wrong > 0 {
+        bail!("{wrong} answers are wrong or failed");
+    }
     Ok(())
 }
+
+fn record_answer(data_dir: &Path, puzzle: &Puzzle, answer: &str) -> anyhow::Result<()> {
+    let mut answers = Answers::load(Answers::path(data_dir, puzzle.year))?;
+    answers.set(puzzle.day, puzzle.part.level(), answer);
+    answers.save()
+}
+
+fn submit(data_dir: &Path, aoc_session: &str, puzzle: &Puzzle, answer: &str) -> anyhow::Result<()> {
+    let part = puzzle.part.level();
+    let mut submissions = Submissions::load(Submissions::path(data_dir, puzzle.year, puzzle.day))?;
+
+    if let Some(verdict) = submissions.known(part, answer) {
+        match verdict {
+            Verdict::Correct => println!("answer: {answer} was already accepted"),
+            _ => println!("answer: {answer} is {verdict}, it was not submitted"),
+        }
+        return Ok(());
+    }
+
+    if let Some(wait) = submissions.wait_left(SystemTime::now()) {
+        bail!(
+            "answer: {answer} was not submitted, the site refuses answers for another {}s",
+            wait.as_secs()
+        );
+    }
+
+    // the site can be replaced by another server, e.g. to try the command
+    let url = env::var("AOC_URL").unwrap_or_else(|_| aoc::AOC_URL.to_string());
+    let outcome = aoc::submit_answer(&url, aoc_session, puzzle.year, puzzle.day, part, answer)?;
+    println!("answer: {answer}, {outcome}");
+
+    match outcome {
+        SubmitOutcome::Answered(verdict) => {
+            submissions.record(part, answer, verdict);
+            submissions.save()?;
+            if verdict == Verdict::Correct {
+                record_answer(data_dir, puzzle, answer)?;
+            }
+            Ok(())
+        }
+        SubmitOutcome::RateLimited(wait) => {
+            // an unknown wait is not saved, the site says it again on the next try
+            if let Some(wait) = wait {
+                submissions.throttle(SystemTime::now(), wait);
+                submissions.save()?;
+            }
+            bail!("the answer was not checked")
+        }
+        SubmitOutcome::WrongLevel | SubmitOutcome::Unknown(_) => Ok(()),
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+/// Module of a new day, `$YEAR` and `$DAY` being replaced by the puzzle.
+const DAY_TEMPLATE: &str = r#"use crate::solutions::Solver;
+
+#[derive(Debug)]
+pub struct Solution;
+
+impl Solution {
+    pub fn boxed() -> Box<Self> {
+        Box::new(Self {})
+    }
+}
+
+// list the parts as they are solved, e.g. `parts = [1]`
+register_solution!($YEAR, $DAY, parts = []);
+
+// the parts not implemented fail with `NotImplemented`
+impl Solver for Solution {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[rstest]
+    #[case::part_1(1, "")]
+    #[case::part_2(2, "")]
+    #[ignore = "the example and its answers are not filled in"]
+    fn test_example(#[case] part: u8, #[case] expected: &str) {
+        let answer = Solution.solve(part, EXAMPLE).unwrap();
+        assert_eq!(answer.to_string(), expected);
+    }
+}
+"#;
+
+/// Creates the module of a day in `solutions_dir`, with the module of its year when it
+/// is the first one, and declares them. Fails without writing anything when the day
+/// exists. Returns the files written.
+pub fn new_day(solutions_dir: &Path, year: usize, day: usize) -> anyhow::Result<Vec<PathBuf>> {
+    let year_dir = solutions_dir.join(format!("y{year}"));
+    let day_path = year_dir.join(format!("day_{day}.rs"));
+    if day_path.exists() {
+        bail!("{} already exists", day_path.display());
+    }
+
+    let mut written = vec![];
+    fs::create_dir_all(&year_dir)?;
+    let mut file = match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&day_path)
+    {
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => {
+            bail!("{} already exists", day_path.display())
+        }
+        file => file.with_context(|| format!("could not create {}", day_path.display()))?,
+    };
+    let module = DAY_TEMPLATE
+        .replace("$YEAR", &year.to_string())
+        .replace("$DAY", &day.to_string());
+    file.write_all(module.as_bytes())?;
+    written.push(day_path);
+
+    let year_mod = year_dir.join("mod.rs");
+    if year_mod.exists() {
+        let content = fs::read_to_string(&year_mod)?;
+        fs::write(&year_mod, insert_declaration(&content, "pub mod day_", day))?;
+    } else {
+        fs::write(&year_mod, format!("pub mod day_{day};\n"))?;
+
+        let solutions_mod = solutions_dir.join("mod.rs");
+        let content = fs::read_to_string(&solutions_mod)?;
+        fs::write(&solutions_mod, insert_declaration(&content, "mod y", year))?;
+        written.push(solutions_mod);
+    }
+    written.push(year_mod);
+
+    Ok(written)
+}
+
+/// Adds the `{prefix}{number};` line among the ones with the same prefix, ordered by
+/// their number, or at the end without them.
+fn insert_declaration(content: &str, prefix: &str, number: usize) -> String {
+    let declaration = format!("{prefix}{number};");
+    let mut lines = content.lines().collect::<Vec<_>>();
+    if lines.contains(&declaration.as_str()) {
+        return content.to_string();
+    }
+
+    let declared = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let n = line.strip_prefix(prefix)?.strip_suffix(';')?.parse().ok()?;
+            Some((i, n))
+        })
+        .collect::<Vec<(usize, usize)>>();
+    let index = match declared.iter().find(|&&(_, n)| n > number) {
+        Some(&(i, _)) => i,
+        None => declared.last().map_or(lines.len(), |&(i, _)| i + 1),
+    };
+    lines.insert(index, &declaration);
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_declaration() {
+        let content = "pub mod day_1;\npub mod day_3;\npub mod day_13;\n";
+
+        assert_eq!(
+            insert_declaration(content, "pub mod day_", 4),
+            "pub mod day_1;\npub mod day_3;\npub mod day_4;\npub mod day_13;\n"
+        );
+        assert_eq!(
+            insert_declaration(content, "pub mod day_", 25),
+            "pub mod day_1;\npub mod day_3;\npub mod day_13;\npub mod day_25;\n"
+        );
+        assert_eq!(
+            insert_declaration(
+                "/// doc\nmacro_rules! m {}\n\nmod y2023;\n\nuse std::fmt;\n",
+                "mod y",
+                2024
+            ),
+            "/// doc\nmacro_rules! m {}\n\nmod y2023;\nmod y2024;\n\nuse std::fmt;\n"
+        );
+        assert_eq!(insert_declaration("", "mod y", 2024), "mod y2024;\n");
+        assert_eq!(insert_declaration(content, "pub mod day_", 3), content);
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(dir.join("y2023")).unwrap();
+        fs::write(dir.join("mod.rs"), "mod y2023;\n\nuse std::fmt;\n").unwrap();
+        fs::write(dir.join("y2023/mod.rs"), "pub mod day_1;\npub mod day_3;\n").unwrap();
+
+        let written = new_day(&dir, 2023, 2).unwrap();
+        assert_eq!(
+            written,
+            vec![dir.join("y2023/day_2.rs"), dir.join("y2023/mod.rs")]
+        );
+        let module = fs::read_to_string(dir.join("y2023/day_2.rs")).unwrap();
+        assert!(module.contains("register_solution!(2023, 2, parts = []);"));
+        assert_eq!(
+            fs::read_to_string(dir.join("y2023/mod.rs")).unwrap(),
+            "pub mod day_1;\npub mod day_2;\npub mod day_3;\n"
+        );
+
+        assert!(new_day(&dir, 2023, 2).is_err());
+        // declared without its file
+        new_day(&dir, 2023, 3).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("y2023/mod.rs")).unwrap(),
+            "pub mod day_1;\npub mod day_2;\npub mod day_3;\n"
+        );
+        fs::write(dir.join("y2023/day_4.rs"), "// solved").unwrap();
+        assert!(new_day(&dir, 2023, 4).is_err());
+        assert_eq!(
+            fs::read_to_string(dir.join("y2023/day_4.rs")).unwrap(),
+            "// solved"
+        );
+
+        new_day(&dir, 2024, 1).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("mod.rs")).unwrap(),
+            "mod y2023;\nmod y2024;\n\nuse std::fmt;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("y2024/mod.rs")).unwrap(),
+            "pub mod day_1;\n"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,29 +1,272 @@
+/// Registers a solution of the current module for a puzzle. By default the `Solution`
+/// type answering both parts is the "default" implementation; other implementations of
+/// the same puzzle are registered with their own name to compare them:
+/// `register_solution!(2023, 1);`, `register_solution!(2023, 13, parts = [1]);` or
+/// `register_solution!(2023, 6, "quadratic" => QuadraticSolution);`
+macro_rules! register_solution {
+    ($year:literal, $day:literal) => {
+        register_solution!($year, $day, "default" => Solution, parts = [1, 2]);
+    };
+    ($year:literal, $day:literal, parts = [$($part:literal),*]) => {
+        register_solution!($year, $day, "default" => Solution, parts = [$($part),*]);
+    };
+    ($year:literal, $day:literal, $name:literal => $solution:ty) => {
+        register_solution!($year, $day, $name => $solution, parts = [1, 2]);
+    };
+    ($year:literal, $day:literal, $name:literal => $solution:ty, parts = [$($part:literal),*]) => {
+        inventory::submit! {
+            crate::solutions::Registration {
+                key: crate::solutions::PuzzleKey::new($year, $day),
+                name: $name,
+                parts: &[$($part),*],
+                solver: || <$solution>::boxed(),
+            }
+        }
+    };
+}
+
 mod y2023;
 
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::BTreeMap, error::Error, fmt, fmt::Debug};
+
+use anyhow::bail;
+
+/// Answer of a part of a puzzle, a number for most of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// The numbers beyond `i64` are kept as text, so that they are never wrapped.
+macro_rules! impl_answer_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    i64::try_from(number)
+                        .map(Answer::Number)
+                        .unwrap_or_else(|_| Answer::Text(number.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(i32, u32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Error of the parts that a solution does not answer yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotImplemented(pub u8);
 
+impl fmt::Display for NotImplemented {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the part {} is not implemented", self.0)
+    }
+}
+
+impl Error for NotImplemented {}
+
+/// Solution of a puzzle, answering its parts from the raw input. The parts left out
+/// fail with `NotImplemented`.
 pub trait Solver: Debug {
-    fn solve_part1(&self, data: Vec<String>) -> String;
-    fn solve_part2(&self, data: Vec<String>) -> String;
+    fn solve_part1(&self, _input: &str) -> anyhow::Result<Answer> {
+        Err(NotImplemented(1).into())
+    }
+
+    fn solve_part2(&self, _input: &str) -> anyhow::Result<Answer> {
+        Err(NotImplemented(2).into())
+    }
+
+    fn solve(&self, part: u8, input: &str) -> anyhow::Result<Answer> {
+        match part {
+            1 => self.solve_part1(input),
+            2 => self.solve_part2(input),
+            _ => bail!("the puzzles have 2 parts, not {part}"),
+        }
+    }
+}
+
+/// Year and day of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleKey {
+    pub year: usize,
+    pub day: usize,
+}
+
+impl PuzzleKey {
+    pub const fn new(year: usize, day: usize) -> Self {
+        Self { year, day }
+    }
+}
+
+impl fmt::Display for PuzzleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
 }
 
-pub struct SolutionsMap<'a> {
-    solutions: HashMap<&'a str, Box<dyn Solver>>,
+/// A solution submitted by its module with `register_solution!`.
+pub struct Registration {
+    pub key: PuzzleKey,
+    /// name of the implementation, "default" for the one used to answer the puzzle
+    pub name: &'static str,
+    /// parts of the puzzle the solution answers
+    pub parts: &'static [u8],
+    pub solver: fn() -> Box<dyn Solver>,
 }
 
-impl<'a> SolutionsMap<'a> {
+inventory::collect!(Registration);
+
+impl Registration {
+    pub fn solver(&self) -> Box<dyn Solver> {
+        (self.solver)()
+    }
+
+    pub fn has_part(&self, part: u8) -> bool {
+        self.parts.contains(&part)
+    }
+}
+
+pub struct SolutionsMap {
+    /// implementations of each puzzle, the default one first
+    solutions: BTreeMap<PuzzleKey, Vec<&'static Registration>>,
+}
+
+impl SolutionsMap {
+    pub const DEFAULT: &'static str = "default";
+
     pub fn init() -> Self {
-        let mut map: HashMap<&'a str, Box<dyn Solver>> = HashMap::new();
-        // register the solutions here
-        map.insert("2023_1", y2023::day_1::Solution::boxed());
-        map.insert("2023_2", y2023::day_2::Solution::boxed());
-        map.insert("2023_3", y2023::day_3::Solution::boxed());
-        map.insert("2023_13", y2023::day_13::Solution::boxed());
-        //
-        Self { solutions: map }
-    }
-
-    pub fn get(self: &Self, entry: &str) -> Option<&Box<dyn Solver>> {
-        self.solutions.get(entry)
+        let mut solutions: BTreeMap<_, Vec<&'static Registration>> = BTreeMap::new();
+        for registration in inventory::iter::<Registration> {
+            let implementations = solutions.entry(registration.key).or_default();
+            assert!(
+                implementations.iter().all(|r| r.name != registration.name),
+                "the {} solution of {} is registered twice",
+                registration.name,
+                registration.key
+            );
+            implementations.push(registration);
+        }
+        for implementations in solutions.values_mut() {
+            implementations.sort_by_key(|r| (r.name != Self::DEFAULT, r.name));
+        }
+        Self { solutions }
+    }
+
+    /// The default implementation of the puzzle, or the first one by name without it.
+    pub fn get(&self, key: PuzzleKey) -> Option<&'static Registration> {
+        self.implementations(key).first().copied()
+    }
+
+    pub fn get_named(&self, key: PuzzleKey, name: &str) -> Option<&'static Registration> {
+        self.implementations(key)
+            .iter()
+            .find(|r| r.name == name)
+            .copied()
+    }
+
+    pub fn implementations(&self, key: PuzzleKey) -> &[&'static Registration] {
+        self.solutions.get(&key).map_or(&[], Vec::as_slice)
+    }
+
+    /// The puzzles with a solution, ordered by year and day.
+    pub fn keys(&self) -> impl Iterator<Item = PuzzleKey> + '_ {
+        self.solutions.keys().copied()
+    }
+
+    /// The default implementations, ordered by year and day.
+    pub fn iter(&self) -> impl Iterator<Item = &'static Registration> + '_ {
+        self.solutions
+            .values()
+            .map(|implementations| implementations[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solutions_map() {
+        let solutions = SolutionsMap::init();
+
+        for day in 1..=11 {
+            let registration = solutions.get(PuzzleKey::new(2023, day)).unwrap();
+            assert!(registration.has_part(1) && registration.has_part(2));
+        }
+        assert!(solutions.get(PuzzleKey::new(2023, 12)).is_none());
+        assert!(!solutions.get(PuzzleKey::new(2023, 13)).unwrap().has_part(1));
+
+        let keys = solutions.keys().collect::<Vec<_>>();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert!(solutions.iter().all(|r| r.name == SolutionsMap::DEFAULT));
+    }
+
+    #[test]
+    fn test_named_implementations() {
+        let solutions = SolutionsMap::init();
+        let key = PuzzleKey::new(2023, 6);
+
+        let names = solutions
+            .implementations(key)
+            .iter()
+            .map(|r| r.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["default", "quadratic"]);
+
+        let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+        for registration in solutions.implementations(key) {
+            let solver = registration.solver();
+            assert_eq!(solver.solve(1, input).unwrap(), Answer::Number(288));
+            assert_eq!(solver.solve(2, input).unwrap(), Answer::Number(71503));
+        }
+        assert!(solutions.get_named(key, "quadratic").is_some());
+        assert!(solutions.get_named(key, "unknown").is_none());
+    }
+
+    #[test]
+    fn test_answer_from_number() {
+        assert_eq!(Answer::from(42u64), Answer::Number(42));
+        assert_eq!(Answer::from(-3), Answer::Number(-3));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(
+            Answer::from(i64::MAX as usize + 1).to_string(),
+            "9223372036854775808"
+        );
+    }
+
+    #[test]
+    fn test_not_implemented_part() {
+        let solver = SolutionsMap::init()
+            .get(PuzzleKey::new(2023, 13))
+            .unwrap()
+            .solver();
+
+        let err = solver.solve(1, "#.#").unwrap_err();
+        assert_eq!(err.downcast_ref(), Some(&NotImplemented(1)));
+        assert!(solver.solve(3, "#.#").is_err());
+        assert_eq!(Answer::from(42usize).to_string(), "42");
     }
 }
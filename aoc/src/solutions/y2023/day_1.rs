@@ -1,4 +1,6 @@
-use crate::solutions::Solver;
+use anyhow::anyhow;
+
+use crate::solutions::{Answer, Solver};
 
 #[derive(Debug)]
 pub struct Solution;
@@ -9,18 +11,26 @@ impl Solution {
     }
 }
 
+register_solution!(2023, 1);
+
 impl Solver for Solution {
-    fn solve_part1(&self, data: Vec<String>) -> String {
-        let value: i32 = data.into_iter().map(parse_num).sum();
-        format!("{value}")
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        sum_calibration_values(input, parse_num)
     }
 
-    fn solve_part2(&self, data: Vec<String>) -> String {
-        let value: i32 = data.into_iter().map(parse_num_with_literals).sum();
-        format!("{value}")
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        sum_calibration_values(input, parse_num_with_literals)
     }
 }
 
+fn sum_calibration_values(input: &str, parse: fn(&str) -> Option<i32>) -> anyhow::Result<Answer> {
+    let value = input
+        .lines()
+        .map(|line| parse(line).ok_or_else(|| anyhow!("there are no digits in `{line}`")))
+        .sum::<anyhow::Result<i32>>()?;
+    Ok(value.into())
+}
+
 const ZERO: u8 = 48;
 
 #[inline]
@@ -29,16 +39,16 @@ fn u8_to_num(v: u8) -> i32 {
 }
 
 /// will parse the number according to some special rules
-fn parse_num(s: String) -> i32 {
+fn parse_num(s: &str) -> Option<i32> {
     let mut nums = vec![];
     for &b in s.as_bytes() {
         if b.is_ascii_digit() {
             nums.push(b);
         }
     }
-    let first = u8_to_num(*nums.first().unwrap());
-    let last = u8_to_num(*nums.last().unwrap());
-    10 * first + last
+    let first = u8_to_num(*nums.first()?);
+    let last = u8_to_num(*nums.last()?);
+    Some(10 * first + last)
 }
 
 const LITERALS: [(&str, i32); 9] = [
@@ -53,7 +63,7 @@ const LITERALS: [(&str, i32); 9] = [
     ("nine", 9),
 ];
 
-fn parse_num_with_literals(s: String) -> i32 {
+fn parse_num_with_literals(s: &str) -> Option<i32> {
     let mut nums = vec![];
 
     for i in 0..s.len() {
@@ -74,9 +84,9 @@ fn parse_num_with_literals(s: String) -> i32 {
         }
     }
 
-    let first = *nums.first().unwrap();
-    let last = *nums.last().unwrap();
-    10 * first + last
+    let first = *nums.first()?;
+    let last = *nums.last()?;
+    Some(10 * first + last)
 }
 
 #[cfg(test)]
@@ -86,8 +96,8 @@ mod tests {
 
     #[test]
     fn parse_num_example1() {
-        let num = parse_num("1abc2".to_string());
-        assert_eq!(num, 12);
+        assert_eq!(parse_num("1abc2"), Some(12));
+        assert_eq!(parse_num("abc"), None);
     }
 
     #[test]
@@ -113,8 +123,8 @@ mod tests {
     #[case("4nineeightseven2", 42)]
     #[case("zoneight234", 14)]
     #[case("7pqrstsixteen", 76)]
-    fn parse_num_with_literals_example1(#[case] input: String, #[case] expected: i32) {
+    fn parse_num_with_literals_example1(#[case] input: &str, #[case] expected: i32) {
         let got = parse_num_with_literals(input);
-        assert_eq!(got, expected)
+        assert_eq!(got, Some(expected))
     }
 }
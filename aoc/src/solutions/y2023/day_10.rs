@@ -0,0 +1,575 @@
+use anyhow::{anyhow, bail};
+
+use crate::solutions::{Answer, Solver};
+
+#[derive(Debug)]
+pub struct Solution;
+
+impl Solution {
+    pub fn boxed() -> Box<Self> {
+        Box::new(Self {})
+    }
+}
+
+register_solution!(2023, 10);
+
+impl Solver for Solution {
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_path(input)?.into())
+    }
+
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_rooms(input)?.into())
+    }
+}
+
+const DIRECTIONS: [Directions; 4] = [
+    Directions::North,
+    Directions::West,
+    Directions::South,
+    Directions::East,
+];
+
+fn calculate_rooms(data: &str) -> anyhow::Result<usize> {
+    let maze = MazeMap::new(data)?;
+    let mut area = RoomsArea::new(maze.dim());
+    let mut path_printer = PathPrinter::new(maze.dim());
+
+    let (initial_pos, initial_pipe) = maze.get_initial_pos()?;
+
+    area.add_pos(&initial_pos, &initial_pipe);
+    path_printer.add_path(&initial_pos, &initial_pipe);
+
+    let (mut pos, mut direction, mut pipe) = DIRECTIONS
+        .iter()
+        // .inspect(|&d| println!("direction {:?}", d))
+        .find_map(|&direction| {
+            if let Some(pos) = maze.get_next_pos(&initial_pos, direction) {
+                if let Some(pipe) = maze.get_pipe(&pos) {
+                    if pipe.can_connect(direction.get_opposite()) {
+                        Some((pos, direction, pipe))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| anyhow!("the loop is broken at {initial_pos:?}"))?;
+
+    while pipe.0 != 'S' {
+        area.add_pos(&pos, pipe);
+        path_printer.add_path(&pos, pipe);
+
+        (pos, direction, pipe) = maze
+            .follow(&pos, direction, pipe)
+            .ok_or_else(|| anyhow!("the loop is broken at {pos:?}"))?;
+    }
+
+    area.recalculate_boundaries();
+
+    for (x, line) in maze.maze.iter().enumerate() {
+        for (y, _) in line.iter().enumerate() {
+            let pos = Pos { x, y };
+            path_printer.add_path(&pos, &Pipe('O'))
+        }
+    }
+
+    let rooms = path_printer
+        .path
+        .iter()
+        .enumerate()
+        .flat_map(|(x, line)| {
+            line.iter()
+                .enumerate()
+                .filter(|&(_, ch)| *ch == 'O')
+                .map(move |(y, _)| Pos { x, y })
+        })
+        .collect::<Vec<_>>();
+
+    let rooms = rooms
+        .into_iter()
+        .filter(|pos| {
+            if area.is_in(pos) {
+                path_printer.add_path(pos, &Pipe('I'));
+                true
+            } else {
+                false
+            }
+        })
+        .count();
+
+    path_printer.print_path();
+
+    Ok(rooms)
+}
+
+fn calculate_path(data: &str) -> anyhow::Result<usize> {
+    let maze = MazeMap::new(data)?;
+
+    let (initial_pos, _) = maze.get_initial_pos()?;
+
+    let (mut pos, mut direction, mut pipe) = DIRECTIONS
+        .iter()
+        // .inspect(|&d| println!("direction {:?}", d))
+        .find_map(|&direction| {
+            if let Some(pos) = maze.get_next_pos(&initial_pos, direction) {
+                if let Some(pipe) = maze.get_pipe(&pos) {
+                    if pipe.can_connect(direction) {
+                        Some((pos, direction, pipe))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| anyhow!("the loop is broken at {initial_pos:?}"))?;
+
+    let mut pipes_counter: usize = 1;
+    while pipe.0 != 'S' {
+        (pos, direction, pipe) = maze
+            .follow(&pos, direction, pipe)
+            .ok_or_else(|| anyhow!("the loop is broken at {pos:?}"))?;
+        pipes_counter += 1;
+    }
+
+    Ok(if pipes_counter.is_multiple_of(2) {
+        pipes_counter / 2
+    } else {
+        pipes_counter / 2 + 1
+    })
+}
+
+#[derive(Debug)]
+struct RoomsArea {
+    boundries: Vec<Vec<(usize, char)>>,
+}
+
+impl RoomsArea {
+    fn new(dim: (usize, usize)) -> Self {
+        Self {
+            boundries: (0..dim.0).map(|_| Vec::new()).collect::<Vec<_>>(),
+        }
+    }
+
+    fn add_pos(&mut self, pos: &Pos, pipe: &Pipe) {
+        if pipe.0 != '-' {
+            self.boundries.get_mut(pos.x).unwrap().push((pos.y, pipe.0))
+        }
+    }
+
+    fn recalculate_boundaries(&mut self) {
+        for line in &mut self.boundries {
+            if line.is_empty() {
+                continue;
+            }
+
+            line.sort_by_key(|a| a.0);
+            *line = line.iter_mut().fold(Vec::new(), |mut acc, c| {
+                if let Some(last) = acc.last_mut() {
+                    match (last.1, c.1) {
+                        ('F', '7') => {
+                            *last = (0, '?');
+                            return acc;
+                        }
+                        ('F', 'J') => {
+                            *last = (c.0, '?');
+                            return acc;
+                        }
+                        ('L', 'J') => {
+                            *last = (0, '?');
+                            return acc;
+                        }
+                        ('L', '7') => {
+                            *last = (c.0, '?');
+                            return acc;
+                        }
+                        _ => {
+                            acc.push(*c);
+                            return acc;
+                        }
+                    }
+                }
+                acc.push(*c);
+                acc
+            })
+        }
+    }
+
+    fn is_in(&self, pos: &Pos) -> bool {
+        if let Some(line) = self.boundries.get(pos.x) {
+            line.iter()
+                .filter(|&&(pipe_pos, _)| pipe_pos > pos.y)
+                .count()
+                % 2
+                != 0
+        } else {
+            false
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Pipe(char);
+
+impl Pipe {
+    fn from_directions(from: Directions, to: Directions) -> Option<Self> {
+        match (from, to) {
+            (Directions::North, Directions::West) => Some(Self('J')),
+            (Directions::West, Directions::North) => Some(Self('J')),
+            (Directions::North, Directions::South) => Some(Self('|')),
+            (Directions::South, Directions::North) => Some(Self('|')),
+            (Directions::North, Directions::East) => Some(Self('L')),
+            (Directions::East, Directions::North) => Some(Self('L')),
+            (Directions::West, Directions::South) => Some(Self('7')),
+            (Directions::South, Directions::West) => Some(Self('7')),
+            (Directions::West, Directions::East) => Some(Self('-')),
+            (Directions::East, Directions::West) => Some(Self('-')),
+            (Directions::South, Directions::East) => Some(Self('F')),
+            (Directions::East, Directions::South) => Some(Self('F')),
+            _ => None,
+        }
+    }
+    fn can_connect(&self, direction: Directions) -> bool {
+        match self.0 {
+            '|' => direction == Directions::North || direction == Directions::South,
+            '-' => direction == Directions::East || direction == Directions::West,
+            'L' => direction == Directions::North || direction == Directions::East,
+            'J' => direction == Directions::North || direction == Directions::West,
+            '7' => direction == Directions::South || direction == Directions::West,
+            'F' => direction == Directions::South || direction == Directions::East,
+            '.' => false,
+            'S' => true,
+            _ => false,
+        }
+    }
+
+    fn next_direction(&self, direction: Directions) -> Option<Directions> {
+        match self.0 {
+            '|' => match direction {
+                Directions::North => Some(Directions::South),
+                Directions::West => None,
+                Directions::South => Some(Directions::North),
+                Directions::East => None,
+            },
+            '-' => match direction {
+                Directions::North => None,
+                Directions::West => Some(Directions::East),
+                Directions::South => None,
+                Directions::East => Some(Directions::West),
+            },
+            'L' => match direction {
+                Directions::North => Some(Directions::East),
+                Directions::West => None,
+                Directions::South => None,
+                Directions::East => Some(Directions::North),
+            },
+            'J' => match direction {
+                Directions::North => Some(Directions::West),
+                Directions::West => Some(Directions::North),
+                Directions::South => None,
+                Directions::East => None,
+            },
+            '7' => match direction {
+                Directions::North => None,
+                Directions::West => Some(Directions::South),
+                Directions::South => Some(Directions::West),
+                Directions::East => None,
+            },
+            'F' => match direction {
+                Directions::North => None,
+                Directions::West => None,
+                Directions::South => Some(Directions::East),
+                Directions::East => Some(Directions::South),
+            },
+            '.' => None,
+            'S' => Some(direction.get_opposite()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Directions {
+    North,
+    West,
+    South,
+    East,
+}
+
+impl Directions {
+    fn get_opposite(&self) -> Self {
+        match self {
+            Directions::North => Directions::South,
+            Directions::West => Directions::East,
+            Directions::South => Directions::North,
+            Directions::East => Directions::West,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Pos {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug, PartialEq)]
+struct MazeMap {
+    maze: Vec<Vec<Pipe>>,
+}
+
+struct PathPrinter {
+    path: Vec<Vec<char>>,
+}
+
+impl PathPrinter {
+    fn new(dim: (usize, usize)) -> Self {
+        let (x_max, y_max) = dim;
+        let path = (0..x_max)
+            .map(|_| (0..y_max).map(|_| ' ').collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        Self { path }
+    }
+
+    fn add_path(&mut self, pos: &Pos, pipe: &Pipe) {
+        if self.path[pos.x][pos.y] == ' ' || pipe.0 == 'I' {
+            self.path[pos.x][pos.y] = pipe.0;
+        }
+    }
+
+    /// Draws the loop and the enclosed rooms, on stderr to keep stdout for the answer.
+    fn print_path(&self) {
+        for line in &self.path {
+            let line = line.iter().collect::<String>();
+            eprintln!("{line}");
+        }
+    }
+}
+
+impl MazeMap {
+    fn new(data: &str) -> anyhow::Result<Self> {
+        let maze = data
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.chars()
+                    .map(|ch| match ch {
+                        '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S' => Ok(Pipe(ch)),
+                        _ => Err(anyhow!("invalid pipe `{ch}`")),
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let Some(first) = maze.first() else {
+            bail!("the maze is empty");
+        };
+        if maze.iter().any(|line| line.len() != first.len()) {
+            bail!("the lines of the maze have different lengths");
+        }
+
+        Ok(Self { maze })
+    }
+
+    fn get_initial_pos(&self) -> anyhow::Result<(Pos, Pipe)> {
+        let pos = self
+            .maze
+            .iter()
+            .enumerate()
+            .find_map(|(x, line)| {
+                line.iter()
+                    .enumerate()
+                    .find_map(|(y, ch)| if ch.0 == 'S' { Some(y) } else { None })
+                    .map(|y| Pos { x, y })
+            })
+            .ok_or_else(|| anyhow!("the maze has no starting position"))?;
+
+        let mut directions = DIRECTIONS.iter().filter(|direction| {
+            self.get_next_pos(&pos, **direction)
+                .and_then(|pos| self.get_pipe(&pos))
+                .is_some_and(|pipe| pipe.can_connect(direction.get_opposite()))
+        });
+        let pipe = directions
+            .next()
+            .zip(directions.next())
+            .and_then(|(from, to)| Pipe::from_directions(*from, *to))
+            .ok_or_else(|| anyhow!("the starting position {pos:?} is not part of a loop"))?;
+        Ok((pos, pipe))
+    }
+
+    fn dim(&self) -> (usize, usize) {
+        let x_max = self.maze.len();
+        let y_max = self.maze[0].len();
+        (x_max, y_max)
+    }
+
+    fn get_next_pos(&self, pos: &Pos, direction: Directions) -> Option<Pos> {
+        let (x_max, y_max) = self.dim();
+        match direction {
+            Directions::North => {
+                if pos.x == 0 {
+                    None
+                } else {
+                    Some(Pos {
+                        x: pos.x - 1,
+                        y: pos.y,
+                    })
+                }
+            }
+            Directions::West => {
+                if pos.y == 0 {
+                    None
+                } else {
+                    Some(Pos {
+                        x: pos.x,
+                        y: pos.y - 1,
+                    })
+                }
+            }
+            Directions::South => {
+                if pos.x > x_max {
+                    None
+                } else {
+                    Some(Pos {
+                        x: pos.x + 1,
+                        y: pos.y,
+                    })
+                }
+            }
+            Directions::East => {
+                if pos.y > y_max {
+                    None
+                } else {
+                    Some(Pos {
+                        x: pos.x,
+                        y: pos.y + 1,
+                    })
+                }
+            }
+        }
+    }
+
+    fn get_pipe(&self, pos: &Pos) -> Option<&Pipe> {
+        self.maze.get(pos.x).and_then(|line| line.get(pos.y))
+    }
+
+    /// The next position of the loop after leaving `pos` towards `direction` and going
+    /// through its `pipe`, or `None` when the pipes do not connect.
+    fn follow(
+        &self,
+        pos: &Pos,
+        direction: Directions,
+        pipe: &Pipe,
+    ) -> Option<(Pos, Directions, &Pipe)> {
+        let direction = pipe.next_direction(direction.get_opposite())?;
+        let pos = self.get_next_pos(pos, direction)?;
+        let pipe = self.get_pipe(&pos)?;
+        Some((pos, direction, pipe))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data() {
+        assert_eq!(
+            MazeMap::new(DATA).unwrap(),
+            MazeMap {
+                maze: vec![
+                    vec![Pipe('7'), Pipe('-'), Pipe('F'), Pipe('7'), Pipe('-')],
+                    vec![Pipe('.'), Pipe('F'), Pipe('J'), Pipe('|'), Pipe('7')],
+                    vec![Pipe('S'), Pipe('J'), Pipe('L'), Pipe('L'), Pipe('7')],
+                    vec![Pipe('|'), Pipe('F'), Pipe('-'), Pipe('-'), Pipe('J')],
+                    vec![Pipe('L'), Pipe('J'), Pipe('.'), Pipe('L'), Pipe('J')],
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn test_calculate_path() {
+        assert_eq!(calculate_path(DATA).unwrap(), 8);
+    }
+
+    #[test]
+    fn test_invalid_maze() {
+        assert!(calculate_path("").is_err());
+        assert!(calculate_path("S-7\n|x|\nL-J").is_err());
+        assert!(calculate_path("S-7\n||\nL-J").is_err());
+        // no starting position
+        assert!(calculate_path("F-7\n|.|\nL-J").is_err());
+        // the loop is broken
+        assert!(calculate_path("S-7\n|.|\nL-.").is_err());
+        assert!(calculate_rooms("S-7\n|.|\nL-.").is_err());
+    }
+
+    #[test]
+    fn test_calculate_rooms() {
+        assert_eq!(
+            calculate_rooms(
+                r"...........
+                  .S-------7.
+                  .|F-----7|.
+                  .||.....||.
+                  .||.....||.
+                  .|L-7.F-J|.
+                  .|..|.|..|.
+                  .L--J.L--J.
+                  ..........."
+            )
+            .unwrap(),
+            4
+        );
+        assert_eq!(
+            calculate_rooms(
+                r".F----7F7F7F7F-7....
+                  .|F--7||||||||FJ....
+                  .||.FJ||||||||L7....
+                  FJL7L7LJLJ||LJ.L-7..
+                  L--J.L7...LJS7F-7L7.
+                  ....F-J..F7FJ|L7L7L7
+                  ....L7.F7||L7|.L7L7|
+                  .....|FJLJ|FJ|F7|.LJ
+                  ....FJL-7.||.||||...
+                  ....L---J.LJ.LJLJ..."
+            )
+            .unwrap(),
+            8
+        );
+        assert_eq!(
+            calculate_rooms(
+                r"FF7FSF7F7F7F7F7F---7
+                  L|LJ||||||||||||F--J
+                  FL-7LJLJ||||||LJL-77
+                  F--JF--7||LJLJ7F7FJ-
+                  L---JF-JLJ.||-FJLJJ7
+                  |F|F-JF---7F7-L7L|7|
+                  |FFJF7L7F-JF7|JL---7
+                  7-L-JL7||F7|L7F-7F7|
+                  L.L7LFJ|||||FJL7||LJ
+                  L7JLJL-JLJLJL--JLJ.L"
+            )
+            .unwrap(),
+            10
+        );
+    }
+
+    const DATA: &str = r"
+    7-F7-
+    .FJ|7
+    SJLL7
+    |F--J
+    LJ.LJ";
+}
@@ -11,15 +11,10 @@ impl Solution {
     }
 }
 
-impl Solver for Solution {
-    fn solve_part1(&self, data: Vec<String>) -> String {
-        todo!()
-    }
+register_solution!(2023, 13, parts = []);
 
-    fn solve_part2(&self, data: Vec<String>) -> String {
-        todo!()
-    }
-}
+/// The parts are not solved yet.
+impl Solver for Solution {}
 
 fn is_symetric(chain: &Vec<char>, start: usize) -> bool {
     let mut current = start;
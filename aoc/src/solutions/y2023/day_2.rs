@@ -1,4 +1,5 @@
-use crate::solutions::Solver;
+use crate::solutions::{Answer, Solver};
+use anyhow::anyhow;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -20,26 +21,26 @@ impl Solution {
     }
 }
 
+register_solution!(2023, 2);
+
 impl Solver for Solution {
-    fn solve_part1(&self, data: Vec<String>) -> String {
-        let value: i32 = data
-            .iter()
-            .filter_map(|s| parse_game(s.as_str()).ok())
-            .filter(|(_, game)| {
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        let value: i32 = parse_games(input)?
+            .into_iter()
+            .filter(|game| {
                 game.cubes
                     .iter()
                     .all(|cube| cube.red <= 12 && cube.green <= 13 && cube.blue <= 14)
             })
-            .map(|(_, game)| game.id)
+            .map(|game| game.id)
             .sum();
-        format!("{value}")
+        Ok(value.into())
     }
 
-    fn solve_part2(&self, data: Vec<String>) -> String {
-        let value: i32 = data
-            .iter()
-            .filter_map(|s| parse_game(s.as_str()).ok())
-            .map(|(_, game)| {
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        let value: i32 = parse_games(input)?
+            .into_iter()
+            .map(|game| {
                 let min_cube = game
                     .cubes
                     .into_iter()
@@ -56,15 +57,27 @@ impl Solver for Solution {
 
                         acc
                     })
-                    .unwrap();
+                    .ok_or_else(|| anyhow!("the game {} has no cubes", game.id))?;
 
-                min_cube.red as i32 * min_cube.green as i32 * min_cube.blue as i32
+                Ok(min_cube.red as i32 * min_cube.green as i32 * min_cube.blue as i32)
             })
-            .sum();
-        format!("{value}")
+            .sum::<anyhow::Result<i32>>()?;
+        Ok(value.into())
     }
 }
 
+fn parse_games(input: &str) -> anyhow::Result<Vec<Game>> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            parse_game(line)
+                .map(|(_, game)| game)
+                .map_err(|err| anyhow!("invalid game `{line}`: {err}"))
+        })
+        .collect()
+}
+
 #[derive(Debug, PartialEq)]
 struct Cube {
     red: u8,
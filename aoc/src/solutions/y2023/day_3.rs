@@ -1,4 +1,4 @@
-use crate::solutions::Solver;
+use crate::solutions::{Answer, Solver};
 
 #[derive(Debug)]
 pub struct Solution;
@@ -9,32 +9,31 @@ impl Solution {
     }
 }
 
+register_solution!(2023, 3);
+
 impl Solver for Solution {
-    fn solve_part1(&self, data: Vec<String>) -> String {
-        let numbers = NumberPos::from_data(&data);
-        let symbols = SymbolPos::from_data(&data);
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        let numbers = NumberPos::from_data(input);
+        let symbols = SymbolPos::from_data(input);
 
         let value: i32 = numbers
             .into_iter()
             .filter(|number| {
-                symbols
-                    .iter()
-                    .find(|symbol| {
-                        symbol.index >= number.start - 1
-                            && symbol.index <= number.end + 1
-                            && symbol.line >= number.line - 1
-                            && symbol.line <= number.line + 1
-                    })
-                    .is_some()
+                symbols.iter().any(|symbol| {
+                    symbol.index >= number.start - 1
+                        && symbol.index <= number.end + 1
+                        && symbol.line >= number.line - 1
+                        && symbol.line <= number.line + 1
+                })
             })
             .map(|number| number.number)
             .sum();
-        format!("{value}")
+        Ok(value.into())
     }
 
-    fn solve_part2(&self, data: Vec<String>) -> String {
-        let numbers = NumberPos::from_data(&data);
-        let symbols = SymbolPos::from_data(&data);
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        let numbers = NumberPos::from_data(input);
+        let symbols = SymbolPos::from_data(input);
 
         let value: i32 = symbols
             .into_iter()
@@ -50,10 +49,7 @@ impl Solver for Solution {
                         })
                         .collect();
                     if near_numbers.len() == 2 {
-                        return Some(
-                            near_numbers.get(0).unwrap().number
-                                * near_numbers.get(1).unwrap().number,
-                        );
+                        return Some(near_numbers[0].number * near_numbers[1].number);
                     }
                     return None;
                 }
@@ -61,7 +57,7 @@ impl Solver for Solution {
                 None
             })
             .sum();
-        format!("{value}")
+        Ok(value.into())
     }
 }
 
@@ -74,11 +70,15 @@ struct NumberPos {
 }
 
 impl NumberPos {
-    fn from_data(data: &Vec<String>) -> Vec<Self> {
-        data.iter().enumerate().flat_map(Self::from_line).collect()
+    fn from_data(input: &str) -> Vec<Self> {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(Self::from_line)
+            .collect()
     }
 
-    fn from_line(data: (usize, &String)) -> Vec<Self> {
+    fn from_line(data: (usize, &str)) -> Vec<Self> {
         let (line_index, line) = data;
         line.char_indices()
             .fold(
@@ -126,11 +126,15 @@ struct SymbolPos {
 }
 
 impl SymbolPos {
-    fn from_data(data: &Vec<String>) -> Vec<Self> {
-        data.iter().enumerate().flat_map(Self::from_line).collect()
+    fn from_data(input: &str) -> Vec<Self> {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(Self::from_line)
+            .collect()
     }
 
-    fn from_line(data: (usize, &String)) -> Vec<Self> {
+    fn from_line(data: (usize, &str)) -> Vec<Self> {
         let (line_index, line) = data;
         line.char_indices()
             .filter_map(|(idx, ch)| {
@@ -178,21 +182,17 @@ mod tests {
     ]
     fn test_number_pos_from_line(
         #[case] line_no: usize,
-        #[case] line: String,
+        #[case] line: &str,
         #[case] expected: Vec<NumberPos>,
     ) {
-        let numers: Vec<_> = NumberPos::from_line((line_no, &line));
+        let numers: Vec<_> = NumberPos::from_line((line_no, line));
 
         assert_eq!(numers, expected);
     }
 
     #[test]
     fn test_number_pos_from_data() {
-        let numbers: Vec<_> = NumberPos::from_data(&vec![
-            "467..114..".to_string(),
-            "...*......".to_string(),
-            "..35..633.".to_string(),
-        ]);
+        let numbers: Vec<_> = NumberPos::from_data("467..114..\n...*......\n..35..633.");
 
         assert_eq!(
             numbers,
@@ -227,7 +227,7 @@ mod tests {
 
     #[test]
     fn test_symbol_pos_from_line() {
-        let symbols: Vec<_> = SymbolPos::from_line((4, &"...$.*....".to_string()));
+        let symbols: Vec<_> = SymbolPos::from_line((4, "...$.*...."));
 
         assert_eq!(
             symbols,
@@ -248,11 +248,7 @@ mod tests {
 
     #[test]
     fn test_symbol_pos_from_data() {
-        let symbol: Vec<_> = SymbolPos::from_data(&vec![
-            "617*......".to_string(),
-            ".....+.58.".to_string(),
-            ".592.....".to_string(),
-        ]);
+        let symbol: Vec<_> = SymbolPos::from_data("617*......\n.....+.58.\n.592.....");
 
         assert_eq!(
             symbol,
@@ -0,0 +1,209 @@
+use std::{cmp::min, error::Error, fmt, str::FromStr};
+
+use crate::solutions::{Answer, Solver};
+
+#[derive(Debug)]
+pub struct Solution;
+
+impl Solution {
+    pub fn boxed() -> Box<Self> {
+        Box::new(Self {})
+    }
+}
+
+register_solution!(2023, 4);
+
+impl Solver for Solution {
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_scratchcards_worth(input)?.into())
+    }
+
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_scratchcards(input)?.into())
+    }
+}
+
+fn calculate_scratchcards(data: &str) -> Result<i32, ParseCardWorthError> {
+    let cards_worth = data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::parse::<CardWorth>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // every card wins copies of the cards that follow it, so they are counted by position
+    let mut copies = vec![1; cards_worth.len()];
+
+    let mut total = 0;
+    for (i, card) in cards_worth.iter().enumerate() {
+        let worth = copies[i];
+        total += worth;
+
+        let end = min(i + card.matches as usize + 1, copies.len());
+        for copy in &mut copies[(i + 1)..end] {
+            *copy += worth;
+        }
+    }
+
+    Ok(total)
+}
+
+fn calculate_scratchcards_worth(data: &str) -> Result<i32, ParseCardWorthError> {
+    data.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::parse::<CardWorth>)
+        .map(|card| {
+            let card = card?;
+            Ok(if card.matches == 0 {
+                0
+            } else {
+                i32::pow(2, card.matches as u32 - 1)
+            })
+        })
+        .sum()
+}
+
+#[derive(Debug, PartialEq)]
+#[allow(clippy::enum_variant_names)]
+enum ParseCardWorthError {
+    InvalidCardPart,
+    InvalidCard,
+    InvalidWorthLine,
+    InvalidWorth,
+}
+
+impl fmt::Display for ParseCardWorthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = match self {
+            ParseCardWorthError::InvalidCardPart => "card part",
+            ParseCardWorthError::InvalidCard => "card number",
+            ParseCardWorthError::InvalidWorthLine => "numbers part",
+            ParseCardWorthError::InvalidWorth => "number",
+        };
+        write!(f, "invalid {part} of a scratchcard")
+    }
+}
+
+impl Error for ParseCardWorthError {}
+
+#[derive(Debug, PartialEq)]
+struct CardWorth {
+    card: i32,
+    matches: i32,
+}
+
+impl FromStr for CardWorth {
+    type Err = ParseCardWorthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut s = s.split(": ");
+
+        let card_parts = s.next().ok_or(ParseCardWorthError::InvalidCardPart)?;
+        let card = card_parts
+            .split(' ')
+            .filter(|&s| !s.is_empty())
+            .nth(1)
+            .ok_or(ParseCardWorthError::InvalidCardPart)?
+            .parse::<i32>()
+            .map_err(|_| ParseCardWorthError::InvalidCard)?;
+
+        let mut num_parts = s
+            .next()
+            .ok_or(ParseCardWorthError::InvalidWorthLine)?
+            .split(" | ");
+
+        let winning = num_parts
+            .next()
+            .ok_or(ParseCardWorthError::InvalidWorthLine)?
+            .split(' ')
+            .filter(|&s| !s.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|_| ParseCardWorthError::InvalidWorth)?;
+
+        let numbers = num_parts
+            .next()
+            .ok_or(ParseCardWorthError::InvalidWorthLine)?
+            .split(' ')
+            .filter(|&s| !s.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|_| ParseCardWorthError::InvalidWorth)?;
+
+        let matches = numbers
+            .into_iter()
+            .filter(|n| winning.iter().find(|&w| w == n).is_some())
+            .count() as i32;
+
+        Ok(CardWorth { card, matches })
+    }
+}
+
+#[allow(dead_code)]
+fn get_card_worth(line: &str) -> Option<i32> {
+    let mut parts = line.split(" | ");
+
+    let winning = parts
+        .next()?
+        .split(' ')
+        .filter_map(|n| n.parse::<i32>().ok())
+        .collect::<Vec<_>>();
+    let numbers = parts
+        .next()?
+        .split(' ')
+        .filter_map(|n| n.parse::<i32>().ok())
+        .collect::<Vec<_>>();
+
+    let winners = numbers
+        .into_iter()
+        .filter(|n| winning.iter().find(|&w| w == n).is_some())
+        .count();
+
+    if winners == 0 {
+        None
+    } else {
+        Some(i32::pow(2, winners as u32 - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_card_worth() {
+        let worth = CardWorth::from_str("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53");
+
+        assert_eq!(
+            worth,
+            Ok(CardWorth {
+                card: 1,
+                matches: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_calculate_scratchcards_worth() {
+        let worth = calculate_scratchcards_worth(TEST_DATA);
+
+        assert_eq!(worth, Ok(13));
+    }
+
+    #[test]
+    fn test_calculate_scratchcards() {
+        let total = calculate_scratchcards(TEST_DATA);
+
+        assert_eq!(total, Ok(30));
+
+        assert!(calculate_scratchcards("Card x: 1 2 | 2 3").is_err());
+    }
+
+    const TEST_DATA: &str = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+}
@@ -0,0 +1,177 @@
+use std::{error::Error, fmt};
+
+use crate::solutions::{Answer, Solver};
+
+#[derive(Debug)]
+pub struct Solution;
+
+impl Solution {
+    pub fn boxed() -> Box<Self> {
+        Box::new(Self {})
+    }
+}
+
+register_solution!(2023, 6);
+
+impl Solver for Solution {
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_record(input, count_ways)?.into())
+    }
+
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_record(&join_numbers(input), count_ways)?.into())
+    }
+}
+
+/// Counts the ways to win from the roots of the distance equation instead of trying
+/// every hold time.
+#[derive(Debug)]
+pub struct QuadraticSolution;
+
+impl QuadraticSolution {
+    pub fn boxed() -> Box<Self> {
+        Box::new(Self {})
+    }
+}
+
+register_solution!(2023, 6, "quadratic" => QuadraticSolution);
+
+impl Solver for QuadraticSolution {
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_record(input, count_ways_quadratic)?.into())
+    }
+
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_record(&join_numbers(input), count_ways_quadratic)?.into())
+    }
+}
+
+/// Reads the numbers of each line as a single number, ignoring the spaces between them.
+fn join_numbers(data: &str) -> String {
+    data.lines()
+        .map(|line| match line.split_once(':') {
+            Some((name, numbers)) => format!("{name}: {}", numbers.replace(' ', "")),
+            None => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn calculate_record(data: &str, count_ways: fn(i64, i64) -> i64) -> Result<i64, ParseError> {
+    parse_data(data)?
+        .map(|(time, distance)| count_ways(time, distance))
+        .reduce(|acc, x| acc * x)
+        .ok_or(ParseError("error trying to reduce the data".to_string()))
+}
+
+fn count_ways(time: i64, distance: i64) -> i64 {
+    (1..time)
+        .map(|x| x * (time - x))
+        .filter(|&x| x > distance)
+        .count() as i64
+}
+
+/// The hold times `x` beating the record are between the roots of `x * (time - x) =
+/// distance`, the float roots being corrected on the integers.
+fn count_ways_quadratic(time: i64, distance: i64) -> i64 {
+    let wins = |x: i64| x * (time - x) > distance;
+    let delta = ((time * time - 4 * distance) as f64).sqrt();
+    if delta.is_nan() {
+        return 0;
+    }
+
+    let mut low = ((time as f64 - delta) / 2.0).floor() as i64 + 1;
+    while low > 0 && wins(low - 1) {
+        low -= 1;
+    }
+    while low <= time / 2 && !wins(low) {
+        low += 1;
+    }
+    if low > time / 2 {
+        return 0;
+    }
+    // the distances are symmetric around time / 2
+    time - 2 * low + 1
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ParseError(String);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for ParseError {}
+
+fn parse_data(data: &str) -> Result<impl Iterator<Item = (i64, i64)>, ParseError> {
+    let mut lines = data.lines().map(str::trim).filter(|&s| !s.is_empty());
+
+    let time = lines
+        .next()
+        .ok_or(ParseError("could not parse time line".to_string()))?;
+    let distance = lines
+        .next()
+        .ok_or(ParseError("could not parse distance line".to_string()))?;
+
+    let time = time
+        .split_whitespace()
+        .skip(1)
+        .map(str::parse)
+        .collect::<Result<Vec<i64>, _>>()
+        .map_err(|_| ParseError("error parsing one of the times".to_string()))?;
+
+    let distance = distance
+        .split_whitespace()
+        .skip(1)
+        .map(str::parse)
+        .collect::<Result<Vec<i64>, _>>()
+        .map_err(|_| ParseError("error parsing one of the distances".to_string()))?;
+
+    Ok(time.into_iter().zip(distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data() {
+        let pairs = parse_data(TEST_DATA);
+
+        assert!(pairs.is_ok());
+        let pairs = pairs.unwrap().collect::<Vec<_>>();
+        assert_eq!(pairs, vec![(7, 9), (15, 40), (30, 200)]);
+    }
+
+    #[test]
+    fn test_calculate_record() {
+        assert_eq!(calculate_record(TEST_DATA, count_ways), Ok(288));
+        assert_eq!(calculate_record(TEST_DATA, count_ways_quadratic), Ok(288));
+    }
+
+    #[test]
+    fn test_calculate_record_of_joined_numbers() {
+        let record = calculate_record(&join_numbers(TEST_DATA), count_ways);
+
+        assert_eq!(record, Ok(71503));
+    }
+
+    #[test]
+    fn test_count_ways_quadratic() {
+        for time in 0..40 {
+            for distance in 0..(time * time / 4 + 2) {
+                assert_eq!(
+                    count_ways_quadratic(time, distance),
+                    count_ways(time, distance),
+                    "time {time} distance {distance}"
+                );
+            }
+        }
+    }
+
+    const TEST_DATA: &str = r"
+    Time:      7  15   30
+    Distance:  9  40  200";
+}
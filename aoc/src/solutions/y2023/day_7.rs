@@ -0,0 +1,357 @@
+use std::{collections::HashMap, error::Error, fmt, str::FromStr};
+
+use lazy_static::lazy_static;
+
+use crate::solutions::{Answer, Solver};
+
+#[derive(Debug)]
+pub struct Solution;
+
+impl Solution {
+    pub fn boxed() -> Box<Self> {
+        Box::new(Self {})
+    }
+}
+
+register_solution!(2023, 7);
+
+impl Solver for Solution {
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_total_winnings(input, HandVersion::V1)?.into())
+    }
+
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_total_winnings(input, HandVersion::V2)?.into())
+    }
+}
+
+fn calculate_total_winnings(data: &str, version: HandVersion) -> Result<i32, ParseHandError> {
+    let mut data = data
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::trim)
+        .map(|line| {
+            let mut parts = line.split(' ');
+            let hand = Hand::parse(parts.next().ok_or(ParseHandError)?, version)?;
+            let amount = parts
+                .next()
+                .ok_or(ParseHandError)?
+                .parse::<i32>()
+                .map_err(|_| ParseHandError)?;
+            Ok((hand, amount))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    data.sort();
+    Ok(data
+        .into_iter()
+        .enumerate()
+        .map(|(index, (_, value))| ((index + 1) as i32) * value)
+        .sum())
+}
+
+lazy_static! {
+    static ref CARD_MAP_V1: HashMap<char, u8> = {
+        [
+            '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, ch)| (ch, i as u8))
+        .collect::<HashMap<_, _>>()
+    };
+    static ref CARD_MAP_V2: HashMap<char, u8> = {
+        [
+            'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, ch)| (ch, i as u8))
+        .collect::<HashMap<_, _>>()
+    };
+}
+
+#[derive(Debug, PartialEq)]
+struct ParseHandError;
+
+impl fmt::Display for ParseHandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid hand")
+    }
+}
+
+impl Error for ParseHandError {}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Card(char);
+
+impl Card {
+    fn from_char(c: char) -> Result<Self, ParseHandError> {
+        match c {
+            '2'..='9' | 'T' | 'J' | 'Q' | 'K' | 'A' => Ok(Card(c)),
+            _ => Err(ParseHandError),
+        }
+    }
+
+    fn value(&self, version: HandVersion) -> u8 {
+        match version {
+            HandVersion::V1 => CARD_MAP_V1[&self.0],
+            HandVersion::V2 => CARD_MAP_V2[&self.0],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Hand {
+    cards: [Card; 5],
+    version: HandVersion,
+}
+
+/// Rules of the hands: in `V2` the `J` cards are jokers, the weakest cards that count as
+/// the most repeated card of the hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum HandVersion {
+    V1,
+    V2,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum HandKind {
+    HighCard = 0,
+    OnePair = 1,
+    TwoPair = 2,
+    ThreeOrAKind = 3,
+    FullHouse = 4,
+    FourOfAKind = 5,
+    FiveOfAKind = 6,
+}
+
+impl Hand {
+    fn parse(s: &str, version: HandVersion) -> Result<Self, ParseHandError> {
+        Ok(Self {
+            cards: s
+                .chars()
+                .take(5)
+                .filter_map(|c| Card::from_char(c).ok())
+                .collect::<Vec<Card>>()
+                .try_into()
+                .map_err(|_| ParseHandError)?,
+            version,
+        })
+    }
+
+    fn get_type(&self) -> HandKind {
+        let mut cards_map = self.cards.iter().fold(HashMap::new(), |mut map, x| {
+            map.entry(x.0).and_modify(|x| *x += 1).or_insert(1);
+            map
+        });
+
+        let j_amount = match self.version {
+            HandVersion::V1 => 0,
+            HandVersion::V2 => cards_map.remove(&'J').unwrap_or(0),
+        };
+
+        let mut repeated_cards = cards_map.into_values().collect::<Vec<_>>();
+        repeated_cards.sort();
+
+        match repeated_cards.pop().unwrap_or_default() + j_amount {
+            5 => HandKind::FiveOfAKind,
+            4 => HandKind::FourOfAKind,
+            3 => match repeated_cards.pop().unwrap_or_default() {
+                2 => HandKind::FullHouse,
+                1 => HandKind::ThreeOrAKind,
+                _ => panic!("invalid case"),
+            },
+            2 => match repeated_cards.pop().unwrap_or_default() {
+                2 => HandKind::TwoPair,
+                1 => HandKind::OnePair,
+                _ => panic!("invalid case"),
+            },
+            1 => HandKind::HighCard,
+            _ => panic!("invalid case"),
+        }
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseHandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Hand::parse(s, HandVersion::V1)
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let kind1 = self.get_type();
+        let kind2 = other.get_type();
+        if kind1 == kind2 {
+            for (card1, card2) in self.cards.iter().zip(other.cards.iter()) {
+                if card1 != card2 {
+                    return card1.value(self.version).cmp(&card2.value(self.version));
+                }
+            }
+        }
+        kind1.cmp(&kind2)
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn joker_hand(s: &str) -> Hand {
+        Hand::parse(s, HandVersion::V2).unwrap()
+    }
+
+    #[test]
+    fn test_hand_get_type() {
+        assert_eq!(joker_hand("22222").get_type(), HandKind::FiveOfAKind);
+        assert_eq!(joker_hand("222JJ").get_type(), HandKind::FiveOfAKind);
+        assert_eq!(joker_hand("JJJJJ").get_type(), HandKind::FiveOfAKind);
+        assert_eq!(joker_hand("22223").get_type(), HandKind::FourOfAKind);
+        assert_eq!(joker_hand("22JJ3").get_type(), HandKind::FourOfAKind);
+        assert_eq!(joker_hand("2JJJ3").get_type(), HandKind::FourOfAKind);
+        assert_eq!(joker_hand("3T3T3").get_type(), HandKind::FullHouse);
+        assert_eq!(joker_hand("3TJT3").get_type(), HandKind::FullHouse);
+        assert_eq!(joker_hand("33345").get_type(), HandKind::ThreeOrAKind);
+        assert_eq!(joker_hand("3JJ45").get_type(), HandKind::ThreeOrAKind);
+        assert_eq!(joker_hand("33442").get_type(), HandKind::TwoPair);
+        assert_eq!(joker_hand("3J425").get_type(), HandKind::OnePair);
+        assert_eq!(joker_hand("34425").get_type(), HandKind::OnePair);
+        assert_eq!(joker_hand("39425").get_type(), HandKind::HighCard);
+
+        // without jokers
+        assert_eq!(
+            "AAAAA".parse::<Hand>().unwrap().get_type(),
+            HandKind::FiveOfAKind
+        );
+        assert_eq!(
+            "32T3K".parse::<Hand>().unwrap().get_type(),
+            HandKind::OnePair
+        );
+        assert_eq!(
+            "T55J5".parse::<Hand>().unwrap().get_type(),
+            HandKind::ThreeOrAKind
+        );
+        assert_eq!(
+            "KK677".parse::<Hand>().unwrap().get_type(),
+            HandKind::TwoPair
+        );
+        assert_eq!(
+            "KTJJT".parse::<Hand>().unwrap().get_type(),
+            HandKind::TwoPair
+        );
+        assert_eq!(
+            "J2332".parse::<Hand>().unwrap().get_type(),
+            HandKind::TwoPair
+        );
+
+        // with jokers
+        assert_eq!(joker_hand("T55J5").get_type(), HandKind::FourOfAKind);
+        assert_eq!(joker_hand("KTJJT").get_type(), HandKind::FourOfAKind);
+        assert_eq!(joker_hand("QQQJA").get_type(), HandKind::FourOfAKind);
+        assert_eq!(joker_hand("J2332").get_type(), HandKind::FullHouse);
+        assert_eq!(joker_hand("2222Q").get_type(), HandKind::FourOfAKind);
+    }
+
+    #[test]
+    fn test_hand_kind_order() {
+        assert!(HandKind::FiveOfAKind > HandKind::HighCard);
+        assert!(HandKind::FullHouse > HandKind::TwoPair);
+
+        let mut kv = vec![
+            HandKind::FiveOfAKind,
+            HandKind::FourOfAKind,
+            HandKind::ThreeOrAKind,
+            HandKind::OnePair,
+            HandKind::TwoPair,
+        ];
+        kv.sort();
+
+        assert_eq!(
+            kv,
+            vec![
+                HandKind::OnePair,
+                HandKind::TwoPair,
+                HandKind::ThreeOrAKind,
+                HandKind::FourOfAKind,
+                HandKind::FiveOfAKind
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hand_from_str() {
+        let h = Hand::from_str("32T3K");
+
+        assert_eq!(
+            h,
+            Ok(Hand {
+                cards: [Card('3'), Card('2'), Card('T'), Card('3'), Card('K')],
+                version: HandVersion::V1,
+            })
+        );
+
+        let h = "32t3_".parse::<Hand>();
+
+        assert_eq!(h, Err(ParseHandError))
+    }
+
+    #[test]
+    fn test_sort_hands() {
+        let mut hands = vec![
+            joker_hand("J2883"),
+            joker_hand("J2524"),
+            joker_hand("J267K"),
+        ];
+
+        hands.sort();
+
+        assert_eq!(
+            hands,
+            vec![
+                joker_hand("J267K"),
+                joker_hand("J2524"),
+                joker_hand("J2883")
+            ]
+        );
+
+        // the J is the weakest card only as a joker
+        let mut hands = ["JTTT2".parse::<Hand>().unwrap(), "TJTT2".parse().unwrap()];
+        hands.sort();
+        assert_eq!(hands[0], "TJTT2".parse().unwrap());
+        let mut hands = [joker_hand("TJTT2"), joker_hand("JTTT2")];
+        hands.sort();
+        assert_eq!(hands[0], joker_hand("JTTT2"));
+    }
+
+    #[test]
+    fn test_calculate_total_winnings() {
+        assert_eq!(
+            calculate_total_winnings(TEST_DATA, HandVersion::V1),
+            Ok(6440)
+        );
+        assert_eq!(
+            calculate_total_winnings(TEST_DATA, HandVersion::V2),
+            Ok(5905)
+        );
+        assert_eq!(
+            calculate_total_winnings("32T3K", HandVersion::V1),
+            Err(ParseHandError)
+        );
+    }
+
+    const TEST_DATA: &str = r"
+    32T3K 765
+    T55J5 684
+    KK677 28
+    KTJJT 220
+    QQQJA 483";
+}
@@ -0,0 +1,205 @@
+use std::{collections::HashMap, iter::repeat};
+
+use anyhow::{anyhow, bail};
+
+use crate::solutions::{Answer, Solver};
+
+#[derive(Debug)]
+pub struct Solution;
+
+impl Solution {
+    pub fn boxed() -> Box<Self> {
+        Box::new(Self {})
+    }
+}
+
+register_solution!(2023, 8);
+
+impl Solver for Solution {
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        let (directions, map_data) = split_input(input)?;
+        Ok(find_the_path(directions, map_data)?.into())
+    }
+
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        let (directions, map_data) = split_input(input)?;
+        Ok(find_the_ghost_path(directions, map_data)?.into())
+    }
+}
+
+/// Splits the directions line from the map.
+fn split_input(input: &str) -> anyhow::Result<(&str, &str)> {
+    input
+        .split_once('\n')
+        .ok_or_else(|| anyhow!("the input has no map after the directions"))
+}
+
+fn find_the_path(directions: &str, map_data: &str) -> anyhow::Result<i32> {
+    let mut i = 1;
+    let mut start = "AAA";
+
+    let directions = parse_directions(directions)?;
+    let map: HashMap<&str, (&str, &str)> = pase_data(map_data)?;
+
+    for &direction in repeat(&directions).flatten() {
+        let next = next_node(&map, start, direction)?;
+        if next == "ZZZ" {
+            break;
+        }
+        start = next;
+        i += 1;
+    }
+    Ok(i)
+}
+
+fn find_the_ghost_path(directions: &str, map_data: &str) -> anyhow::Result<u64> {
+    let directions = parse_directions(directions)?;
+    let map: HashMap<&str, (&str, &str)> = pase_data(map_data)?;
+    let mut starts = map
+        .keys()
+        .filter(|&&key| key.ends_with("A"))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let mut visited_nodes: Vec<u64> = Vec::new();
+    for (counter, &direction) in (1..).zip(repeat(&directions).flatten()) {
+        if starts.is_empty() {
+            break;
+        }
+        let mut next_starts = Vec::with_capacity(starts.len());
+        for start in starts {
+            let next = next_node(&map, start, direction)?;
+            if next.ends_with('Z') {
+                visited_nodes.push(counter);
+            } else {
+                next_starts.push(next);
+            }
+        }
+        starts = next_starts;
+    }
+
+    Ok(lcm_of_vec(&visited_nodes))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Direction {
+    Left,
+    Right,
+}
+
+fn parse_directions(directions: &str) -> anyhow::Result<Vec<Direction>> {
+    let directions = directions
+        .trim()
+        .chars()
+        .map(|direction| match direction {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(anyhow!("invalid direction `{direction}`")),
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    if directions.is_empty() {
+        bail!("there are no directions");
+    }
+    Ok(directions)
+}
+
+fn next_node<'a>(
+    map: &HashMap<&str, (&'a str, &'a str)>,
+    node: &str,
+    direction: Direction,
+) -> anyhow::Result<&'a str> {
+    let &(left, right) = map
+        .get(node)
+        .ok_or_else(|| anyhow!("the node `{node}` is not in the map"))?;
+    Ok(match direction {
+        Direction::Left => left,
+        Direction::Right => right,
+    })
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    // let mut a = a;
+    // let mut b = b;
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+fn lcm_of_vec(numbers: &[u64]) -> u64 {
+    numbers
+        .iter()
+        .fold(1, |lcm_so_far, &num| lcm(lcm_so_far, num))
+}
+
+fn pase_data(data: &str) -> anyhow::Result<HashMap<&str, (&str, &str)>> {
+    data.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (key, dir) = line
+                .split_once(" = ")
+                .ok_or_else(|| anyhow!("invalid node `{line}`"))?;
+            let (left, right) = dir
+                .strip_prefix('(')
+                .and_then(|dir| dir.strip_suffix(')'))
+                .and_then(|dir| dir.split_once(", "))
+                .ok_or_else(|| anyhow!("invalid node `{line}`"))?;
+
+            Ok((key, (left, right)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_the_path() {
+        let path = find_the_path(TEST_DIRECTIONS, TEST_MAP_DATA).unwrap();
+        assert_eq!(path, 6)
+    }
+
+    #[test]
+    fn test_find_the_ghost_path() {
+        let path = find_the_ghost_path(TEST_GHOST_DIRECTIOS, TEST_GOST_MAP_DATA).unwrap();
+        assert_eq!(path, 6)
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+
+        assert_eq!(Solution.solve_part1(input).unwrap(), Answer::Number(6));
+        assert!(Solution.solve_part1("LLR").is_err());
+        assert!(Solution.solve_part1("\n\nAAA = (ZZZ, ZZZ)").is_err());
+        assert!(Solution.solve_part1("LLX\n\nAAA = (ZZZ, ZZZ)").is_err());
+        assert!(Solution.solve_part1("L\n\nAAA = (BBB, ZZZ)").is_err());
+        assert!(Solution.solve_part2("L\n\nAAA = BBB").is_err());
+    }
+
+    const TEST_DIRECTIONS: &str = "LLR";
+
+    const TEST_MAP_DATA: &str = r"
+    AAA = (BBB, BBB)
+    BBB = (AAA, ZZZ)
+    ZZZ = (ZZZ, ZZZ)";
+
+    const TEST_GHOST_DIRECTIOS: &str = "LR";
+    const TEST_GOST_MAP_DATA: &str = r"
+    11A = (11B, XXX)
+    11B = (XXX, 11Z)
+    11Z = (11B, XXX)
+    22A = (22B, XXX)
+    22B = (22C, 22C)
+    22C = (22Z, 22Z)
+    22Z = (22B, 22B)
+    XXX = (XXX, XXX)";
+}
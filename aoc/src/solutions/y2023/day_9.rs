@@ -0,0 +1,127 @@
+use std::{error::Error, fmt};
+
+use crate::solutions::{Answer, Solver};
+
+#[derive(Debug)]
+pub struct Solution;
+
+impl Solution {
+    pub fn boxed() -> Box<Self> {
+        Box::new(Self {})
+    }
+}
+
+register_solution!(2023, 9);
+
+impl Solver for Solution {
+    fn solve_part1(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_predictions(input, predict_forward)?.into())
+    }
+
+    fn solve_part2(&self, input: &str) -> anyhow::Result<Answer> {
+        Ok(calculate_predictions(input, predict_backward)?.into())
+    }
+}
+
+fn calculate_predictions<F>(data: &str, predict: F) -> Result<i32, ParseDataError>
+where
+    F: FnMut(Vec<i32>) -> i32,
+{
+    let data = parse_data(data)?;
+
+    Ok(data.into_iter().map(predict).sum())
+}
+
+fn predict_forward(serie: Vec<i32>) -> i32 {
+    let mut prediction = 0;
+    let mut serie = serie;
+
+    // a series too short to reach a constant difference is extrapolated from its last one
+    while let Some(&last) = serie.last().filter(|_| serie.iter().any(|&i| i != 0)) {
+        prediction += last;
+        serie = differences(&serie);
+    }
+
+    prediction
+}
+
+fn predict_backward(serie: Vec<i32>) -> i32 {
+    let mut decomp = vec![];
+    let mut serie = serie;
+
+    while let Some(&first) = serie.first().filter(|_| serie.iter().any(|&i| i != 0)) {
+        decomp.push(first);
+        serie = differences(&serie);
+    }
+
+    decomp
+        .into_iter()
+        .rev()
+        .fold(0, |prediction, first| first - prediction)
+}
+
+fn differences(serie: &[i32]) -> Vec<i32> {
+    serie.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+#[derive(Debug, PartialEq)]
+struct ParseDataError;
+
+impl fmt::Display for ParseDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the values must be integers")
+    }
+}
+
+impl Error for ParseDataError {}
+
+fn parse_data(data: &str) -> Result<Vec<Vec<i32>>, ParseDataError> {
+    data.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.split_whitespace()
+                .map(str::parse::<i32>)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| ParseDataError)
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data() {
+        let data = parse_data(DATA);
+        assert_eq!(
+            data,
+            Ok(vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![1, 3, 6, 10, 15, 21],
+                vec![10, 13, 16, 21, 30, 45]
+            ])
+        )
+    }
+
+    #[test]
+    fn test_calculate_predictions() {
+        let prediction = calculate_predictions(DATA, predict_forward);
+        assert_eq!(prediction, Ok(114));
+        let prediction = calculate_predictions(DATA, predict_backward);
+        assert_eq!(prediction, Ok(2));
+
+        assert_eq!(calculate_predictions("1 2", predict_forward), Ok(3));
+        assert_eq!(calculate_predictions("1 2", predict_backward), Ok(0));
+        assert_eq!(
+            calculate_predictions("0 3 x", predict_forward),
+            Err(ParseDataError)
+        );
+    }
+
+    const DATA: &str = r"
+    0 3 6 9 12 15
+    1 3 6 10 15 21
+    10 13 16 21 30 45";
+}
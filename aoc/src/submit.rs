@@ -0,0 +1,479 @@
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Context};
+use reqwest::header::COOKIE;
+
+/// What the site says about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        [
+            Verdict::Correct,
+            Verdict::Wrong,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.as_str() == s)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "the right answer"),
+            Verdict::Wrong => write!(f, "not the right answer"),
+            Verdict::TooHigh => write!(f, "not the right answer, it is too high"),
+            Verdict::TooLow => write!(f, "not the right answer, it is too low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Answered(Verdict),
+    /// an answer was given too recently, the next one can be sent after the wait, `None`
+    /// when the reply doesn't say how long it is
+    RateLimited(Option<Duration>),
+    /// the part was already solved, or the first part is not solved yet
+    WrongLevel,
+    /// a reply that could not be understood, with its text
+    Unknown(String),
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Answered(verdict) => write!(f, "that's {verdict}"),
+            SubmitOutcome::RateLimited(Some(wait)) => {
+                write!(
+                    f,
+                    "rate limited, wait {}s before trying again",
+                    wait.as_secs()
+                )
+            }
+            SubmitOutcome::RateLimited(None) => {
+                write!(f, "rate limited, for a wait the reply doesn't give")
+            }
+            SubmitOutcome::WrongLevel => {
+                write!(f, "not the level being solved, was it already completed?")
+            }
+            SubmitOutcome::Unknown(text) => write!(f, "unexpected reply: {text}"),
+        }
+    }
+}
+
+/// Posts the answer of a part (1 or 2) and parses the reply.
+pub fn submit_answer(
+    url: &str,
+    session: &str,
+    year: usize,
+    day: usize,
+    part: u8,
+    answer: &str,
+) -> anyhow::Result<SubmitOutcome> {
+    let client = reqwest::blocking::Client::new();
+    let html = client
+        .post(format!("{url}/{year}/day/{day}/answer"))
+        .timeout(Duration::from_secs(10))
+        .header(COOKIE, format!("session={session}"))
+        .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+        .send()
+        .context("could not send the answer to the api")?
+        .error_for_status()
+        .context("api reponded with an invalid status")?
+        .text()
+        .context("could not read the reply of the api")?;
+
+    Ok(parse_reply(&html))
+}
+
+/// Reads the outcome from the text of the `<article>` of the reply page.
+pub fn parse_reply(html: &str) -> SubmitOutcome {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        SubmitOutcome::Answered(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        SubmitOutcome::Answered(if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if text.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited(parse_wait(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::WrongLevel
+    } else {
+        SubmitOutcome::Unknown(text)
+    }
+}
+
+/// The text of the page article without its tags, or of the whole page without one.
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| {
+            let end = html[start..].find("</article>")?;
+            Some(&html[start..start + end])
+        })
+        .unwrap_or(html);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses the wait of `You have 1m 34s left to wait`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for amount in text[start..end].split_whitespace() {
+        let (unit, _) = amount.char_indices().last()?;
+        let (value, unit) = amount.split_at(unit);
+        let value: u64 = value.parse().ok()?;
+        secs += value
+            * match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Escapes the separators of the submissions file in an answer.
+fn escape(answer: &str) -> String {
+    let mut escaped = String::with_capacity(answer.len());
+    for c in answer.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The answer escaped by `escape`, `None` on an unknown escape.
+fn unescape(escaped: &str) -> Option<String> {
+    let mut answer = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            answer.push(c);
+            continue;
+        }
+        answer.push(match chars.next()? {
+            '\\' => '\\',
+            't' => '\t',
+            'n' => '\n',
+            'r' => '\r',
+            _ => return None,
+        });
+    }
+    Some(answer)
+}
+
+/// Answers already submitted for a day, kept in a file so that the answers known to be
+/// wrong are never sent again, with the time before which the site refuses new ones.
+#[derive(Debug)]
+pub struct Submissions {
+    path: PathBuf,
+    entries: Vec<(u8, String, Verdict)>,
+    /// seconds since the unix epoch
+    throttled_until: Option<u64>,
+}
+
+impl Submissions {
+    pub fn path(data_dir: &Path, year: usize, day: usize) -> PathBuf {
+        data_dir.join(format!("{year}/day_{day}.submissions"))
+    }
+
+    /// Loads the submissions saved in `path`, none when the file does not exist.
+    pub fn load<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut entries = vec![];
+        let mut throttled_until = None;
+
+        if path.exists() {
+            let file = File::open(&path).context("could not open the submissions file")?;
+            for line in BufReader::new(file).lines() {
+                let line = line?;
+                if let Some(until) = line.strip_prefix("wait\t") {
+                    let until = until
+                        .parse()
+                        .map_err(|_| anyhow!("invalid wait `{line}`"))?;
+                    throttled_until = Some(until);
+                    continue;
+                }
+                let mut fields = line.split('\t');
+                let entry = (|| {
+                    let part = fields.next()?.parse().ok()?;
+                    let answer = unescape(fields.next()?)?;
+                    let verdict = Verdict::parse(fields.next()?)?;
+                    Some((part, answer, verdict))
+                })()
+                .ok_or_else(|| anyhow!("invalid submission `{line}`"))?;
+                entries.push(entry);
+            }
+        }
+
+        Ok(Self {
+            path,
+            entries,
+            throttled_until,
+        })
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut content: String = self
+            .entries
+            .iter()
+            .map(|(part, answer, verdict)| {
+                format!("{part}\t{}\t{}\n", escape(answer), verdict.as_str())
+            })
+            .collect();
+        if let Some(until) = self.throttled_until {
+            content += &format!("wait\t{until}\n");
+        }
+        fs::write(&self.path, content).context("could not save the submissions")
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        self.entries.push((part, answer.to_string(), verdict));
+    }
+
+    /// Records that the site refuses answers for `wait` from `now`.
+    pub fn throttle(&mut self, now: SystemTime, wait: Duration) {
+        self.throttled_until = Some(unix_secs(now + wait));
+    }
+
+    /// What is left at `now` of the wait before the site takes answers again, `None` once
+    /// it is over.
+    pub fn wait_left(&self, now: SystemTime) -> Option<Duration> {
+        let left = self.throttled_until?.checked_sub(unix_secs(now))?;
+        (left > 0).then(|| Duration::from_secs(left))
+    }
+
+    /// The verdict of an answer when it follows from the earlier ones: the same answer
+    /// was sent, the right answer is known or it is beyond a too high or too low one.
+    pub fn known(&self, part: u8, answer: &str) -> Option<Verdict> {
+        let entries = self.entries.iter().filter(|(p, _, _)| *p == part);
+        let number = answer.parse::<i128>().ok();
+
+        let mut known = None;
+        for (_, submitted, verdict) in entries {
+            if submitted == answer {
+                return Some(*verdict);
+            }
+            let bound = number.zip(submitted.parse::<i128>().ok());
+            known = known.or(match (verdict, bound) {
+                (Verdict::Correct, _) => Some(Verdict::Wrong),
+                (Verdict::TooHigh, Some((number, high))) if number > high => Some(Verdict::TooHigh),
+                (Verdict::TooLow, Some((number, low))) if number < low => Some(Verdict::TooLow),
+                _ => None,
+            });
+        }
+        known
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    const RIGHT: &str =
+        "<main><article><p>That's the right answer!  You are <em>one gold star</em> \
+                         closer to restoring snow operations.</p></article></main>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+                            Please wait one minute before trying again.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait \
+                              after submitting an answer before trying again.  You have 1m 34s \
+                              left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a></p>\
+                              </article>";
+
+    #[rstest]
+    #[case(RIGHT, SubmitOutcome::Answered(Verdict::Correct))]
+    #[case(TOO_HIGH, SubmitOutcome::Answered(Verdict::TooHigh))]
+    #[case(
+        "<article><p>That's not the right answer.</p></article>",
+        SubmitOutcome::Answered(Verdict::Wrong)
+    )]
+    #[case(TOO_RECENT, SubmitOutcome::RateLimited(Some(Duration::from_secs(94))))]
+    #[case(
+        "<article><p>You gave an answer too recently.  You have 1m 34é left to wait.</p>\
+         </article>",
+        SubmitOutcome::RateLimited(None)
+    )]
+    #[case(
+        "<article><p>You don't seem to be solving the right level.  Did you already complete \
+         it?</p></article>",
+        SubmitOutcome::WrongLevel
+    )]
+    fn test_parse_reply(#[case] html: &str, #[case] expected: SubmitOutcome) {
+        assert_eq!(parse_reply(html), expected);
+    }
+
+    /// Answers one request with `html` and returns the request.
+    fn mock_server(html: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buffer = [0; 1024];
+            // the headers and then the form, as long as its content length
+            let complete = |request: &[u8]| {
+                let request = String::from_utf8_lossy(request);
+                let (headers, body) = request.split_once("\r\n\r\n")?;
+                let length = headers.lines().find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })?;
+                Some(body.len() >= length)
+            };
+            while complete(&request) != Some(true) {
+                let n = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..n]);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\n\
+                 Connection: close\r\n\r\n{}",
+                html.len(),
+                html
+            )
+            .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (url, server) = mock_server(TOO_HIGH);
+
+        let outcome = submit_answer(&url, "secret", 2023, 7, 2, "1234").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(outcome, SubmitOutcome::Answered(Verdict::TooHigh));
+        assert!(request.starts_with("POST /2023/day/7/answer HTTP/1.1"));
+        assert!(request.contains("cookie: session=secret"));
+        assert!(request.ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn test_submit_answer_rate_limited() {
+        let (url, server) = mock_server(TOO_RECENT);
+
+        let outcome = submit_answer(&url, "secret", 2023, 1, 1, "42").unwrap();
+        server.join().unwrap();
+
+        assert_eq!(
+            outcome,
+            SubmitOutcome::RateLimited(Some(Duration::from_secs(94)))
+        );
+    }
+
+    #[test]
+    fn test_throttled_submissions() {
+        let path = std::env::temp_dir().join(format!("aoc-throttled-{}", std::process::id()));
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut submissions = Submissions::load(&path).unwrap();
+        assert_eq!(submissions.wait_left(now), None);
+
+        submissions.record(1, "42", Verdict::TooLow);
+        submissions.throttle(now, Duration::from_secs(94));
+        submissions.save().unwrap();
+
+        let submissions = Submissions::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(submissions.known(1, "41"), Some(Verdict::TooLow));
+        assert_eq!(
+            submissions.wait_left(now + Duration::from_secs(30)),
+            Some(Duration::from_secs(64))
+        );
+        assert_eq!(submissions.wait_left(now + Duration::from_secs(94)), None);
+    }
+
+    #[test]
+    fn test_known_submissions() {
+        let path = std::env::temp_dir().join(format!("aoc-submissions-{}", std::process::id()));
+        let mut submissions = Submissions::load(&path).unwrap();
+        submissions.record(1, "500", Verdict::TooHigh);
+        submissions.record(1, "100", Verdict::TooLow);
+        submissions.record(1, "abc", Verdict::Wrong);
+        submissions.record(1, "a\tb\nc\\n", Verdict::Wrong);
+        submissions.record(2, "7", Verdict::Correct);
+        submissions.save().unwrap();
+
+        let submissions = Submissions::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(submissions.known(1, "abc"), Some(Verdict::Wrong));
+        assert_eq!(submissions.known(1, "a\tb\nc\\n"), Some(Verdict::Wrong));
+        assert_eq!(submissions.known(1, "a\tb\nc\n"), None);
+        assert_eq!(submissions.known(1, "501"), Some(Verdict::TooHigh));
+        assert_eq!(submissions.known(1, "99"), Some(Verdict::TooLow));
+        assert_eq!(submissions.known(1, "250"), None);
+        assert_eq!(submissions.known(2, "7"), Some(Verdict::Correct));
+        assert_eq!(submissions.known(2, "8"), Some(Verdict::Wrong));
+    }
+}
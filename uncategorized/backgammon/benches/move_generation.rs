@@ -0,0 +1,70 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use backgammon::{
+    agents::random::RandomAgent,
+    api::{Board, DiceRoll, Game, PlayerColor},
+};
+
+/// Positions and rolls of a few games between random agents.
+fn game_positions() -> Vec<(Board, PlayerColor, DiceRoll)> {
+    let mut positions = Vec::new();
+
+    for seed in 0..10 {
+        let mut game = Game::new(seed);
+        while game.winner().is_none() {
+            let roll = game.roll_dice().unwrap();
+            positions.push((game.board().clone(), game.turn(), roll));
+
+            let plays = game.legal_plays();
+            let play = plays.get(seed as usize % plays.len().max(1));
+            game.play(&play.cloned().unwrap_or_default()).unwrap();
+        }
+    }
+
+    positions
+}
+
+fn bench_legal_plays(c: &mut Criterion) {
+    let positions = game_positions();
+
+    c.bench_function("legal_plays", |b| {
+        b.iter(|| {
+            for (board, player_color, roll) in &positions {
+                std::hint::black_box(board.legal_plays(*player_color, *roll));
+            }
+        });
+    });
+}
+
+fn bench_legal_plays_doubles(c: &mut Criterion) {
+    let board = Board::new();
+
+    c.bench_function("legal_plays_doubles", |b| {
+        b.iter(|| {
+            for die in 1..=6 {
+                std::hint::black_box(
+                    board.legal_plays(PlayerColor::Black, DiceRoll::new(die, die)),
+                );
+            }
+        });
+    });
+}
+
+fn bench_random_games(c: &mut Criterion) {
+    c.bench_function("random_games_100", |b| {
+        b.iter(|| {
+            for seed in 0..100 {
+                let mut game = Game::new(seed);
+                std::hint::black_box(game.run(&mut RandomAgent::new(), &mut RandomAgent::new()));
+            }
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_legal_plays,
+    bench_legal_plays_doubles,
+    bench_random_games
+);
+criterion_main!(benches);
@@ -0,0 +1,108 @@
+use backgammon::api::{Agent, Board, DiceRoll, Play, PlayerColor, Position};
+use rand::RngCore;
+
+/// Agent that only uses the public API of `backgammon`: it makes the play leaving the
+/// best board by a few rules of thumb, hitting, making points and leaving no blots.
+#[derive(Debug, Clone, Copy)]
+pub struct PointMakerAgent {
+    pub hit_weight: f64,
+    pub point_weight: f64,
+    pub blot_weight: f64,
+}
+
+impl Default for PointMakerAgent {
+    fn default() -> Self {
+        Self {
+            hit_weight: 3.0,
+            point_weight: 1.0,
+            blot_weight: 1.5,
+        }
+    }
+}
+
+impl PointMakerAgent {
+    pub fn score(&self, board: &Board, player_color: PlayerColor) -> f64 {
+        let opponent = player_color.opponent();
+
+        let (points, blots) = Position::points()
+            .map(|pos| board.checkers(player_color, pos))
+            .fold((0, 0), |(points, blots), checkers| match checkers {
+                0 => (points, blots),
+                1 => (points, blots + 1),
+                _ => (points + 1, blots),
+            });
+
+        self.hit_weight * board.bar(opponent) as f64 + self.point_weight * points as f64
+            - self.blot_weight * blots as f64
+            // closer to bearing off breaks the ties
+            - board.pip_count(player_color) as f64 / 100.0
+    }
+}
+
+impl Agent for PointMakerAgent {
+    fn choose_play(
+        &mut self,
+        board: &Board,
+        player_color: PlayerColor,
+        _roll: DiceRoll,
+        plays: &[Play],
+        _rng: &mut dyn RngCore,
+    ) -> usize {
+        plays
+            .iter()
+            .map(|play| self.score(&board.apply(player_color, play), player_color))
+            .enumerate()
+            .max_by(|(_, x), (_, y)| x.total_cmp(y))
+            .map_or(0, |(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backgammon::{agents::random::RandomAgent, api::Game};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_hits_a_blot() {
+        let mut board = Board::empty();
+        let pos = |player_color, point| Position::from_point(player_color, point).unwrap();
+        board.set_checkers(PlayerColor::Black, pos(PlayerColor::Black, 8), 2);
+        board.set_checkers(PlayerColor::Black, pos(PlayerColor::Black, 13), 13);
+        board.set_checkers(PlayerColor::Red, pos(PlayerColor::Black, 5), 1);
+        board.set_checkers(PlayerColor::Red, pos(PlayerColor::Red, 6), 14);
+
+        let roll = DiceRoll::new(3, 3);
+        let plays = board.legal_plays(PlayerColor::Black, roll);
+        let index = PointMakerAgent::default().choose_play(
+            &board,
+            PlayerColor::Black,
+            roll,
+            &plays,
+            &mut StdRng::seed_from_u64(0),
+        );
+
+        let board = board.apply(PlayerColor::Black, &plays[index]);
+        assert_eq!(board.bar(PlayerColor::Red), 1);
+        // both checkers from the 8 point land together
+        assert_eq!(
+            board.checkers(PlayerColor::Black, pos(PlayerColor::Black, 5)),
+            2
+        );
+    }
+
+    #[test]
+    fn test_beats_the_random_agent() {
+        let wins = (0..20)
+            .filter(|&seed| {
+                let mut game = Game::new(seed);
+                let (winner, _) =
+                    game.run(&mut PointMakerAgent::default(), &mut RandomAgent::new());
+                winner == PlayerColor::Black
+            })
+            .count();
+
+        assert!(wins > 10, "won {} of 20 games", wins);
+    }
+}
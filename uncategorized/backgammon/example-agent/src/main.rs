@@ -0,0 +1,37 @@
+use backgammon::{
+    agents::random::RandomAgent,
+    api::{Game, PlayerColor},
+};
+use example_agent::PointMakerAgent;
+
+const GAMES: u64 = 1_000;
+
+fn main() {
+    let mut agent = PointMakerAgent::default();
+    let mut random_agent = RandomAgent::new();
+
+    let (mut wins, mut points) = (0, 0);
+    for seed in 0..GAMES {
+        let mut game = Game::new(seed);
+        // the agent takes black in even games and red in odd ones
+        let (agent_color, (winner, victory_type)) = if seed % 2 == 0 {
+            (PlayerColor::Black, game.run(&mut agent, &mut random_agent))
+        } else {
+            (PlayerColor::Red, game.run(&mut random_agent, &mut agent))
+        };
+
+        if winner == agent_color {
+            wins += 1;
+            points += victory_type.value();
+        } else {
+            points -= victory_type.value();
+        }
+    }
+
+    println!(
+        "PointMakerAgent vs RandomAgent: won {} of {} games, {:+.3} points per game",
+        wins,
+        GAMES,
+        points as f64 / GAMES as f64
+    );
+}
@@ -1,5 +1,8 @@
+use rand::RngCore;
+
 use crate::core::{
-    board::BackgammonBoard, dice::DiceRoll, moves::MoveSequence, players::PlayerColor,
+    board::BackgammonBoard, cube::CubeState, dice::DiceRoll, moves::MoveSequence,
+    players::PlayerColor,
 };
 
 pub trait TestingAgent {
@@ -8,5 +11,32 @@ pub trait TestingAgent {
         board: &BackgammonBoard,
         player_color: PlayerColor,
         roll: &DiceRoll,
+        rng: &mut dyn RngCore,
     ) -> Option<MoveSequence>;
+
+    /// Called before rolling when the player may double. Never doubles by default.
+    fn should_double(
+        &self,
+        _board: &BackgammonBoard,
+        _player_color: PlayerColor,
+        _cube_state: &CubeState,
+    ) -> bool {
+        false
+    }
+
+    /// Called when the opponent doubles. Always takes by default.
+    fn should_take(
+        &self,
+        _board: &BackgammonBoard,
+        _player_color: PlayerColor,
+        _cube_state: &CubeState,
+    ) -> bool {
+        true
+    }
+
+    /// Checked after every play: a player who resigned loses the game at the value of
+    /// the cube. Bots never resign.
+    fn has_resigned(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,388 @@
+use std::{
+    cell::{Cell, RefCell},
+    io::{self, BufRead, Write},
+};
+
+use rand::RngCore;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    core::{
+        board::BackgammonBoard,
+        cube::CubeState,
+        dice::DiceRoll,
+        moves::{generate_all_move_sequences, MoveSequence},
+        players::{PlayerColor, BLACK_PLAYER},
+    },
+    game::{
+        board_printer::BoardPrinter,
+        notation::{format_move_sequence, format_roll, parse_moves},
+    },
+};
+
+use super::agent::TestingAgent;
+
+/// Shows `prompt` and reads the answer, `None` once the input is closed.
+fn prompt_line(prompt: &str) -> io::Result<Option<String>> {
+    print!("{prompt} > ");
+    io::stdout().flush()?;
+
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line))
+}
+
+fn ask(question: &str) -> io::Result<Option<bool>> {
+    loop {
+        let Some(line) = prompt_line(&format!("{question} [y/n]"))? else {
+            return Ok(None);
+        };
+        match line.trim() {
+            "y" | "yes" => return Ok(Some(true)),
+            "n" | "no" => return Ok(Some(false)),
+            _ => {}
+        }
+    }
+}
+
+fn describe_cube_state(cube_state: &CubeState) -> String {
+    match cube_state.away {
+        Some((own, opp)) => format!(
+            "cube at {}, you need {own} and your opponent {opp}",
+            cube_state.cube.value
+        ),
+        None => format!("cube at {}", cube_state.cube.value),
+    }
+}
+
+fn player_name(player_color: PlayerColor) -> &'static str {
+    if player_color == BLACK_PLAYER {
+        "Black (X)"
+    } else {
+        "Red (O)"
+    }
+}
+
+/// Agent driven by a person on the terminal. The moves are typed in standard notation
+/// (e.g. "8/5 6/5") or chosen by their number in the list of legal plays.
+///
+/// The player resigns when the input is closed or can't be read.
+#[derive(Default)]
+pub struct HumanAgent {
+    hint_agent: Option<(Box<dyn TestingAgent>, RefCell<ChaCha8Rng>)>,
+    resigned: Cell<bool>,
+}
+
+impl HumanAgent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Typing `hint` (or `?`) shows the play `hint_agent` would choose. The hints draw
+    /// from `hint_rng` so that asking for one doesn't change the rest of the game.
+    pub fn with_hints(hint_agent: Box<dyn TestingAgent>, hint_rng: ChaCha8Rng) -> Self {
+        Self {
+            hint_agent: Some((hint_agent, RefCell::new(hint_rng))),
+            resigned: Cell::new(false),
+        }
+    }
+
+    /// Reads the answer of the player, who resigns when there is none.
+    fn answer<T>(&self, player_color: PlayerColor, answer: io::Result<Option<T>>) -> Option<T> {
+        match answer {
+            Ok(Some(answer)) => return Some(answer),
+            Ok(None) => println!("The input was closed"),
+            Err(err) => println!("The input can't be read: {err}"),
+        }
+        println!("{} resigns", player_name(player_color));
+        self.resigned.set(true);
+        None
+    }
+}
+
+impl TestingAgent for HumanAgent {
+    fn get_best_action(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        roll: &DiceRoll,
+        _rng: &mut dyn RngCore,
+    ) -> Option<MoveSequence> {
+        if self.resigned.get() {
+            return None;
+        }
+        let move_sequences = generate_all_move_sequences(board, player_color, roll);
+
+        BoardPrinter.print_board(board, player_color, roll, None);
+        if move_sequences.is_empty() {
+            println!(
+                "{} rolls {} and cannot move",
+                player_name(player_color),
+                format_roll(roll)
+            );
+            return None;
+        }
+        print_legal_plays(board, player_color, &move_sequences);
+
+        loop {
+            let line = self.answer(
+                player_color,
+                prompt_line(&format!(
+                    "{} to play {} (moves, number, [l]ist or [h]int)",
+                    player_name(player_color),
+                    format_roll(roll)
+                )),
+            )?;
+
+            match line.trim() {
+                "" => continue,
+                "l" | "list" => print_legal_plays(board, player_color, &move_sequences),
+                "h" | "hint" | "?" => match &self.hint_agent {
+                    Some((hint_agent, hint_rng)) => {
+                        if let Some(hint) = hint_agent.get_best_action(
+                            board,
+                            player_color,
+                            roll,
+                            &mut *hint_rng.borrow_mut(),
+                        ) {
+                            println!("Hint: {}", format_move_sequence(board, &hint, player_color));
+                        }
+                    }
+                    None => println!("No hints available"),
+                },
+                input => match input.parse::<usize>() {
+                    Ok(n) if (1..=move_sequences.len()).contains(&n) => {
+                        return Some(move_sequences[n - 1].clone())
+                    }
+                    Ok(_) => println!("There is no play number {input}"),
+                    Err(_) => {
+                        match match_move_sequence(board, player_color, &move_sequences, input) {
+                            Ok(move_sequence) => return Some(move_sequence),
+                            Err(err) => println!("{err}"),
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    fn should_double(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        cube_state: &CubeState,
+    ) -> bool {
+        if self.resigned.get() {
+            return false;
+        }
+        BoardPrinter.print_position(board);
+        let double = ask(&format!(
+            "{} ({}), double?",
+            player_name(player_color),
+            describe_cube_state(cube_state)
+        ));
+        self.answer(player_color, double).unwrap_or(false)
+    }
+
+    fn should_take(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        cube_state: &CubeState,
+    ) -> bool {
+        if self.resigned.get() {
+            return false;
+        }
+        BoardPrinter.print_position(board);
+        let take = ask(&format!(
+            "{} ({}), your opponent doubles, take?",
+            player_name(player_color),
+            describe_cube_state(cube_state)
+        ));
+        self.answer(player_color, take).unwrap_or(false)
+    }
+
+    fn has_resigned(&self) -> bool {
+        self.resigned.get()
+    }
+}
+
+fn print_legal_plays(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    move_sequences: &[MoveSequence],
+) {
+    println!("Legal plays:");
+    for (i, move_sequence) in move_sequences.iter().enumerate() {
+        println!(
+            "{:>4}) {}",
+            i + 1,
+            format_move_sequence(board, move_sequence, player_color)
+        );
+    }
+}
+
+/// Finds the legal play matching the typed moves. Plays are compared by the position
+/// they lead to, so the order of the moves and the way they are split don't matter.
+pub(crate) fn match_move_sequence(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    move_sequences: &[MoveSequence],
+    input: &str,
+) -> Result<MoveSequence, String> {
+    let moves = parse_moves(input, player_color)?;
+
+    let mut target = board.clone();
+    for m in &moves {
+        if target.checkers_at(player_color, m.src) == 0 {
+            return Err(format!("'{input}' is not a legal play"));
+        }
+        target = target.apply_n_moves(m, player_color, 1);
+    }
+
+    move_sequences
+        .iter()
+        .find(|move_sequence| board.apply_move_sequence(move_sequence, player_color) == target)
+        .cloned()
+        .ok_or_else(|| format!("'{input}' is not a legal play"))
+}
+
+/// Wraps a bot to print each of its plays, so a person playing against it can follow the game.
+pub struct NarratedAgent {
+    agent: Box<dyn TestingAgent>,
+}
+
+impl NarratedAgent {
+    pub fn new(agent: Box<dyn TestingAgent>) -> Self {
+        Self { agent }
+    }
+}
+
+impl TestingAgent for NarratedAgent {
+    fn get_best_action(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        roll: &DiceRoll,
+        rng: &mut dyn RngCore,
+    ) -> Option<MoveSequence> {
+        let move_sequence = self.agent.get_best_action(board, player_color, roll, rng);
+
+        match &move_sequence {
+            Some(ms) => println!(
+                "{} rolls {} and plays {}",
+                player_name(player_color),
+                format_roll(roll),
+                format_move_sequence(board, ms, player_color)
+            ),
+            None => println!(
+                "{} rolls {} and cannot move",
+                player_name(player_color),
+                format_roll(roll)
+            ),
+        }
+
+        move_sequence
+    }
+
+    fn should_double(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        cube_state: &CubeState,
+    ) -> bool {
+        let double = self.agent.should_double(board, player_color, cube_state);
+        if double {
+            println!(
+                "{} doubles to {}",
+                player_name(player_color),
+                cube_state.cube.value * 2
+            );
+        }
+        double
+    }
+
+    fn should_take(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        cube_state: &CubeState,
+    ) -> bool {
+        let take = self.agent.should_take(board, player_color, cube_state);
+        println!(
+            "{} {}",
+            player_name(player_color),
+            if take { "takes" } else { "drops" }
+        );
+        take
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::core::{moves::MoveWithDie, players::RED_PLAYER};
+
+    use super::*;
+
+    #[test]
+    fn test_match_move_sequence() {
+        let board = BackgammonBoard::default_board();
+        let roll = DiceRoll::Single(3, 1);
+        let move_sequences = generate_all_move_sequences(&board, BLACK_PLAYER, &roll);
+
+        let expected = MoveSequence::TwoMoves(
+            MoveWithDie::with_die(BLACK_PLAYER, 8, 3),
+            MoveWithDie::with_die(BLACK_PLAYER, 6, 1),
+        );
+        for input in ["8/5 6/5", "6/5 8/5"] {
+            let ms = match_move_sequence(&board, BLACK_PLAYER, &move_sequences, input).unwrap();
+            assert_eq!(ms, expected);
+        }
+
+        let ms = match_move_sequence(&board, BLACK_PLAYER, &move_sequences, "24/20").unwrap();
+        assert_eq!(
+            board.apply_move_sequence(&ms, BLACK_PLAYER),
+            board.apply_move_sequence(
+                &MoveSequence::TwoMoves(
+                    MoveWithDie::with_die(BLACK_PLAYER, 24, 3),
+                    MoveWithDie::with_die(BLACK_PLAYER, 21, 1),
+                ),
+                BLACK_PLAYER
+            )
+        );
+    }
+
+    #[test]
+    fn test_match_move_sequence_illegal() {
+        let board = BackgammonBoard::default_board();
+        let roll = DiceRoll::Single(3, 1);
+        let move_sequences = generate_all_move_sequences(&board, RED_PLAYER, &roll);
+
+        for input in ["8/5", "8/4 6/5", "7/4 6/5", "6/1 6/5", "nonsense"] {
+            assert!(match_move_sequence(&board, RED_PLAYER, &move_sequences, input).is_err());
+        }
+    }
+
+    #[test]
+    fn test_closed_input_resigns() {
+        let agent = HumanAgent::new();
+        assert_eq!(agent.answer(BLACK_PLAYER, Ok(Some(1))), Some(1));
+        assert!(!agent.has_resigned());
+
+        assert_eq!(agent.answer::<usize>(BLACK_PLAYER, Ok(None)), None);
+        assert!(agent.has_resigned());
+
+        // no more questions once resigned
+        let board = BackgammonBoard::default_board();
+        let roll = DiceRoll::Single(3, 1);
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        assert_eq!(
+            agent.get_best_action(&board, BLACK_PLAYER, &roll, &mut rng),
+            None
+        );
+    }
+}
@@ -1,4 +1,6 @@
 pub mod agent;
+pub mod human;
 pub mod logic;
 pub mod model;
 pub mod random;
+pub mod search;
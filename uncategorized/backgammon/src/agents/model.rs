@@ -1,27 +1,69 @@
-//
-// pub(crate) struct ModelAgent<B: Backend> {
-//     model: Model<B>,
-// }
-//
-// impl<B: Backend> ModelAgent<B> {
-//     pub(crate) fn new(device: &B::Device, input_size: usize, hidden_size: usize) -> Self {
-//         Self {
-//             model: ModelConfig::new(input_size, hidden_size).init(device),
-//         }
-//     }
-//     pub(crate) fn with_model(model: Model<B>) -> Self {
-//         Self { model }
-//     }
-// }
-//
-// impl<B: Backend> Agent for ModelAgent<B> {
-//     fn get_action(
-//         &self,
-//         board: &BackgammonBoard,
-//         player_color: PlayerColor,
-//         roll: &DiceRoll,
-//     ) -> Option<MoveSequence> {
-//         self.model.get_action(board, player_color, roll)
-//     }
-// }
-//
+use burn::tensor::backend::Backend;
+use rand::RngCore;
+
+use crate::{
+    core::{
+        board::BackgammonBoard,
+        cube::CubeState,
+        dice::DiceRoll,
+        moves::MoveSequence,
+        players::{get_opponent, PlayerColor},
+    },
+    models::model::{Model, ModelConfig},
+};
+
+use super::{agent::TestingAgent, search::BoardEvaluator};
+
+pub struct ModelAgent<B: Backend> {
+    model: Model<B>,
+}
+
+impl<B: Backend> ModelAgent<B> {
+    pub fn new(device: &B::Device, input_size: usize, hidden_size: usize) -> Self {
+        Self {
+            model: ModelConfig::new(input_size, hidden_size).init(device),
+        }
+    }
+
+    pub fn with_model(model: Model<B>) -> Self {
+        Self { model }
+    }
+}
+
+impl<B: Backend> TestingAgent for ModelAgent<B> {
+    fn get_best_action(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        roll: &DiceRoll,
+        _rng: &mut dyn RngCore,
+    ) -> Option<MoveSequence> {
+        self.model.get_action(board, player_color, roll)
+    }
+
+    fn should_double(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        cube_state: &CubeState,
+    ) -> bool {
+        cube_state.should_double(self.model.win_probability(board, player_color))
+    }
+
+    fn should_take(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        cube_state: &CubeState,
+    ) -> bool {
+        cube_state.should_take(self.model.win_probability(board, player_color))
+    }
+}
+
+impl<B: Backend> BoardEvaluator for ModelAgent<B> {
+    fn evaluate(&self, board: &BackgammonBoard, player_color: PlayerColor) -> f64 {
+        1.0 - self
+            .model
+            .win_probability(board, get_opponent(player_color))
+    }
+}
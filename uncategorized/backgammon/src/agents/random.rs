@@ -1,4 +1,4 @@
-use rand::seq::SliceRandom;
+use rand::{seq::SliceRandom, RngCore};
 
 use crate::core::{
     board::BackgammonBoard,
@@ -23,13 +23,14 @@ impl TestingAgent for RandomAgent {
         board: &BackgammonBoard,
         player_color: PlayerColor,
         roll: &DiceRoll,
+        rng: &mut dyn RngCore,
     ) -> Option<MoveSequence> {
-        let move_sequences = generate_all_move_sequences(board, player_color, roll, None);
+        let move_sequences = generate_all_move_sequences(board, player_color, roll);
 
         if move_sequences.len() == 0 {
             None
         } else {
-            move_sequences.choose(&mut rand::thread_rng()).cloned()
+            move_sequences.choose(rng).cloned()
         }
     }
 }
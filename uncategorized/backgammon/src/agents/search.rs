@@ -0,0 +1,366 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    time::{Duration, Instant},
+};
+
+use rand::RngCore;
+
+use crate::{
+    core::{
+        bearoff::bearoff_win_probability,
+        board::BackgammonBoard,
+        cube::CubeState,
+        dice::{all_dice_rolls, DiceRoll},
+        moves::{generate_all_move_sequences, MoveSequence},
+        players::{get_opponent, PlayerColor},
+    },
+    game::game::get_winner,
+};
+
+use super::agent::TestingAgent;
+
+/// Static evaluation of a position used by the search.
+pub trait BoardEvaluator {
+    /// Winning chances of `player_color` right after its play, with the opponent on roll.
+    fn evaluate(&self, board: &BackgammonBoard, player_color: PlayerColor) -> f64;
+}
+
+/// Values of the boards after the plays of `player_color` for a roll, in their order.
+///
+/// The winning chances of the bear-off database and the values of an evaluator are not
+/// on the same scale, so the database values the boards only when all of them are in it.
+pub(crate) fn evaluate_plays(
+    evaluator: &dyn BoardEvaluator,
+    boards: &[BackgammonBoard],
+    player_color: PlayerColor,
+) -> Vec<f64> {
+    boards
+        .iter()
+        .map(|board| bearoff_win_probability(board, player_color))
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_else(|| {
+            boards
+                .iter()
+                .map(|board| evaluator.evaluate(board, player_color))
+                .collect()
+        })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SearchConfig {
+    /// 1 scores the board after each play, 2 averages over the opponent rolls, 3 also over
+    /// the next own rolls
+    pub plies: usize,
+    /// plays searched deeper at each node, picked by their 1-ply ranking
+    pub width: usize,
+    /// prints the stats of every decision
+    pub verbose: bool,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self {
+            plies: 2,
+            width: 8,
+            verbose: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SearchStats {
+    /// positions evaluated or expanded
+    pub nodes: usize,
+    pub cache_hits: usize,
+    pub elapsed: Duration,
+}
+
+/// Expectiminimax over the dice: each play is valued by averaging, over the 21 distinct
+/// rolls, the best reply of the opponent, down to `plies` and then using the evaluator.
+pub struct SearchAgent {
+    evaluator: Box<dyn BoardEvaluator>,
+    config: SearchConfig,
+    /// values by board hash, player who just played and remaining depth
+    cache: RefCell<HashMap<(u64, PlayerColor, usize), f64>>,
+    /// the positions of the decision are valued by the bear-off database
+    bearoff: Cell<bool>,
+    stats: RefCell<SearchStats>,
+}
+
+impl SearchAgent {
+    pub fn new(evaluator: Box<dyn BoardEvaluator>, config: SearchConfig) -> Self {
+        assert!(config.plies > 0, "the search needs at least 1 ply");
+        assert!(config.width > 0, "the search needs to keep at least 1 play");
+
+        Self {
+            evaluator,
+            config,
+            cache: RefCell::new(HashMap::new()),
+            bearoff: Cell::new(false),
+            stats: RefCell::new(SearchStats::default()),
+        }
+    }
+
+    /// Stats of the last decision.
+    pub fn last_stats(&self) -> SearchStats {
+        *self.stats.borrow()
+    }
+
+    fn evaluate(&self, board: &BackgammonBoard, player_color: PlayerColor) -> f64 {
+        self.stats.borrow_mut().nodes += 1;
+        if get_winner(board).is_some() {
+            return 1.0;
+        }
+        self.bearoff
+            .get()
+            .then(|| bearoff_win_probability(board, player_color))
+            .flatten()
+            .unwrap_or_else(|| self.evaluator.evaluate(board, player_color))
+    }
+
+    /// Boards after each play of `move_sequences`, best first by 1-ply, keeping `width`.
+    fn ranked_plays(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        move_sequences: Vec<MoveSequence>,
+        width: usize,
+    ) -> Vec<(f64, BackgammonBoard, MoveSequence)> {
+        let mut plays: Vec<_> = move_sequences
+            .into_iter()
+            .map(|move_sequence| {
+                let board = board.apply_move_sequence(&move_sequence, player_color);
+                (self.evaluate(&board, player_color), board, move_sequence)
+            })
+            .collect();
+        plays.sort_by(|(x, _, _), (y, _, _)| y.partial_cmp(x).unwrap());
+        plays.truncate(width);
+        plays
+    }
+
+    /// Winning chances of `player_color`, who just played, searching `depth` more plies.
+    fn value_after_play(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        depth: usize,
+    ) -> f64 {
+        if depth == 0 || get_winner(board).is_some() {
+            return self.evaluate(board, player_color);
+        }
+
+        let mut hasher = DefaultHasher::new();
+        board.hash(&mut hasher);
+        let key = (hasher.finish(), player_color, depth);
+        if let Some(value) = self.cache.borrow().get(&key) {
+            self.stats.borrow_mut().cache_hits += 1;
+            return *value;
+        }
+        self.stats.borrow_mut().nodes += 1;
+
+        let opponent = get_opponent(player_color);
+        let value = all_dice_rolls()
+            .into_iter()
+            .map(|(roll, probability)| {
+                let move_sequences = generate_all_move_sequences(board, opponent, &roll);
+                // the opponent cannot move: the same position with the player on roll
+                let opponent_value = if move_sequences.is_empty() {
+                    self.value_after_play(board, opponent, depth - 1)
+                } else if depth == 1 {
+                    self.ranked_plays(board, opponent, move_sequences, 1)[0].0
+                } else {
+                    self.ranked_plays(board, opponent, move_sequences, self.config.width)
+                        .into_iter()
+                        .map(|(_, board, _)| self.value_after_play(&board, opponent, depth - 1))
+                        .fold(f64::MIN, f64::max)
+                };
+                probability * (1.0 - opponent_value)
+            })
+            .sum();
+
+        self.cache.borrow_mut().insert(key, value);
+        value
+    }
+
+    /// Winning chances of `player_color` when it is on roll, at 1-ply.
+    fn win_probability(&self, board: &BackgammonBoard, player_color: PlayerColor) -> f64 {
+        let opponent = get_opponent(player_color);
+        1.0 - bearoff_win_probability(board, opponent)
+            .unwrap_or_else(|| self.evaluator.evaluate(board, opponent))
+    }
+}
+
+impl TestingAgent for SearchAgent {
+    fn get_best_action(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        roll: &DiceRoll,
+        _rng: &mut dyn RngCore,
+    ) -> Option<MoveSequence> {
+        let start = Instant::now();
+        *self.stats.borrow_mut() = SearchStats::default();
+        self.cache.borrow_mut().clear();
+
+        let move_sequences = generate_all_move_sequences(board, player_color, roll);
+        if move_sequences.is_empty() {
+            return None;
+        }
+
+        // the plays of a bear-off lead to positions of the database again, so when all the
+        // plays are in it the whole search is valued on its scale, and otherwise never
+        self.bearoff.set(move_sequences.iter().all(|move_sequence| {
+            let board = board.apply_move_sequence(move_sequence, player_color);
+            bearoff_win_probability(&board, player_color).is_some()
+        }));
+
+        let plays = self.ranked_plays(board, player_color, move_sequences, self.config.width);
+        let best = plays
+            .into_iter()
+            .map(|(_, board, move_sequence)| {
+                let value = self.value_after_play(&board, player_color, self.config.plies - 1);
+                (value, move_sequence)
+            })
+            .max_by(|(x, _), (y, _)| x.partial_cmp(y).unwrap())
+            .map(|val| val.1);
+
+        let mut stats = self.stats.borrow_mut();
+        stats.elapsed = start.elapsed();
+        if self.config.verbose {
+            println!(
+                "{}-ply search: {} nodes, {} cache hits in {:?}",
+                self.config.plies, stats.nodes, stats.cache_hits, stats.elapsed
+            );
+        }
+
+        best
+    }
+
+    fn should_double(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        cube_state: &CubeState,
+    ) -> bool {
+        cube_state.should_double(self.win_probability(board, player_color))
+    }
+
+    fn should_take(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        cube_state: &CubeState,
+    ) -> bool {
+        cube_state.should_take(self.win_probability(board, player_color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::{
+        agents::logic::LogicAgent,
+        core::players::{b, r, BLACK_PLAYER, RED_PLAYER},
+    };
+
+    use super::*;
+
+    fn search_agent(plies: usize, width: usize) -> SearchAgent {
+        SearchAgent::new(
+            Box::new(LogicAgent::new_hard()),
+            SearchConfig {
+                plies,
+                width,
+                verbose: false,
+            },
+        )
+    }
+
+    #[test]
+    fn test_one_ply_matches_the_evaluator_ranking() {
+        let agent = search_agent(1, 100);
+        let board = BackgammonBoard::default_board();
+        let roll = DiceRoll::Single(3, 1);
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        let action = agent
+            .get_best_action(&board, RED_PLAYER, &roll, &mut rng)
+            .unwrap();
+        let best = generate_all_move_sequences(&board, RED_PLAYER, &roll)
+            .into_iter()
+            .map(|move_sequence| {
+                let board = board.apply_move_sequence(&move_sequence, RED_PLAYER);
+                LogicAgent::new_hard().evaluate(&board, RED_PLAYER)
+            })
+            .fold(f64::MIN, f64::max);
+        let board_after = board.apply_move_sequence(&action, RED_PLAYER);
+
+        assert_eq!(
+            LogicAgent::new_hard().evaluate(&board_after, RED_PLAYER),
+            best
+        );
+        assert_eq!(agent.last_stats().cache_hits, 0);
+    }
+
+    #[test]
+    fn test_two_ply_searches_the_opponent_rolls() {
+        let agent = search_agent(2, 3);
+        let board = BackgammonBoard::default_board();
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        let action = agent.get_best_action(&board, BLACK_PLAYER, &DiceRoll::Single(6, 5), &mut rng);
+        assert!(action.is_some());
+
+        // 3 plays, each one with 21 opponent rolls
+        let stats = agent.last_stats();
+        assert!(stats.nodes > 3 * 21);
+    }
+
+    #[test]
+    fn test_cache_hits_on_transpositions() {
+        let agent = search_agent(3, 2);
+        let board = BackgammonBoard::default_board();
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        agent.get_best_action(&board, RED_PLAYER, &DiceRoll::Single(2, 1), &mut rng);
+        assert!(agent.last_stats().cache_hits > 0);
+    }
+
+    struct ConstantEvaluator;
+
+    impl BoardEvaluator for ConstantEvaluator {
+        fn evaluate(&self, _: &BackgammonBoard, _: PlayerColor) -> f64 {
+            7.0
+        }
+    }
+
+    #[test]
+    fn test_evaluate_plays_keeps_one_scale() {
+        let race = BackgammonBoard::from_pairs(&[(24, r(2)), (25, r(13)), (1, b(15))]);
+        let contact = BackgammonBoard::default_board();
+
+        // whether the database is generated or not, a play out of it is enough to value
+        // all the plays with the evaluator
+        assert_eq!(
+            evaluate_plays(&ConstantEvaluator, &[race, contact.clone()], RED_PLAYER),
+            vec![7.0, 7.0]
+        );
+        assert_eq!(
+            evaluate_plays(&ConstantEvaluator, &[contact], RED_PLAYER),
+            vec![7.0]
+        );
+    }
+
+    #[test]
+    fn test_value_of_a_won_race() {
+        // red bears off its last checker with any roll
+        let board = BackgammonBoard::from_pairs(&[(24, r(1)), (25, r(14)), (1, b(15))]);
+        let agent = search_agent(2, 8);
+
+        assert_eq!(agent.value_after_play(&board, BLACK_PLAYER, 1), 0.0);
+    }
+}
@@ -0,0 +1,46 @@
+use rand::RngCore;
+
+use crate::{agents::agent::TestingAgent, core::dice::DiceRoll};
+
+use super::{
+    board::Board,
+    types::{Play, PlayerColor},
+};
+
+/// Something that picks the plays of a player.
+///
+/// Every agent of the crate is one through `TestingAgent`.
+pub trait Agent {
+    /// Index in `plays` of the play to make with `roll`. `plays` holds the legal plays
+    /// and is never empty.
+    fn choose_play(
+        &mut self,
+        board: &Board,
+        player_color: PlayerColor,
+        roll: DiceRoll,
+        plays: &[Play],
+        rng: &mut dyn RngCore,
+    ) -> usize;
+}
+
+impl<T: TestingAgent + ?Sized> Agent for T {
+    fn choose_play(
+        &mut self,
+        board: &Board,
+        player_color: PlayerColor,
+        roll: DiceRoll,
+        plays: &[Play],
+        rng: &mut dyn RngCore,
+    ) -> usize {
+        let Some(move_sequence) =
+            self.get_best_action(board.inner(), player_color.raw(), &roll, rng)
+        else {
+            return 0;
+        };
+        let best = board.apply(player_color, &Play::from(&move_sequence));
+        plays
+            .iter()
+            .position(|play| board.apply(player_color, play) == best)
+            .unwrap_or(0)
+    }
+}
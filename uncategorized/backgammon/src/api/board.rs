@@ -0,0 +1,185 @@
+use crate::{
+    core::{
+        board::BackgammonBoard, dice::DiceRoll, moves::generate_all_move_sequences,
+        scores::get_pip_count,
+    },
+    game::{
+        game::get_winner,
+        gnubg_id::{decode_position_id, encode_position_id},
+        notation::format_move_sequence,
+    },
+};
+
+use super::types::{Play, PlayerColor, Position};
+
+const CHECKERS: u8 = 15;
+
+/// The checkers of both players, see `Position` for how the board is numbered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Board {
+    board: BackgammonBoard,
+}
+
+impl Board {
+    /// The starting position.
+    pub fn new() -> Self {
+        Self {
+            board: BackgammonBoard::default_board(),
+        }
+    }
+
+    /// A board without checkers, to be set up with `set_checkers`.
+    pub fn empty() -> Self {
+        Self {
+            board: BackgammonBoard::from_pairs(&[]),
+        }
+    }
+
+    /// Board of a GNU Backgammon Position ID.
+    pub fn from_position_id(id: &str, player_on_roll: PlayerColor) -> Result<Self, String> {
+        decode_position_id(id, player_on_roll.raw()).map(Self::from)
+    }
+
+    pub fn position_id(&self, player_on_roll: PlayerColor) -> String {
+        encode_position_id(&self.board, player_on_roll.raw())
+    }
+
+    pub fn checkers(&self, player_color: PlayerColor, pos: Position) -> u8 {
+        self.board.checkers_at(player_color.raw(), pos.index()) as u8
+    }
+
+    /// Puts `count` checkers of `player_color` at `pos`, replacing the checkers that were
+    /// there. The checkers borne off are not updated.
+    ///
+    /// # Panics
+    ///
+    /// If `count` is more than 15.
+    pub fn set_checkers(&mut self, player_color: PlayerColor, pos: Position, count: u8) {
+        assert!(count <= CHECKERS, "a player has 15 checkers, got {}", count);
+        self.board
+            .set(pos.index(), player_color.raw() * count as i8);
+    }
+
+    pub fn bar(&self, player_color: PlayerColor) -> u8 {
+        self.checkers(player_color, Position::bar(player_color))
+    }
+
+    pub fn off(&self, player_color: PlayerColor) -> u8 {
+        self.checkers(player_color, Position::off(player_color))
+    }
+
+    /// Pips `player_color` needs to bear off all its checkers.
+    pub fn pip_count(&self, player_color: PlayerColor) -> u32 {
+        get_pip_count(&self.board, player_color.raw()) as u32
+    }
+
+    pub fn winner(&self) -> Option<PlayerColor> {
+        get_winner(&self.board).map(PlayerColor::from_raw)
+    }
+
+    /// Every legal play of `roll`, empty when `player_color` cannot move.
+    pub fn legal_plays(&self, player_color: PlayerColor, roll: DiceRoll) -> Vec<Play> {
+        generate_all_move_sequences(&self.board, player_color.raw(), &roll)
+            .iter()
+            .map(Play::from)
+            .collect()
+    }
+
+    /// The board after `play`, without checking it is legal.
+    pub fn apply(&self, player_color: PlayerColor, play: &Play) -> Self {
+        match play.to_move_sequence() {
+            Some(move_sequence) => self
+                .board
+                .apply_move_sequence(&move_sequence, player_color.raw())
+                .into(),
+            None => self.clone(),
+        }
+    }
+
+    /// `play` in standard notation, like "13/11 6/5*".
+    pub fn format_play(&self, player_color: PlayerColor, play: &Play) -> String {
+        match play.to_move_sequence() {
+            Some(move_sequence) => {
+                format_move_sequence(&self.board, &move_sequence, player_color.raw())
+            }
+            None => String::new(),
+        }
+    }
+
+    pub(crate) fn inner(&self) -> &BackgammonBoard {
+        &self.board
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<BackgammonBoard> for Board {
+    fn from(board: BackgammonBoard) -> Self {
+        Self { board }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starting_board() {
+        let board = Board::new();
+
+        for player_color in [PlayerColor::Black, PlayerColor::Red] {
+            let six_point = Position::from_point(player_color, 6).unwrap();
+            assert_eq!(board.checkers(player_color, six_point), 5);
+            assert_eq!(board.checkers(player_color.opponent(), six_point), 0);
+            assert_eq!(board.pip_count(player_color), 167);
+            assert_eq!(board.bar(player_color), 0);
+            assert_eq!(board.off(player_color), 0);
+        }
+        assert_eq!(board.winner(), None);
+        assert_eq!(board.position_id(PlayerColor::Black), "4HPwATDgc/ABMA");
+    }
+
+    #[test]
+    fn test_set_checkers() {
+        let mut board = Board::empty();
+        board.set_checkers(PlayerColor::Red, Position::off(PlayerColor::Red), 15);
+        assert_eq!(board.winner(), Some(PlayerColor::Red));
+
+        board.set_checkers(PlayerColor::Black, Position::new(3).unwrap(), 2);
+        assert_eq!(board.pip_count(PlayerColor::Black), 6);
+    }
+
+    #[test]
+    fn test_legal_plays_and_apply() {
+        let board = Board::new();
+        let plays = board.legal_plays(PlayerColor::Black, DiceRoll::new(3, 1));
+
+        let five_point = Position::from_point(PlayerColor::Black, 5).unwrap();
+        let play = plays
+            .iter()
+            .find(|play| {
+                board
+                    .apply(PlayerColor::Black, play)
+                    .checkers(PlayerColor::Black, five_point)
+                    == 2
+            })
+            .unwrap();
+        let mut notation = board.format_play(PlayerColor::Black, play);
+        assert!(
+            notation == "8/5 6/5" || notation == "6/5 8/5",
+            "{}",
+            notation
+        );
+
+        let board = board.apply(PlayerColor::Black, play);
+        notation = board.format_play(PlayerColor::Red, &Play::default());
+        assert!(notation.is_empty());
+
+        // the empty play leaves the board as it was
+        assert_eq!(board.apply(PlayerColor::Red, &Play::default()), board);
+    }
+}
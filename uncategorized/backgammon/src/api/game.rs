@@ -0,0 +1,265 @@
+use std::fmt;
+
+use crate::{
+    core::dice::DiceRoll,
+    game::{game::get_victory_type, game::VictoryType, rng::GameRng},
+};
+
+use super::{
+    agent::Agent,
+    board::Board,
+    types::{Play, PlayerColor},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameError {
+    /// the dice were already rolled this turn
+    AlreadyRolled,
+    /// a play was made before rolling
+    NotRolled,
+    /// the play is not one of the legal plays of the roll
+    IllegalPlay,
+    GameOver,
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::AlreadyRolled => write!(f, "the dice were already rolled"),
+            GameError::NotRolled => write!(f, "the dice must be rolled before playing"),
+            GameError::IllegalPlay => write!(f, "the play is not legal"),
+            GameError::GameOver => write!(f, "the game is over"),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+/// A game without doubling cube, played a turn at a time: the player on turn rolls, with
+/// `roll_dice` or `set_roll` for dice rolled elsewhere, and then makes one of the
+/// `legal_plays`.
+pub struct Game {
+    board: Board,
+    turn: PlayerColor,
+    roll: Option<DiceRoll>,
+    turns: usize,
+    rng: GameRng,
+}
+
+impl Game {
+    /// A game from the starting position, the first player and the dice drawn from `seed`.
+    pub fn new(seed: u64) -> Self {
+        let mut rng = GameRng::from_seed(seed);
+        let turn = PlayerColor::from_raw(rng.first_player());
+
+        Self {
+            board: Board::new(),
+            turn,
+            roll: None,
+            turns: 0,
+            rng,
+        }
+    }
+
+    /// A game from `board` with `turn` to roll.
+    pub fn from_position(board: Board, turn: PlayerColor, seed: u64) -> Self {
+        Self {
+            board,
+            turn,
+            roll: None,
+            turns: 0,
+            rng: GameRng::from_seed(seed),
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Player to roll or to play.
+    pub fn turn(&self) -> PlayerColor {
+        self.turn
+    }
+
+    /// Roll of the player on turn, `None` until it rolls.
+    pub fn roll(&self) -> Option<DiceRoll> {
+        self.roll
+    }
+
+    /// Plays made so far.
+    pub fn turns(&self) -> usize {
+        self.turns
+    }
+
+    pub fn winner(&self) -> Option<(PlayerColor, VictoryType)> {
+        self.board
+            .winner()
+            .map(|winner| (winner, get_victory_type(self.board.inner(), winner.raw())))
+    }
+
+    pub fn roll_dice(&mut self) -> Result<DiceRoll, GameError> {
+        let roll = self.rng.roll_dice();
+        self.set_roll(roll)?;
+        Ok(roll)
+    }
+
+    pub fn set_roll(&mut self, roll: DiceRoll) -> Result<(), GameError> {
+        if self.winner().is_some() {
+            return Err(GameError::GameOver);
+        }
+        if self.roll.is_some() {
+            return Err(GameError::AlreadyRolled);
+        }
+        self.roll = Some(roll);
+        Ok(())
+    }
+
+    /// Legal plays of the roll, empty before rolling or when the player cannot move.
+    pub fn legal_plays(&self) -> Vec<Play> {
+        match self.roll {
+            Some(roll) => self.board.legal_plays(self.turn, roll),
+            None => Vec::new(),
+        }
+    }
+
+    /// Makes `play` and passes the turn. The empty play passes when there are no legal
+    /// plays. A play is legal when it leaves the same board as a legal play, whatever the
+    /// order of its moves.
+    pub fn play(&mut self, play: &Play) -> Result<(), GameError> {
+        if self.winner().is_some() {
+            return Err(GameError::GameOver);
+        }
+        if self.roll.is_none() {
+            return Err(GameError::NotRolled);
+        }
+
+        let board = self.board.apply(self.turn, play);
+        let legal_plays = self.legal_plays();
+        let is_legal = if legal_plays.is_empty() {
+            play.is_empty()
+        } else {
+            legal_plays
+                .iter()
+                .any(|legal_play| self.board.apply(self.turn, legal_play) == board)
+        };
+        if !is_legal {
+            return Err(GameError::IllegalPlay);
+        }
+
+        self.board = board;
+        self.roll = None;
+        self.turns += 1;
+        if self.winner().is_none() {
+            self.turn = self.turn.opponent();
+        }
+        Ok(())
+    }
+
+    /// Plays the game to the end with the dice of the game and returns the winner.
+    pub fn run(
+        &mut self,
+        black: &mut dyn Agent,
+        red: &mut dyn Agent,
+    ) -> (PlayerColor, VictoryType) {
+        loop {
+            if let Some(winner) = self.winner() {
+                return winner;
+            }
+            if self.roll.is_none() {
+                self.roll_dice().unwrap();
+            }
+
+            let plays = self.legal_plays();
+            let play = if plays.is_empty() {
+                Play::default()
+            } else {
+                let agent: &mut dyn Agent = match self.turn {
+                    PlayerColor::Black => &mut *black,
+                    PlayerColor::Red => &mut *red,
+                };
+                let index = agent.choose_play(
+                    &self.board,
+                    self.turn,
+                    self.roll.unwrap(),
+                    &plays,
+                    self.rng.agent_rng(),
+                );
+                plays[index.min(plays.len() - 1)].clone()
+            };
+            self.play(&play).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        agents::random::RandomAgent,
+        api::types::{Move, Position},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_turn_state_machine() {
+        let mut game = Game::from_position(Board::new(), PlayerColor::Black, 0);
+        assert_eq!(game.play(&Play::default()), Err(GameError::NotRolled));
+        assert!(game.legal_plays().is_empty());
+
+        game.set_roll(DiceRoll::new(6, 5)).unwrap();
+        assert_eq!(game.roll_dice(), Err(GameError::AlreadyRolled));
+        assert_eq!(game.play(&Play::default()), Err(GameError::IllegalPlay));
+
+        // 24/13 played in the other order than generated
+        let pos = |index| Position::new(index).unwrap();
+        let play = Play::new(vec![
+            Move {
+                from: pos(24),
+                to: pos(18),
+                die: 6,
+            },
+            Move {
+                from: pos(18),
+                to: pos(13),
+                die: 5,
+            },
+        ])
+        .unwrap();
+        game.play(&play).unwrap();
+
+        assert_eq!(game.board().checkers(PlayerColor::Black, pos(13)), 6);
+        assert_eq!(game.turn(), PlayerColor::Red);
+        assert_eq!(game.roll(), None);
+        assert_eq!(game.turns(), 1);
+    }
+
+    #[test]
+    fn test_pass_without_legal_plays() {
+        // red closed out on the bar against a closed board
+        let mut board = Board::empty();
+        for point in 1..=6 {
+            let pos = Position::from_point(PlayerColor::Black, point).unwrap();
+            board.set_checkers(PlayerColor::Black, pos, 2);
+        }
+        board.set_checkers(PlayerColor::Red, Position::bar(PlayerColor::Red), 15);
+
+        let mut game = Game::from_position(board.clone(), PlayerColor::Red, 0);
+        game.roll_dice().unwrap();
+        assert!(game.legal_plays().is_empty());
+        game.play(&Play::default()).unwrap();
+
+        assert_eq!(game.board(), &board);
+        assert_eq!(game.turn(), PlayerColor::Black);
+    }
+
+    #[test]
+    fn test_run_to_the_end() {
+        let mut game = Game::new(7);
+        let (winner, _) = game.run(&mut RandomAgent::new(), &mut RandomAgent::new());
+
+        assert_eq!(game.winner().map(|(winner, _)| winner), Some(winner));
+        assert_eq!(game.board().off(winner), 15);
+        assert_eq!(game.turn(), winner);
+        assert_eq!(game.roll_dice(), Err(GameError::GameOver));
+    }
+}
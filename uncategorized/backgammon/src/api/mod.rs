@@ -0,0 +1,23 @@
+//! Public API to set up boards, generate and make plays and drive games from other
+//! crates, for example to write agents. It wraps the internals of the crate, which can
+//! change without notice.
+//!
+//! ```
+//! use backgammon::api::{Board, DiceRoll, PlayerColor};
+//!
+//! let board = Board::new();
+//! let plays = board.legal_plays(PlayerColor::Black, DiceRoll::new(3, 1));
+//! let board = board.apply(PlayerColor::Black, &plays[0]);
+//! assert_eq!(board.pip_count(PlayerColor::Black), 163);
+//! ```
+
+mod agent;
+mod board;
+mod game;
+mod types;
+
+pub use crate::{core::dice::DiceRoll, game::game::VictoryType};
+pub use agent::Agent;
+pub use board::Board;
+pub use game::{Game, GameError};
+pub use types::{Move, Play, PlayerColor, Position};
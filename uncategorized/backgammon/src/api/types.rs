@@ -0,0 +1,251 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    moves::{MoveSequence, MoveWithDie},
+    players::{self, BLACK_PLAYER, RED_PLAYER},
+    positions::{self, bar_pos, norm_pos, BLACK_OFF},
+};
+
+const BAR_POINT: u8 = 25;
+const OFF_POINT: u8 = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerColor {
+    /// moves from 24 to 1 and bears off to 0, its checkers are printed as `X`
+    Black,
+    /// moves from 1 to 24 and bears off to 25, its checkers are printed as `O`
+    Red,
+}
+
+impl PlayerColor {
+    pub fn opponent(self) -> Self {
+        match self {
+            PlayerColor::Black => PlayerColor::Red,
+            PlayerColor::Red => PlayerColor::Black,
+        }
+    }
+
+    pub(crate) fn raw(self) -> players::PlayerColor {
+        match self {
+            PlayerColor::Black => BLACK_PLAYER,
+            PlayerColor::Red => RED_PLAYER,
+        }
+    }
+
+    pub(crate) fn from_raw(player_color: players::PlayerColor) -> Self {
+        if player_color == BLACK_PLAYER {
+            PlayerColor::Black
+        } else {
+            PlayerColor::Red
+        }
+    }
+}
+
+impl fmt::Display for PlayerColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlayerColor::Black => write!(f, "Black"),
+            PlayerColor::Red => write!(f, "Red"),
+        }
+    }
+}
+
+/// A place for checkers on the board: the 24 points, the bar and the checkers borne off
+/// of each player.
+///
+/// Positions are absolute, numbered as printed by the board: 1 to 24 from black's home
+/// board, 0 and 25 for the checkers borne off by black and red, 26 and 27 for their bars.
+/// Use `from_point` to name them from the point of view of a player instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position(u8);
+
+impl Position {
+    /// Number of positions, including the bars and the checkers borne off.
+    pub const COUNT: usize = 28;
+
+    pub fn new(index: usize) -> Option<Self> {
+        (index < Self::COUNT).then_some(Self(index as u8))
+    }
+
+    /// Position of `point` as numbered by `player_color`: its home board is 1 to 6, the bar
+    /// is 25 and its checkers borne off are at 0.
+    pub fn from_point(player_color: PlayerColor, point: u8) -> Option<Self> {
+        match point {
+            BAR_POINT => Some(Self::bar(player_color)),
+            point if point < BAR_POINT => Some(Self::from_raw(norm_pos(
+                point as positions::Position,
+                player_color.raw(),
+            ))),
+            _ => None,
+        }
+    }
+
+    pub fn bar(player_color: PlayerColor) -> Self {
+        Self::from_raw(bar_pos(player_color.raw()))
+    }
+
+    pub fn off(player_color: PlayerColor) -> Self {
+        Self::from_raw(norm_pos(BLACK_OFF, player_color.raw()))
+    }
+
+    /// The 24 points, from 1 to 24.
+    pub fn points() -> impl Iterator<Item = Self> {
+        (1..=24).map(Self)
+    }
+
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Inverse of `from_point`, `None` for the bar and the checkers borne off by the
+    /// opponent.
+    pub fn point(self, player_color: PlayerColor) -> Option<u8> {
+        if self == Self::bar(player_color) {
+            Some(BAR_POINT)
+        } else if self == Self::off(player_color) {
+            Some(OFF_POINT)
+        } else if (1..=24).contains(&self.0) {
+            Some(norm_pos(self.index(), player_color.raw()) as u8)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn from_raw(pos: positions::Position) -> Self {
+        Self(pos as u8)
+    }
+}
+
+/// A checker moved with one die.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Move {
+    pub from: Position,
+    pub to: Position,
+    pub die: u8,
+}
+
+impl From<&MoveWithDie> for Move {
+    fn from(m: &MoveWithDie) -> Self {
+        Self {
+            from: Position::from_raw(m.src),
+            to: Position::from_raw(m.dst),
+            die: m.die,
+        }
+    }
+}
+
+impl From<Move> for MoveWithDie {
+    fn from(m: Move) -> Self {
+        Self {
+            src: m.from.index(),
+            dst: m.to.index(),
+            die: m.die,
+        }
+    }
+}
+
+/// The moves of a turn, empty when the player cannot move.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Play {
+    moves: Vec<Move>,
+}
+
+impl Play {
+    /// A play of up to 4 moves, the legality is checked when it is played.
+    pub fn new(moves: Vec<Move>) -> Option<Self> {
+        (moves.len() <= 4).then_some(Self { moves })
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    pub(crate) fn to_move_sequence(&self) -> Option<MoveSequence> {
+        let mut moves = self.moves.iter().map(|m| MoveWithDie::from(*m));
+        let move_sequence = match self.moves.len() {
+            0 => return None,
+            1 => MoveSequence::SingleMove(moves.next()?),
+            2 => MoveSequence::TwoMoves(moves.next()?, moves.next()?),
+            3 => MoveSequence::ThreeMoves(moves.next()?, moves.next()?, moves.next()?),
+            _ => {
+                MoveSequence::FourMoves(moves.next()?, moves.next()?, moves.next()?, moves.next()?)
+            }
+        };
+        Some(move_sequence)
+    }
+}
+
+impl From<&MoveSequence> for Play {
+    fn from(move_sequence: &MoveSequence) -> Self {
+        Self {
+            moves: move_sequence.iter().map(Move::from).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_from_point() {
+        assert_eq!(
+            Position::from_point(PlayerColor::Black, 6),
+            Position::new(6)
+        );
+        assert_eq!(Position::from_point(PlayerColor::Red, 6), Position::new(19));
+        assert_eq!(
+            Position::from_point(PlayerColor::Red, 25),
+            Some(Position::bar(PlayerColor::Red))
+        );
+        assert_eq!(
+            Position::from_point(PlayerColor::Red, 0),
+            Some(Position::off(PlayerColor::Red))
+        );
+        assert_eq!(Position::from_point(PlayerColor::Black, 26), None);
+        assert_eq!(Position::new(28), None);
+    }
+
+    #[test]
+    fn test_position_point() {
+        for player_color in [PlayerColor::Black, PlayerColor::Red] {
+            for point in 0..=25 {
+                let pos = Position::from_point(player_color, point).unwrap();
+                assert_eq!(pos.point(player_color), Some(point));
+            }
+        }
+        assert_eq!(
+            Position::off(PlayerColor::Red).point(PlayerColor::Black),
+            None
+        );
+        assert_eq!(Position::points().count(), 24);
+    }
+
+    #[test]
+    fn test_play_roundtrip() {
+        let play = Play::new(vec![
+            Move {
+                from: Position::new(24).unwrap(),
+                to: Position::new(22).unwrap(),
+                die: 2,
+            },
+            Move {
+                from: Position::new(13).unwrap(),
+                to: Position::new(10).unwrap(),
+                die: 3,
+            },
+        ])
+        .unwrap();
+
+        assert_eq!(Play::from(&play.to_move_sequence().unwrap()), play);
+        assert!(Play::default().to_move_sequence().is_none());
+        assert_eq!(Play::new(vec![play.moves()[0]; 5]), None);
+    }
+}
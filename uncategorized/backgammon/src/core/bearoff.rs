@@ -0,0 +1,414 @@
+use std::{
+    collections::HashSet,
+    env, fs,
+    io::{self, Read, Write},
+    path::Path,
+};
+
+use lazy_static::lazy_static;
+
+use super::{
+    board::BackgammonBoard,
+    dice::{all_dice_rolls, DiceRoll},
+    players::{get_opponent, PlayerColor},
+    positions::{bar_pos, norm_pos, PosIter},
+    scores::is_race,
+};
+
+const HOME_POINTS: usize = 6;
+pub const MAX_CHECKERS: u8 = 15;
+
+const MAGIC: &[u8; 4] = b"BGBO";
+/// expected rolls are stored as fixed point numbers
+const SCALE: f32 = 4096.0;
+
+/// Default location of the database, overridden with the `BACKGAMMON_BEAROFF` variable.
+pub const DEFAULT_PATH: &str = "bearoff.db";
+
+/// One side of a bear-off: the checkers on each home point, the ace point first.
+pub(crate) type HomeBoard = [u8; HOME_POINTS];
+
+lazy_static! {
+    static ref DATABASE: Option<BearoffDatabase> = {
+        let path = env::var("BACKGAMMON_BEAROFF").unwrap_or(DEFAULT_PATH.to_string());
+        BearoffDatabase::load(path).ok()
+    };
+}
+
+/// The database at [`DEFAULT_PATH`], `None` when it has not been generated.
+pub(crate) fn bearoff_database() -> Option<&'static BearoffDatabase> {
+    DATABASE.as_ref()
+}
+
+/// One-sided bear-off database: the expected number of rolls to bear off every
+/// distribution of up to `max_checkers` checkers on the home board, playing to minimize it.
+pub struct BearoffDatabase {
+    max_checkers: u8,
+    expected_rolls: Vec<f32>,
+}
+
+impl BearoffDatabase {
+    pub fn generate(max_checkers: u8) -> Self {
+        assert!(
+            max_checkers <= MAX_CHECKERS,
+            "a player has at most {MAX_CHECKERS} checkers"
+        );
+
+        let mut positions = Vec::with_capacity(positions_count(max_checkers));
+        collect_positions(&mut [0; HOME_POINTS], 0, max_checkers, &mut positions);
+        // every play lowers the pip count, so the positions after it are already solved
+        positions.sort_by_key(pip_count);
+
+        let mut database = Self {
+            max_checkers,
+            expected_rolls: vec![0.0; positions_count(max_checkers)],
+        };
+        let rolls = all_dice_rolls();
+        for home in positions.iter().skip(1) {
+            let expected_rolls = 1.0
+                + rolls
+                    .iter()
+                    .map(|(roll, probability)| {
+                        let best = plays(home, roll)
+                            .iter()
+                            .map(|home| database.expected_rolls[database.index(home)])
+                            .fold(f32::MAX, f32::min);
+                        *probability as f32 * best
+                    })
+                    .sum::<f32>();
+            let index = database.index(home);
+            database.expected_rolls[index] = expected_rolls;
+        }
+
+        database
+    }
+
+    pub fn max_checkers(&self) -> u8 {
+        self.max_checkers
+    }
+
+    /// Expected rolls to bear off all the checkers of `home`, 0 when all are off. `None`
+    /// when `home` has more checkers than the database.
+    pub(crate) fn expected_rolls(&self, home: &HomeBoard) -> Option<f32> {
+        let checkers = home.iter().map(|count| *count as usize).sum::<usize>();
+        if checkers > self.max_checkers as usize {
+            return None;
+        }
+        Some(self.expected_rolls[self.index(home)])
+    }
+
+    /// Rank of `home` among the positions with up to `max_checkers`, seen as the places of
+    /// the 6 bars in a stars and bars string of `max_checkers` stars.
+    fn index(&self, home: &HomeBoard) -> usize {
+        let mut checkers = 0;
+        let mut index = 0;
+        for (point, count) in home.iter().enumerate() {
+            checkers += *count as usize;
+            index += binomial(checkers + point, point + 1);
+        }
+        assert!(
+            checkers <= self.max_checkers as usize,
+            "the database only has positions with up to {} checkers",
+            self.max_checkers
+        );
+        index
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut bytes = Vec::with_capacity(5 + self.expected_rolls.len() * 2);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(self.max_checkers);
+        for expected_rolls in &self.expected_rolls {
+            let value = (expected_rolls * SCALE).round() as u16;
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        fs::File::create(path)?.write_all(&bytes)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        fs::File::open(path)?.read_to_end(&mut bytes)?;
+
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+        if bytes.len() < 5 || &bytes[..4] != MAGIC {
+            return Err(invalid("not a bear-off database"));
+        }
+        let max_checkers = bytes[4];
+        if max_checkers > MAX_CHECKERS || bytes.len() != 5 + positions_count(max_checkers) * 2 {
+            return Err(invalid("the bear-off database is corrupted"));
+        }
+
+        let expected_rolls = bytes[5..]
+            .chunks_exact(2)
+            .map(|value| u16::from_le_bytes([value[0], value[1]]) as f32 / SCALE)
+            .collect();
+        Ok(Self {
+            max_checkers,
+            expected_rolls,
+        })
+    }
+}
+
+fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+fn positions_count(max_checkers: u8) -> usize {
+    binomial(max_checkers as usize + HOME_POINTS, HOME_POINTS)
+}
+
+fn collect_positions(
+    home: &mut HomeBoard,
+    point: usize,
+    checkers_left: u8,
+    positions: &mut Vec<HomeBoard>,
+) {
+    if point == HOME_POINTS {
+        positions.push(*home);
+        return;
+    }
+    for count in 0..=checkers_left {
+        home[point] = count;
+        collect_positions(home, point + 1, checkers_left - count, positions);
+    }
+    home[point] = 0;
+}
+
+fn pip_count(home: &HomeBoard) -> usize {
+    home.iter()
+        .enumerate()
+        .map(|(point, count)| (point + 1) * *count as usize)
+        .sum()
+}
+
+/// Moves a checker from `point` (1 based) with `die`, if the bear-off rules allow it.
+fn play_die(home: &HomeBoard, point: usize, die: usize) -> Option<HomeBoard> {
+    if home[point - 1] == 0 {
+        return None;
+    }
+    let highest = home.iter().rposition(|count| *count > 0).unwrap() + 1;
+    if point < die && point != highest {
+        return None;
+    }
+
+    let mut home = *home;
+    home[point - 1] -= 1;
+    if point > die {
+        home[point - die - 1] += 1;
+    }
+    Some(home)
+}
+
+/// Positions after every legal play of `roll`. With all the checkers at home every die
+/// can be played until they are all off.
+fn plays(home: &HomeBoard, roll: &DiceRoll) -> HashSet<HomeBoard> {
+    fn play_dice(
+        home: HomeBoard,
+        dice: &[usize],
+        max_point: usize,
+        plays: &mut HashSet<HomeBoard>,
+    ) {
+        let Some((die, dice_left)) = dice.split_first() else {
+            plays.insert(home);
+            return;
+        };
+        if home.iter().all(|count| *count == 0) {
+            plays.insert(home);
+            return;
+        }
+        for point in 1..=max_point {
+            if let Some(next) = play_die(&home, point, *die) {
+                // a repeated die is played from the same or lower points only, playing the
+                // higher checkers first gives all the positions of the other orders
+                let max_point = if dice_left.first() == Some(die) {
+                    point
+                } else {
+                    HOME_POINTS
+                };
+                play_dice(next, dice_left, max_point, plays);
+            }
+        }
+    }
+
+    let mut result = HashSet::new();
+    match roll {
+        DiceRoll::Single(die1, die2) => {
+            let (die1, die2) = (*die1 as usize, *die2 as usize);
+            play_dice(*home, &[die1, die2], HOME_POINTS, &mut result);
+            play_dice(*home, &[die2, die1], HOME_POINTS, &mut result);
+        }
+        DiceRoll::Double(die) => play_dice(*home, &[*die as usize; 4], HOME_POINTS, &mut result),
+    }
+    result
+}
+
+/// The checkers of `player_color` on its home points, `None` if any is still outside.
+fn home_board(board: &BackgammonBoard, player_color: PlayerColor) -> Option<HomeBoard> {
+    if board.checkers_at(player_color, bar_pos(player_color)) > 0 {
+        return None;
+    }
+    let outside = PosIter::full_board(player_color, None)
+        .filter(|pos| norm_pos(*pos, player_color) > HOME_POINTS)
+        .any(|pos| board.checkers_at(player_color, pos) > 0);
+    if outside {
+        return None;
+    }
+
+    let mut home = [0; HOME_POINTS];
+    for (point, count) in home.iter_mut().enumerate() {
+        *count = board.checkers_at(player_color, norm_pos(point + 1, player_color)) as u8;
+    }
+    Some(home)
+}
+
+/// Winning chances of `player_color` right after its play when both players are bearing
+/// off, from the expected rolls in the database. Being on roll is worth half a roll to the
+/// opponent, and the spread of the difference grows with the length of the race.
+pub(crate) fn bearoff_win_probability(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+) -> Option<f64> {
+    database_win_probability(bearoff_database()?, board, player_color)
+}
+
+/// `None` unless both sides are in the database.
+fn database_win_probability(
+    database: &BearoffDatabase,
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+) -> Option<f64> {
+    if !is_race(board) {
+        return None;
+    }
+    let opponent = get_opponent(player_color);
+    let own = database.expected_rolls(&home_board(board, player_color)?)? as f64;
+    let opp = database.expected_rolls(&home_board(board, opponent)?)? as f64;
+
+    Some(race_win_probability(own, opp))
+}
+
+fn race_win_probability(own: f64, opp: f64) -> f64 {
+    if own == 0.0 {
+        return 1.0;
+    }
+    // the opponent is on roll and gets off for sure
+    if opp <= 1.0 {
+        return 0.0;
+    }
+    let spread = 0.35 * (own + opp).sqrt();
+    let diff = (own - opp + 0.5) / spread;
+    // logistic approximation of the normal distribution
+    1.0 / (1.0 + (1.702 * diff).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::players::{b, r, BLACK_PLAYER, RED_PLAYER};
+
+    use super::*;
+
+    #[test]
+    fn test_index_is_a_bijection() {
+        let database = BearoffDatabase::generate(3);
+        let mut positions = Vec::new();
+        collect_positions(&mut [0; HOME_POINTS], 0, 3, &mut positions);
+
+        let indexes: HashSet<_> = positions.iter().map(|home| database.index(home)).collect();
+        assert_eq!(positions.len(), 84);
+        assert_eq!(indexes.len(), 84);
+        assert!(indexes.iter().all(|index| *index < 84));
+    }
+
+    #[test]
+    fn test_known_expected_rolls() {
+        let database = BearoffDatabase::generate(3);
+        let expected = |home: HomeBoard| database.expected_rolls(&home).unwrap();
+
+        assert_eq!(expected([0, 0, 0, 0, 0, 0]), 0.0);
+        assert_eq!(expected([1, 0, 0, 0, 0, 0]), 1.0);
+        assert_eq!(expected([2, 0, 0, 0, 0, 0]), 1.0);
+        // 11, 21, 31, 41 and 32 leave the checker on the board
+        assert!((expected([0, 0, 0, 0, 0, 1]) - 1.25).abs() < 1e-6);
+        // 3 checkers on the ace point: only doubles take them off in one roll
+        assert!((expected([3, 0, 0, 0, 0, 0]) - (1.0 + 5.0 / 6.0)).abs() < 1e-6);
+        assert_eq!(database.expected_rolls(&[4, 0, 0, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_known_expected_rolls_of_15_checkers() {
+        let database = BearoffDatabase::generate(MAX_CHECKERS);
+
+        // all on the ace point every die bears off a checker, so with n checkers left
+        // E(n) = 1 + 5/6 E(n - 2) + 1/6 E(n - 4)
+        let mut ace_point = [0.0; MAX_CHECKERS as usize + 1];
+        for n in 1..ace_point.len() {
+            ace_point[n] = 1.0
+                + 5.0 / 6.0 * ace_point[n.saturating_sub(2)]
+                + 1.0 / 6.0 * ace_point[n.saturating_sub(4)];
+        }
+        let expected = database.expected_rolls(&[15, 0, 0, 0, 0, 0]).unwrap();
+        assert!((expected - ace_point[15]).abs() < 1e-4);
+        assert!((expected - 6.9796).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_win_probability_beyond_the_database() {
+        let database = BearoffDatabase::generate(3);
+
+        let board = BackgammonBoard::from_pairs(&[(1, b(2)), (0, b(13)), (24, r(3)), (25, r(12))]);
+        assert!(database_win_probability(&database, &board, BLACK_PLAYER).is_some());
+
+        // the checkers borne off do not count, the ones left on the board do
+        let board = BackgammonBoard::from_pairs(&[(1, b(15)), (24, r(3)), (25, r(12))]);
+        assert_eq!(
+            database_win_probability(&database, &board, BLACK_PLAYER),
+            None
+        );
+        assert_eq!(
+            database_win_probability(&database, &board, RED_PLAYER),
+            None
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = env::temp_dir().join(format!("bg-bearoff-{}.db", std::process::id()));
+        let database = BearoffDatabase::generate(4);
+        database.save(&path).unwrap();
+
+        let loaded = BearoffDatabase::load(&path).unwrap();
+        assert_eq!(loaded.max_checkers(), 4);
+        for (expected, loaded) in database.expected_rolls.iter().zip(&loaded.expected_rolls) {
+            assert!((expected - loaded).abs() < 1.0 / SCALE);
+        }
+
+        fs::write(&path, b"not a database").unwrap();
+        assert!(BearoffDatabase::load(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_home_board() {
+        let board = BackgammonBoard::from_pairs(&[(1, b(2)), (6, b(3)), (0, b(10)), (20, r(15))]);
+
+        assert_eq!(home_board(&board, BLACK_PLAYER), Some([2, 0, 0, 0, 0, 3]));
+        assert_eq!(home_board(&board, RED_PLAYER), Some([0, 0, 0, 0, 15, 0]));
+        assert_eq!(
+            home_board(&BackgammonBoard::default_board(), BLACK_PLAYER),
+            None
+        );
+    }
+
+    #[test]
+    fn test_race_win_probability() {
+        assert_eq!(race_win_probability(0.0, 3.0), 1.0);
+        assert_eq!(race_win_probability(2.0, 1.0), 0.0);
+        // the side on roll is ahead in an even race
+        assert!(race_win_probability(5.0, 5.0) < 0.5);
+        assert!(race_win_probability(4.0, 6.0) > race_win_probability(5.0, 5.0));
+    }
+}
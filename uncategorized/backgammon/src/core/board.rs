@@ -1,8 +1,10 @@
 use super::moves::{MoveSequence, MoveWithDie};
 use super::players::{b, get_opponent, r, PlayerColor, BLACK_PLAYER, RED_PLAYER};
 use super::positions::{bar_pos, norm_pos, Position};
+use super::rules::Variant;
 
 use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
 
 pub(crate) type Point = i8;
 
@@ -29,9 +31,13 @@ pub(crate) type Point = i8;
  */
 type Board = [Point; 28];
 
-#[derive(Debug, Clone)]
+/// The checkers of a position with the rules it is played under, which give the number
+/// of checkers each player has to bear off.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct BackgammonBoard {
     board: Board,
+    #[serde(default)]
+    variant: Variant,
 }
 
 lazy_static! {
@@ -53,6 +59,7 @@ impl BackgammonBoard {
     pub(crate) fn default_board() -> Self {
         Self {
             board: *DEFAULT_BOARD,
+            variant: Variant::Standard,
         }
     }
 
@@ -64,9 +71,26 @@ impl BackgammonBoard {
             board[pos] = point
         }
 
-        Self { board }
+        Self {
+            board,
+            variant: Variant::Standard,
+        }
+    }
+
+    /// The same checkers played under `variant`.
+    pub(crate) fn with_variant(self, variant: Variant) -> Self {
+        Self { variant, ..self }
+    }
+
+    pub(crate) fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    pub(crate) fn set(&mut self, pos: Position, point: Point) {
+        self.board[pos] = point;
     }
 
+    /// Checkers of black and of red, both counted as positive numbers.
     pub(crate) fn points(&self) -> (Point, Point) {
         let (mut black_points, mut red_points) = (0, 0);
 
@@ -106,16 +130,13 @@ impl BackgammonBoard {
         }
     }
 
+    /// All the checkers of the player in the variant are in its home board or borne off.
     pub(crate) fn can_bear_off(&self, player_color: PlayerColor) -> bool {
-        let chk = self.checkers_at(player_color, norm_pos(0, player_color))
-            + self.checkers_at(player_color, norm_pos(1, player_color))
-            + self.checkers_at(player_color, norm_pos(2, player_color))
-            + self.checkers_at(player_color, norm_pos(3, player_color))
-            + self.checkers_at(player_color, norm_pos(4, player_color))
-            + self.checkers_at(player_color, norm_pos(5, player_color))
-            + self.checkers_at(player_color, norm_pos(6, player_color));
+        let chk: usize = (0..=6)
+            .map(|pos| self.checkers_at(player_color, norm_pos(pos, player_color)))
+            .sum();
 
-        chk == 15
+        chk == self.variant.checkers() as usize
     }
 
     pub(crate) fn apply_n_moves(
@@ -124,23 +145,26 @@ impl BackgammonBoard {
         player_color: PlayerColor,
         n: usize,
     ) -> Self {
-        let points = player_color * n as i8;
+        let mut new_board = self.clone();
+        new_board.move_checkers(m, player_color, n);
+        new_board
+    }
 
-        let mut new_board = self.board.clone();
-        new_board[m.src] -= points;
+    /// Moves `n` checkers in place, sending a hit blot to the bar.
+    fn move_checkers(&mut self, m: &MoveWithDie, player_color: PlayerColor, n: usize) {
+        let points = player_color * n as i8;
+        self.board[m.src] -= points;
 
         let opponent = get_opponent(player_color);
         let opp_chk = self.checkers_at(opponent, m.dst);
 
         if opp_chk != 0 {
-            new_board[m.dst] = points;
+            self.board[m.dst] = points;
         } else {
-            new_board[m.dst] += points;
+            self.board[m.dst] += points;
         }
 
-        new_board[bar_pos(opponent)] += opp_chk as i8 * opponent;
-
-        Self { board: new_board }
+        self.board[bar_pos(opponent)] += opp_chk as i8 * opponent;
     }
 
     pub(crate) fn apply_move_sequence(
@@ -150,8 +174,8 @@ impl BackgammonBoard {
     ) -> Self {
         let mut new_board = self.clone();
 
-        for m in move_sequence.to_list() {
-            new_board = new_board.apply_n_moves(m, player_color, 1)
+        for m in move_sequence.iter() {
+            new_board.move_checkers(m, player_color, 1);
         }
 
         new_board
@@ -164,8 +188,13 @@ mod tests {
 
     #[test]
     fn test_backgammon_board_points() {
+        // black's checkers are negative on the board but counted as positive, as the
+        // printer expects when it checks them against the checkers of the variant
         let board = BackgammonBoard::default_board();
-        assert_eq!(board.points(), (-15, 15));
+        assert_eq!(board.points(), (15, 15));
+
+        let board = BackgammonBoard::from_pairs(&[(6, b(3)), (0, b(12)), (19, r(4))]);
+        assert_eq!(board.points(), (15, 4));
     }
 
     #[test]
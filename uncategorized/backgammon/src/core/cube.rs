@@ -0,0 +1,190 @@
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+use super::players::PlayerColor;
+
+/// Share of the wins that are gammons, used to estimate the match equities.
+const GAMMON_RATE: f64 = 0.26;
+/// Money play take point: the minimum winning chances for a take to be correct.
+const MONEY_TAKE_POINT: f64 = 0.25;
+/// How far beyond the opponent's take point a player is still willing to double.
+const DOUBLE_WINDOW: f64 = 0.1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cube {
+    pub value: u32,
+    /// `None` while the cube is in the middle
+    pub owner: Option<PlayerColor>,
+}
+
+impl Default for Cube {
+    fn default() -> Self {
+        Self {
+            value: 1,
+            owner: None,
+        }
+    }
+}
+
+impl Cube {
+    pub(crate) fn can_double(&self, player_color: PlayerColor) -> bool {
+        self.owner.is_none_or(|owner| owner == player_color)
+    }
+
+    /// The cube after `taker` accepts a double.
+    pub(crate) fn doubled(&self, taker: PlayerColor) -> Self {
+        Self {
+            value: self.value * 2,
+            owner: Some(taker),
+        }
+    }
+}
+
+/// What an agent knows when deciding to double or take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CubeState {
+    pub cube: Cube,
+    /// points the player and its opponent still need to win the match, `None` in money play
+    pub away: Option<(u32, u32)>,
+}
+
+impl CubeState {
+    /// The state as seen from the opponent.
+    pub(crate) fn swapped(&self) -> Self {
+        Self {
+            cube: self.cube,
+            away: self.away.map(|(own, opp)| (opp, own)),
+        }
+    }
+
+    /// Minimum winning chances for a take to be correct, from the taker's point of view.
+    pub fn take_point(&self) -> f64 {
+        let Some((own, opp)) = self.away else {
+            return MONEY_TAKE_POINT;
+        };
+
+        let value = self.cube.value;
+        let drop = match_equity(own, opp.saturating_sub(value));
+        let take_lose = match_equity(own, opp.saturating_sub(2 * value));
+        let take_win = match_equity(own.saturating_sub(2 * value), opp);
+
+        if take_win <= take_lose {
+            return 0.0;
+        }
+        ((drop - take_lose) / (take_win - take_lose)).clamp(0.0, 1.0)
+    }
+
+    /// Decides a double from the winning chances of the player on roll.
+    pub fn should_double(&self, win_probability: f64) -> bool {
+        1.0 - win_probability <= self.swapped().take_point() + DOUBLE_WINDOW
+    }
+
+    /// Decides a take from the winning chances of the player being doubled.
+    pub fn should_take(&self, win_probability: f64) -> bool {
+        win_probability >= self.take_point()
+    }
+}
+
+/// Longest match whose equities are kept in `MATCH_EQUITY_TABLE`.
+const MAX_AWAY: usize = 25;
+
+lazy_static! {
+    static ref MATCH_EQUITY_TABLE: Vec<Vec<f64>> = match_equity_table(MAX_AWAY, MAX_AWAY);
+}
+
+/// Chances of winning the match needing `own` points while the opponent needs `opp`.
+///
+/// The table is computed assuming each game is even, is played without cube and ends in
+/// a gammon `GAMMON_RATE` of the time, so it's an estimate of a real match equity table.
+pub fn match_equity(own: u32, opp: u32) -> f64 {
+    if own == 0 {
+        return 1.0;
+    }
+    if opp == 0 {
+        return 0.0;
+    }
+
+    let (own, opp) = (own as usize, opp as usize);
+    if own <= MAX_AWAY && opp <= MAX_AWAY {
+        MATCH_EQUITY_TABLE[own][opp]
+    } else {
+        match_equity_table(own, opp)[own][opp]
+    }
+}
+
+/// Match equities of the scores up to `own` and `opp` points away.
+fn match_equity_table(own: usize, opp: usize) -> Vec<Vec<f64>> {
+    let mut table = vec![vec![0.0; opp + 1]; own + 1];
+    let equity =
+        |table: &Vec<Vec<f64>>, a: usize, b: usize, points_a: usize, points_b: usize| match (
+            a.checked_sub(points_a),
+            b.checked_sub(points_b),
+        ) {
+            (None, _) | (Some(0), _) => 1.0,
+            (_, None) | (_, Some(0)) => 0.0,
+            (Some(a), Some(b)) => table[a][b],
+        };
+
+    for a in 1..=own {
+        for b in 1..=opp {
+            table[a][b] = 0.5 * (1.0 - GAMMON_RATE) * equity(&table, a, b, 1, 0)
+                + 0.5 * GAMMON_RATE * equity(&table, a, b, 2, 0)
+                + 0.5 * (1.0 - GAMMON_RATE) * equity(&table, a, b, 0, 1)
+                + 0.5 * GAMMON_RATE * equity(&table, a, b, 0, 2);
+        }
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::players::{BLACK_PLAYER, RED_PLAYER};
+
+    use super::*;
+
+    #[test]
+    fn test_cube_double() {
+        let cube = Cube::default();
+        assert!(cube.can_double(BLACK_PLAYER));
+        assert!(cube.can_double(RED_PLAYER));
+
+        let cube = cube.doubled(RED_PLAYER);
+        assert_eq!(cube.value, 2);
+        assert!(!cube.can_double(BLACK_PLAYER));
+        assert!(cube.can_double(RED_PLAYER));
+    }
+
+    #[test]
+    fn test_match_equity() {
+        assert_eq!(match_equity(0, 3), 1.0);
+        assert_eq!(match_equity(3, 0), 0.0);
+        assert!((match_equity(5, 5) - 0.5).abs() < 1e-9);
+        // beyond the cached table
+        assert!((match_equity(30, 30) - 0.5).abs() < 1e-9);
+        assert!(match_equity(MAX_AWAY as u32, 30) > match_equity(30, MAX_AWAY as u32));
+        assert!((match_equity(1, 1) - 0.5).abs() < 1e-9);
+        assert!(match_equity(1, 2) > match_equity(2, 2));
+        assert!((match_equity(2, 7) + match_equity(7, 2) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_take_point() {
+        let money = CubeState {
+            cube: Cube::default(),
+            away: None,
+        };
+        assert_eq!(money.take_point(), MONEY_TAKE_POINT);
+        assert!(money.should_take(0.3));
+        assert!(!money.should_take(0.2));
+        assert!(money.should_double(0.7));
+        assert!(!money.should_double(0.55));
+
+        // at 1-away 1-away a double changes nothing, so any take is correct
+        let dmp = CubeState {
+            cube: Cube::default(),
+            away: Some((1, 1)),
+        };
+        assert_eq!(dmp.take_point(), 0.0);
+    }
+}
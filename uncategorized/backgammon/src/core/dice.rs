@@ -1,16 +1,41 @@
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-pub(crate) type Die = u8;
+pub type Die = u8;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DiceRoll {
     Single(Die, Die),
     Double(Die),
 }
 
 impl DiceRoll {
-    pub(crate) fn get_dice_roll() -> DiceRoll {
-        let mut rng = rand::thread_rng();
+    /// # Panics
+    ///
+    /// If a die is not between 1 and 6.
+    pub fn new(die1: Die, die2: Die) -> Self {
+        assert!(
+            (1..=6).contains(&die1) && (1..=6).contains(&die2),
+            "dice go from 1 to 6, got {} and {}",
+            die1,
+            die2
+        );
+        if die1 == die2 {
+            Self::Double(die1)
+        } else {
+            Self::Single(die1, die2)
+        }
+    }
+
+    /// The dice to play, 4 of them for a double.
+    pub fn dice(&self) -> Vec<Die> {
+        match *self {
+            DiceRoll::Single(die1, die2) => vec![die1, die2],
+            DiceRoll::Double(die) => repeat_die(die, 4),
+        }
+    }
+
+    pub(crate) fn get_dice_roll<R: Rng + ?Sized>(rng: &mut R) -> DiceRoll {
         let die1: Die = rng.gen_range(1..=6);
         let die2: Die = rng.gen_range(1..=6);
 
@@ -35,6 +60,18 @@ impl DiceRoll {
     }
 }
 
+/// The 21 distinct rolls with their probability.
+pub(crate) fn all_dice_rolls() -> Vec<(DiceRoll, f64)> {
+    let mut rolls = Vec::with_capacity(21);
+    for die1 in 1..=6 {
+        rolls.push((DiceRoll::Double(die1), 1.0 / 36.0));
+        for die2 in 1..die1 {
+            rolls.push((DiceRoll::Single(die1, die2), 2.0 / 36.0));
+        }
+    }
+    rolls
+}
+
 pub(crate) fn repeat_die(die: Die, times: usize) -> Vec<Die> {
     [die].into_iter().cycle().take(times).collect::<Vec<_>>()
 }
@@ -47,4 +84,19 @@ mod tests {
     fn test_repeat_die() {
         assert_eq!(repeat_die(1, 2), vec![1, 1])
     }
+
+    #[test]
+    fn test_dice_roll_new() {
+        assert_eq!(DiceRoll::new(3, 3), DiceRoll::Double(3));
+        assert_eq!(DiceRoll::new(3, 1).dice(), vec![3, 1]);
+        assert_eq!(DiceRoll::new(2, 2).dice(), vec![2; 4]);
+    }
+
+    #[test]
+    fn test_all_dice_rolls() {
+        let rolls = all_dice_rolls();
+
+        assert_eq!(rolls.len(), 21);
+        assert!((rolls.iter().map(|(_, p)| p).sum::<f64>() - 1.0).abs() < 1e-9);
+    }
 }
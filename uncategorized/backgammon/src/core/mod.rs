@@ -1,6 +1,10 @@
+pub mod bearoff;
 pub(crate) mod board;
+pub(crate) mod cube;
 pub(crate) mod dice;
+pub(crate) mod movegen;
 pub(crate) mod moves;
 pub(crate) mod players;
 pub(crate) mod positions;
+pub mod rules;
 pub(crate) mod scores;
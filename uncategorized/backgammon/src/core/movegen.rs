@@ -0,0 +1,492 @@
+use std::collections::HashSet;
+
+use super::{
+    board::BackgammonBoard,
+    dice::{DiceRoll, Die},
+    moves::{MoveSequence, MoveWithDie},
+    players::{get_opponent, PlayerColor},
+    positions::{bar_pos, norm_pos, Position},
+};
+
+/// Points are numbered from the side of the player to move: its checkers borne off are
+/// at 0, its home board is 1 to 6 and its bar is 25.
+const OFF_POINT: u8 = 0;
+const BAR_POINT: u8 = 25;
+const HOME_POINTS: u32 = 0b111_1110;
+
+/// Position of the player to move packed for the search, updated in place as moves are
+/// made and undone.
+struct MoveGen {
+    player_color: PlayerColor,
+    /// the checkers on the bar enter before any other move, see `Variant::enter_first`
+    enter_first: bool,
+    /// checkers on each point, 4 bits per point
+    checkers: u128,
+    /// points with checkers of the player, bar included
+    occupied: u32,
+    /// points held by the opponent
+    blocked: u32,
+    /// points with a single checker of the opponent
+    blots: u32,
+    /// checkers outside the home board, bar included
+    outside: u8,
+}
+
+#[derive(Clone, Copy)]
+struct GenMove {
+    from: u8,
+    to: u8,
+    die: Die,
+    hit: bool,
+}
+
+/// The moves of the sequence being searched, kept on the stack.
+#[derive(Clone, Copy)]
+struct MoveStack {
+    moves: [GenMove; 4],
+    len: usize,
+}
+
+/// Legal sequences found so far, only the ones playing the most dice.
+struct Found {
+    max_len: usize,
+    /// resulting positions of `sequences`: the checkers of the player and the blots left
+    positions: HashSet<(u128, u32)>,
+    sequences: Vec<(Die, MoveSequence)>,
+}
+
+impl MoveGen {
+    fn new(board: &BackgammonBoard, player_color: PlayerColor) -> Self {
+        let opponent = get_opponent(player_color);
+        let mut gen = Self {
+            player_color,
+            enter_first: board.variant().enter_first(),
+            checkers: 0,
+            occupied: 0,
+            blocked: 0,
+            blots: 0,
+            outside: 0,
+        };
+
+        for point in OFF_POINT..=BAR_POINT {
+            let pos = gen.position(point);
+            let count = board.checkers_at(player_color, pos) as u8;
+            gen.checkers |= (count as u128) << (4 * point);
+            if count > 0 && point != OFF_POINT {
+                gen.occupied |= 1 << point;
+            }
+            if point > 6 {
+                gen.outside += count;
+            }
+
+            if (1..BAR_POINT).contains(&point) {
+                match board.checkers_at(opponent, pos) {
+                    0 => {}
+                    1 => gen.blots |= 1 << point,
+                    _ => gen.blocked |= 1 << point,
+                }
+            }
+        }
+
+        gen
+    }
+
+    fn position(&self, point: u8) -> Position {
+        if point == BAR_POINT {
+            bar_pos(self.player_color)
+        } else {
+            norm_pos(point as Position, self.player_color)
+        }
+    }
+
+    fn count(&self, point: u8) -> u8 {
+        (self.checkers >> (4 * point as u32) & 0xF) as u8
+    }
+
+    /// The move of a checker on `from` with `die`, if it is legal.
+    fn legal_move(&self, from: u8, die: Die) -> Option<GenMove> {
+        if from > die {
+            let to = from - die;
+            if self.blocked & 1 << to != 0 {
+                return None;
+            }
+            return Some(GenMove {
+                from,
+                to,
+                die,
+                hit: self.blots & 1 << to != 0,
+            });
+        }
+
+        // bearing off: exactly, or with a bigger die from the highest point
+        let higher_points = HOME_POINTS & !((2 << from) - 1);
+        if self.outside > 0 || (from < die && self.occupied & higher_points != 0) {
+            return None;
+        }
+        Some(GenMove {
+            from,
+            to: OFF_POINT,
+            die,
+            hit: false,
+        })
+    }
+
+    fn apply(&mut self, m: &GenMove) {
+        self.checkers -= 1 << (4 * m.from as u32);
+        self.checkers += 1 << (4 * m.to as u32);
+        if self.count(m.from) == 0 {
+            self.occupied &= !(1 << m.from);
+        }
+        if m.to != OFF_POINT {
+            self.occupied |= 1 << m.to;
+        }
+        if m.from > 6 && m.to <= 6 {
+            self.outside -= 1;
+        }
+        if m.hit {
+            self.blots &= !(1 << m.to);
+        }
+    }
+
+    fn undo(&mut self, m: &GenMove) {
+        self.checkers -= 1 << (4 * m.to as u32);
+        self.checkers += 1 << (4 * m.from as u32);
+        if m.to != OFF_POINT && self.count(m.to) == 0 {
+            self.occupied &= !(1 << m.to);
+        }
+        self.occupied |= 1 << m.from;
+        if m.from > 6 && m.to <= 6 {
+            self.outside += 1;
+        }
+        if m.hit {
+            self.blots |= 1 << m.to;
+        }
+    }
+
+    /// Plays `dice` in order from every point up to `max_from`, recording the sequences
+    /// that end when no die is left or none can be played.
+    ///
+    /// The moves of a double are made from the back of the board to the front, which
+    /// reaches every position once per set of moves.
+    fn search(&mut self, dice: &[Die], max_from: u8, stack: &mut MoveStack, found: &mut Found) {
+        let mut played = false;
+
+        if let Some((&die, rest)) = dice.split_first() {
+            // checkers on the bar enter before any other move
+            let mut points = if self.enter_first && self.occupied & 1 << BAR_POINT != 0 {
+                1 << BAR_POINT
+            } else {
+                self.occupied
+            };
+            points &= (2 << max_from) - 1;
+
+            while points != 0 {
+                let from = 31 - points.leading_zeros() as u8;
+                points &= !(1 << from);
+
+                let Some(m) = self.legal_move(from, die) else {
+                    continue;
+                };
+                played = true;
+
+                self.apply(&m);
+                stack.moves[stack.len] = m;
+                stack.len += 1;
+                let max_from = if rest.first() == Some(&die) {
+                    from
+                } else {
+                    BAR_POINT
+                };
+                self.search(rest, max_from, stack, found);
+                stack.len -= 1;
+                self.undo(&m);
+            }
+        }
+
+        if !played {
+            found.record(self, stack);
+        }
+    }
+}
+
+impl Found {
+    fn record(&mut self, gen: &MoveGen, stack: &MoveStack) {
+        if stack.len < self.max_len {
+            return;
+        }
+        if stack.len > self.max_len {
+            self.max_len = stack.len;
+            self.positions.clear();
+            self.sequences.clear();
+        }
+        if !self.positions.insert((gen.checkers, gen.blots)) {
+            return;
+        }
+
+        let m = |i: usize| {
+            let m = &stack.moves[i];
+            MoveWithDie {
+                src: gen.position(m.from),
+                dst: gen.position(m.to),
+                die: m.die,
+            }
+        };
+        let move_sequence = match stack.len {
+            0 => return,
+            1 => MoveSequence::SingleMove(m(0)),
+            2 => MoveSequence::TwoMoves(m(0), m(1)),
+            3 => MoveSequence::ThreeMoves(m(0), m(1), m(2)),
+            _ => MoveSequence::FourMoves(m(0), m(1), m(2), m(3)),
+        };
+        self.sequences.push((stack.moves[0].die, move_sequence));
+    }
+
+    /// Searches the sequences playing as many of `dice` as possible, the two orders of
+    /// two different dice and the moves of equal dice from the back to the front.
+    fn search(board: &BackgammonBoard, player_color: PlayerColor, dice: &[Die]) -> Self {
+        let mut gen = MoveGen::new(board, player_color);
+        let mut stack = MoveStack {
+            moves: [GenMove {
+                from: 0,
+                to: 0,
+                die: 0,
+                hit: false,
+            }; 4],
+            len: 0,
+        };
+        let mut found = Found {
+            max_len: 0,
+            positions: HashSet::new(),
+            sequences: Vec::new(),
+        };
+
+        gen.search(dice, BAR_POINT, &mut stack, &mut found);
+        if let [die1, die2] = *dice {
+            if die1 != die2 {
+                gen.search(&[die2, die1], BAR_POINT, &mut stack, &mut found);
+            }
+        }
+        found
+    }
+}
+
+/// Legal plays of `roll`, each one leading to a different position.
+///
+/// A play uses as many dice as possible and, when only one die of a roll can be played,
+/// the higher one if it can.
+pub(crate) fn generate_all_move_sequences(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    roll: &DiceRoll,
+) -> Vec<MoveSequence> {
+    let found = match *roll {
+        DiceRoll::Single(die1, die2) => {
+            let high_die = die1.max(die2);
+            let mut found = Found::search(board, player_color, &[high_die, die1.min(die2)]);
+            if found.max_len == 1 && found.sequences.iter().any(|(die, _)| *die == high_die) {
+                found.sequences.retain(|(die, _)| *die == high_die);
+            }
+            found
+        }
+        DiceRoll::Double(die) => Found::search(board, player_color, &[die; 4]),
+    };
+
+    found
+        .sequences
+        .into_iter()
+        .map(|(_, move_sequence)| move_sequence)
+        .collect()
+}
+
+/// Positions reached by playing as many of `dice` as possible, the dice left of a roll
+/// after some of its moves. Unlike the plays of a roll, any die can be played when only
+/// one of them can.
+pub(crate) fn reachable_positions(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    dice: &[Die],
+) -> Vec<BackgammonBoard> {
+    let found = Found::search(board, player_color, dice);
+    if found.sequences.is_empty() {
+        return vec![board.clone()];
+    }
+
+    found
+        .sequences
+        .iter()
+        .map(|(_, move_sequence)| board.apply_move_sequence(move_sequence, player_color))
+        .collect()
+}
+
+#[cfg(test)]
+mod reference;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use crate::core::{
+        dice::all_dice_rolls,
+        players::{b, r, BLACK_PLAYER, RED_PLAYER},
+        positions::{BLACK_BAR, BLACK_OFF, RED_BAR, RED_OFF},
+        rules::Variant,
+    };
+
+    use super::{reference::reference_move_sequences, *};
+
+    fn positions(
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+        move_sequences: &[MoveSequence],
+    ) -> HashSet<BackgammonBoard> {
+        move_sequences
+            .iter()
+            .map(|move_sequence| board.apply_move_sequence(move_sequence, player_color))
+            .collect()
+    }
+
+    /// Board with the checkers of each player on the given points, numbered from its own
+    /// side with the bar at 25. A red checker on a point taken by black is borne off.
+    fn board_from_points(black: &[u8], red: &[u8]) -> BackgammonBoard {
+        let mut counts = [(0u8, 0u8); 28];
+        for &point in black {
+            let pos = match point {
+                25 => BLACK_BAR,
+                point => norm_pos(point as Position, BLACK_PLAYER),
+            };
+            counts[pos].0 += 1;
+        }
+        for &point in red {
+            let pos = match point {
+                25 => RED_BAR,
+                point => norm_pos(point as Position, RED_PLAYER),
+            };
+            let pos = if pos != RED_OFF && counts[pos].0 > 0 {
+                RED_OFF
+            } else {
+                pos
+            };
+            counts[pos].1 += 1;
+        }
+
+        let pairs = counts
+            .iter()
+            .enumerate()
+            .map(|(pos, &(black, red))| (pos, if black > 0 { b(black) } else { r(red) }))
+            .collect::<Vec<_>>();
+        BackgammonBoard::from_pairs(&pairs)
+    }
+
+    /// Points of 15 checkers, anywhere or only in the home board and off to test the
+    /// bear-offs.
+    fn checkers() -> impl Strategy<Value = Vec<u8>> {
+        prop_oneof![
+            proptest::collection::vec(0..=25u8, 15),
+            proptest::collection::vec(0..=6u8, 15),
+        ]
+    }
+
+    proptest! {
+        // the reference generator is slow with doubles
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_same_legal_plays_as_the_reference(
+            black in checkers(),
+            red in checkers(),
+            variant in prop_oneof![Just(Variant::Standard), Just(Variant::AceyDeucey)],
+        ) {
+            let board = board_from_points(&black, &red).with_variant(variant);
+            prop_assume!(board.checkers_at(BLACK_PLAYER, BLACK_OFF) < 15);
+
+            for (roll, _) in all_dice_rolls() {
+                let move_sequences = generate_all_move_sequences(&board, BLACK_PLAYER, &roll);
+                let reference = reference_move_sequences(&board, BLACK_PLAYER, &roll);
+
+                let new_positions = positions(&board, BLACK_PLAYER, &move_sequences);
+                prop_assert_eq!(new_positions.len(), move_sequences.len());
+                prop_assert_eq!(
+                    &new_positions,
+                    &positions(&board, BLACK_PLAYER, &reference),
+                    "{:?} {:?}",
+                    board,
+                    roll
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_red_plays_mirror_black_plays() {
+        let board = BackgammonBoard::default_board();
+        for (roll, _) in all_dice_rolls() {
+            assert_eq!(
+                generate_all_move_sequences(&board, RED_PLAYER, &roll).len(),
+                generate_all_move_sequences(&board, BLACK_PLAYER, &roll).len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_only_the_higher_die_when_one_can_be_played() {
+        // black can play 9/3 or 9/7 with its last checker, but not both dice as red
+        // holds the 1 point
+        let board = BackgammonBoard::from_pairs(&[
+            (9, b(1)),
+            (BLACK_OFF, b(14)),
+            (1, r(2)),
+            (RED_OFF, r(13)),
+        ]);
+        let move_sequences =
+            generate_all_move_sequences(&board, BLACK_PLAYER, &DiceRoll::Single(6, 2));
+
+        assert_eq!(
+            move_sequences,
+            vec![MoveSequence::SingleMove(MoveWithDie {
+                src: 9,
+                dst: 3,
+                die: 6
+            })]
+        );
+    }
+
+    #[test]
+    fn test_acey_deucey_moves_while_entering() {
+        // an entered checker can move on while the others wait on the bar
+        let board = Variant::AceyDeucey.starting_board();
+        let roll = DiceRoll::Single(3, 1);
+        let positions = positions(
+            &board,
+            BLACK_PLAYER,
+            &generate_all_move_sequences(&board, BLACK_PLAYER, &roll),
+        );
+        assert_eq!(positions.len(), 2);
+        assert!(positions
+            .iter()
+            .any(|position| position.checkers_at(BLACK_PLAYER, 21) == 1));
+
+        let board = board.with_variant(Variant::Standard);
+        assert_eq!(
+            generate_all_move_sequences(&board, BLACK_PLAYER, &roll).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_reachable_positions_with_the_dice_left() {
+        let board = BackgammonBoard::default_board();
+        let after_8_5 =
+            board.apply_n_moves(&MoveWithDie::with_die(BLACK_PLAYER, 8, 3), BLACK_PLAYER, 1);
+
+        let positions = reachable_positions(&after_8_5, BLACK_PLAYER, &[1]);
+        assert!(positions.contains(&after_8_5.apply_n_moves(
+            &MoveWithDie::with_die(BLACK_PLAYER, 6, 1),
+            BLACK_PLAYER,
+            1
+        )));
+        assert!(positions.iter().all(|position| position != &after_8_5));
+        assert_eq!(reachable_positions(&board, BLACK_PLAYER, &[]), vec![board]);
+    }
+}
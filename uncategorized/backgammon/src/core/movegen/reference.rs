@@ -0,0 +1,545 @@
+//! The move generator used before the in-place one, kept to check the legal plays of the
+//! new generator against it.
+
+use crate::core::{
+    board::BackgammonBoard,
+    dice::{repeat_die, DiceRoll, Die},
+    moves::{MoveSequence, MoveWithDie},
+    players::{get_opponent, PlayerColor, BLACK_PLAYER},
+    positions::{bar_pos, norm_pos, PosIter, Position, BLACK_OFF},
+};
+
+fn lte_bs(player_color: PlayerColor, pos: Position) -> bool {
+    if player_color == BLACK_PLAYER {
+        pos <= 24
+    } else {
+        pos >= 1
+    }
+}
+
+fn lt_bs(player_color: PlayerColor, pos: Position) -> bool {
+    if player_color == BLACK_PLAYER {
+        pos < 24
+    } else {
+        pos > 1
+    }
+}
+
+fn gte_be(player_color: PlayerColor, pos: Position) -> bool {
+    if player_color == BLACK_PLAYER {
+        pos >= 1
+    } else {
+        pos <= 24
+    }
+}
+
+fn gt_be(player_color: PlayerColor, pos: Position) -> bool {
+    if player_color == BLACK_PLAYER {
+        pos > 1
+    } else {
+        pos < 24
+    }
+}
+
+fn in_hb(player_color: PlayerColor, pos: Position) -> bool {
+    if player_color == BLACK_PLAYER {
+        (1..=6).contains(&pos)
+    } else {
+        (19..=24).contains(&pos)
+    }
+}
+
+fn eq_bo(player_color: PlayerColor, pos: Position) -> bool {
+    pos == norm_pos(BLACK_OFF, player_color)
+}
+
+impl MoveWithDie {
+    pub(crate) fn from_bar_with_die(player_color: PlayerColor, die: Die) -> Self {
+        let src = bar_pos(player_color);
+        let dst = norm_pos(die as Position, get_opponent(player_color));
+
+        Self { src, dst, die }
+    }
+
+    pub(crate) fn with_die(player_color: PlayerColor, src: Position, die: Die) -> Self {
+        let dst = src as i8 + player_color * die as i8;
+        let mut dst = if dst < 0 { 0 } else { dst as Position };
+
+        if !gte_be(player_color, dst) {
+            dst = norm_pos(BLACK_OFF, player_color);
+        }
+
+        Self { src, dst, die }
+    }
+
+    fn is_valid_re_entry(&self, board: &BackgammonBoard, player_color: PlayerColor) -> bool {
+        let chk_src = board.checkers_at(player_color, self.src);
+        let chk_dst = board.checkers_at(get_opponent(player_color), self.dst);
+
+        chk_src > 0 && chk_dst <= 1
+    }
+
+    fn is_valid_normal(&self, board: &BackgammonBoard, player_color: PlayerColor) -> bool {
+        lte_bs(player_color, self.src)
+            && gt_be(player_color, self.src)
+            && gte_be(player_color, self.dst)
+            && lt_bs(player_color, self.dst)
+            && board.checkers_at(player_color, self.src) > 0
+            && board.checkers_at(get_opponent(player_color), self.dst) <= 1
+    }
+
+    fn is_valid_boff(&self, board: &BackgammonBoard, player_color: PlayerColor, die: Die) -> bool {
+        if !in_hb(player_color, self.src)
+            || !eq_bo(player_color, self.dst)
+            || board.checkers_at(player_color, self.src) == 0
+            || !board.can_bear_off(player_color)
+        {
+            return false;
+        }
+
+        let boff_pos = norm_pos(self.src, player_color);
+
+        if boff_pos == die as Position {
+            return true;
+        }
+
+        if boff_pos > die as Position {
+            return false;
+        }
+
+        for pos in boff_pos + 1..7 {
+            if board.checkers_at(player_color, norm_pos(pos, player_color)) > 0 {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn get_moves(board: &BackgammonBoard, player_color: PlayerColor, die: Die) -> Vec<MoveWithDie> {
+    let on_bar = board.checkers_at(player_color, bar_pos(player_color)) > 0;
+    if on_bar && board.variant().enter_first() {
+        let m = MoveWithDie::from_bar_with_die(player_color, die);
+        if m.is_valid_re_entry(board, player_color) {
+            vec![m]
+        } else {
+            Vec::new()
+        }
+    } else {
+        let entering = Some(MoveWithDie::from_bar_with_die(player_color, die))
+            .filter(|m| on_bar && m.is_valid_re_entry(board, player_color));
+        PosIter::full_board(player_color, None)
+            .filter(|&pos| board.checkers_at(player_color, pos) > 0)
+            .map(|pos| MoveWithDie::with_die(player_color, pos, die))
+            .filter(|m| {
+                m.is_valid_normal(board, player_color) || m.is_valid_boff(board, player_color, die)
+            })
+            .chain(entering)
+            .collect::<Vec<_>>()
+    }
+}
+
+fn get_move_sequences(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    roll: &[Die],
+) -> Vec<MoveSequence> {
+    let dice_len = roll.len();
+    assert!(dice_len > 0, "there must be at least 1 die");
+
+    let mut move_sequences = Vec::new();
+
+    for move1 in get_moves(board, player_color, roll[0]) {
+        if dice_len > 1 {
+            let board2 = board.apply_n_moves(&move1, player_color, 1);
+            for move2 in get_moves(&board2, player_color, roll[1]) {
+                if dice_len > 2 {
+                    let board3 = board2.apply_n_moves(&move2, player_color, 1);
+                    for move3 in get_moves(&board3, player_color, roll[2]) {
+                        if dice_len > 3 {
+                            let board4 = board3.apply_n_moves(&move3, player_color, 1);
+                            for move4 in get_moves(&board4, player_color, roll[3]) {
+                                move_sequences.push(MoveSequence::FourMoves(
+                                    move1.clone(),
+                                    move2.clone(),
+                                    move3.clone(),
+                                    move4.clone(),
+                                ));
+                            }
+                        } else {
+                            move_sequences.push(MoveSequence::ThreeMoves(
+                                move1.clone(),
+                                move2.clone(),
+                                move3.clone(),
+                            ));
+                        }
+                    }
+                } else {
+                    move_sequences.push(MoveSequence::TwoMoves(move1.clone(), move2.clone()));
+                }
+            }
+        } else {
+            move_sequences.push(MoveSequence::SingleMove(move1.clone()));
+        }
+    }
+
+    move_sequences
+}
+
+pub(super) fn reference_move_sequences(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    roll: &DiceRoll,
+) -> Vec<MoveSequence> {
+    let max_seq_len = max_valid_moves(board, player_color, roll, None);
+
+    let mut move_sequences = Vec::new();
+
+    if max_seq_len == 0 {
+        return move_sequences;
+    }
+
+    match roll {
+        DiceRoll::Single(_, _) => {
+            let (high_die, low_die) = roll.get_high_low_die();
+            if max_seq_len == 1 {
+                move_sequences.append(&mut get_move_sequences(board, player_color, &[high_die]));
+                if move_sequences.is_empty() {
+                    move_sequences.append(&mut get_move_sequences(board, player_color, &[low_die]));
+                }
+            } else {
+                move_sequences.append(&mut get_move_sequences(
+                    board,
+                    player_color,
+                    &[high_die, low_die],
+                ));
+                move_sequences.append(&mut get_move_sequences(
+                    board,
+                    player_color,
+                    &[low_die, high_die],
+                ));
+            }
+        }
+        DiceRoll::Double(die) => {
+            move_sequences.append(&mut get_move_sequences(
+                board,
+                player_color,
+                &repeat_die(*die, max_seq_len),
+            ));
+        }
+    }
+
+    move_sequences.sort();
+    move_sequences.dedup();
+
+    move_sequences
+}
+
+fn max_valid_moves(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    roll: &DiceRoll,
+    n: Option<usize>,
+) -> usize {
+    let n = n.unwrap_or(4);
+
+    if !board.variant().enter_first() {
+        return max_valid_moves_by_search(board, player_color, roll, n);
+    }
+
+    match roll {
+        DiceRoll::Single(die1, die2) => {
+            if board.checkers_at(player_color, bar_pos(player_color)) > 0 {
+                max_value(
+                    board,
+                    player_color,
+                    &[*die1, *die2],
+                    max_valid_re_entry_moves_single_roll,
+                )
+            } else {
+                max_value(
+                    board,
+                    player_color,
+                    &[*die1, *die2],
+                    max_valid_moves_single_roll,
+                )
+            }
+        }
+        DiceRoll::Double(die) => {
+            if board.checkers_at(player_color, bar_pos(player_color)) > 0 {
+                max_valid_re_entry_moves_double_roll(board, player_color, *die, n)
+            } else {
+                max_n_valid_moves_for_double_roll(board, player_color, *die, n)
+            }
+        }
+    }
+}
+
+/// Longest play found by searching the plays, when the checkers on the bar don't have
+/// to enter first.
+fn max_valid_moves_by_search(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    roll: &DiceRoll,
+    n: usize,
+) -> usize {
+    let orders = match *roll {
+        DiceRoll::Single(die1, die2) => vec![vec![die1, die2], vec![die2, die1]],
+        DiceRoll::Double(die) => vec![repeat_die(die, n)],
+    };
+
+    orders
+        .iter()
+        .flat_map(|dice| (1..=dice.len()).map(move |len| &dice[..len]))
+        .filter(|dice| !get_move_sequences(board, player_color, dice).is_empty())
+        .map(|dice| dice.len())
+        .max()
+        .unwrap_or(0)
+}
+
+fn max_value<F>(board: &BackgammonBoard, player_color: PlayerColor, roll: &[Die], func: F) -> usize
+where
+    F: Fn(&BackgammonBoard, PlayerColor, &[Die]) -> usize,
+{
+    let max_val = func(board, player_color, roll);
+
+    if max_val == 2 {
+        return max_val;
+    }
+
+    std::cmp::max(max_val, func(board, player_color, &[roll[1], roll[0]]))
+}
+
+fn max_valid_re_entry_moves_single_roll(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    roll: &[Die],
+) -> usize {
+    let (die_x, die_y) = (roll[0], roll[1]);
+    let re_entry_move = MoveWithDie::from_bar_with_die(player_color, die_x);
+    if re_entry_move.is_valid_re_entry(board, player_color) {
+        let temp_board = board.apply_n_moves(&re_entry_move, player_color, 1);
+        if temp_board.checkers_at(player_color, bar_pos(player_color)) == 0 {
+            return 1 + max_valid_normal_or_bearing_off_moves_one_die(
+                &temp_board,
+                player_color,
+                die_y,
+            );
+        }
+        let re_entry_move = MoveWithDie::from_bar_with_die(player_color, die_y);
+        if re_entry_move.is_valid_re_entry(&temp_board, player_color) {
+            return 2;
+        }
+        return 1;
+    }
+
+    let re_entry_move = MoveWithDie::from_bar_with_die(player_color, die_y);
+    if re_entry_move.is_valid_re_entry(board, player_color) {
+        let temp_board = board.apply_n_moves(&re_entry_move, player_color, 1);
+
+        if temp_board.checkers_at(player_color, bar_pos(player_color)) == 0 {
+            return 1 + max_valid_normal_or_bearing_off_moves_one_die(
+                &temp_board,
+                player_color,
+                die_x,
+            );
+        }
+        return 1;
+    }
+
+    0
+}
+
+fn max_valid_normal_or_bearing_off_moves_one_die(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    die: Die,
+) -> usize {
+    for pos in PosIter::full_board(player_color, None) {
+        if board.checkers_at(player_color, pos) == 0 {
+            continue;
+        }
+
+        let m = MoveWithDie::with_die(player_color, pos, die);
+
+        if m.is_valid_normal(board, player_color) || m.is_valid_boff(board, player_color, die) {
+            return 1;
+        }
+    }
+
+    0
+}
+
+fn max_valid_moves_single_roll(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    roll: &[Die],
+) -> usize {
+    let (die_x, die_y) = (roll[0], roll[1]);
+
+    let mut moves = 0;
+
+    for ipos in PosIter::full_board(player_color, None) {
+        if board.checkers_at(player_color, ipos) == 0 {
+            continue;
+        }
+
+        let m = MoveWithDie::with_die(player_color, ipos, die_x);
+
+        if m.is_valid_normal(board, player_color) || m.is_valid_boff(board, player_color, die_x) {
+            moves = 1;
+
+            let temp_board = board.apply_n_moves(&m, player_color, 1);
+
+            for jpos in PosIter::full_board(player_color, Some(ipos)) {
+                if temp_board.checkers_at(player_color, jpos) == 0 {
+                    continue;
+                }
+
+                let m = MoveWithDie::with_die(player_color, jpos, die_y);
+                if m.is_valid_normal(&temp_board, player_color)
+                    || m.is_valid_boff(&temp_board, player_color, die_y)
+                {
+                    return 2;
+                }
+            }
+        }
+    }
+
+    moves
+}
+
+fn max_valid_re_entry_moves_double_roll(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    die: Die,
+    n: usize,
+) -> usize {
+    let re_entry_move = MoveWithDie::from_bar_with_die(player_color, die);
+    if re_entry_move.is_valid_re_entry(board, player_color) {
+        let bar_chk = board.checkers_at(player_color, bar_pos(player_color));
+
+        if bar_chk > n {
+            return n;
+        }
+
+        let temp_board = board.apply_n_moves(&re_entry_move, player_color, 1);
+
+        return bar_chk as usize
+            + max_n_valid_moves_for_double_roll(&temp_board, player_color, die, n - bar_chk);
+    }
+
+    0
+}
+
+fn max_n_valid_moves_for_double_roll(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    die: Die,
+    n: usize,
+) -> usize {
+    let mut board = board.clone();
+
+    let mut moves = 0;
+
+    for pos in PosIter::full_board(player_color, None) {
+        let chk = board.checkers_at(player_color, pos);
+        if chk == 0 {
+            continue;
+        }
+
+        let m = MoveWithDie::with_die(player_color, pos, die);
+        if m.is_valid_normal(&board, player_color) {
+            if moves + chk >= n {
+                return n;
+            }
+
+            let max_jumps = ((n - moves) as f32 / chk as f32).ceil() as usize;
+            let jumps = max_n_jumps_with_one_die(&board, player_color, pos, die, max_jumps);
+
+            moves += chk * jumps;
+            if moves >= n {
+                return n;
+            }
+
+            board = board.apply_n_moves(
+                &MoveWithDie::with_die(player_color, pos, die * jumps as u8),
+                player_color,
+                chk,
+            );
+        } else if m.is_valid_boff(&board, player_color, die) {
+            moves += chk;
+            if moves >= n {
+                return n;
+            }
+
+            board = board.apply_n_moves(&m, player_color, chk)
+        }
+    }
+
+    moves
+}
+
+fn max_n_jumps_with_one_die(
+    board: &BackgammonBoard,
+    player_color: PlayerColor,
+    pos: Position,
+    die: Die,
+    n: usize,
+) -> usize {
+    let mut jumps = 1;
+
+    if n == 1 {
+        return n;
+    }
+
+    let mut last_valid_dst = (pos as i8 + (player_color * die as i8)) as Position;
+
+    loop {
+        let m = MoveWithDie::with_die(player_color, pos, die * (jumps + 1));
+
+        if m.is_valid_normal(board, player_color) {
+            jumps += 1;
+
+            if jumps >= n as u8 {
+                return jumps as usize;
+            }
+
+            last_valid_dst = m.dst;
+        } else {
+            let temp_board = board.apply_n_moves(
+                &MoveWithDie {
+                    src: pos,
+                    dst: last_valid_dst,
+                    die: 0,
+                },
+                player_color,
+                1,
+            );
+            let m = MoveWithDie::with_die(player_color, last_valid_dst, die);
+
+            if m.is_valid_boff(&temp_board, player_color, die) {
+                return (jumps + 1) as usize;
+            }
+            break;
+        }
+    }
+
+    jumps as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_valid_moves() {
+        let board = BackgammonBoard::default_board();
+        let max_moves = max_valid_moves(&board, BLACK_PLAYER, &DiceRoll::Single(2, 1), None);
+        assert_eq!(max_moves, 2);
+
+        let max_moves = max_valid_moves(&board, BLACK_PLAYER, &DiceRoll::Double(1), None);
+        assert_eq!(max_moves, 4);
+    }
+}
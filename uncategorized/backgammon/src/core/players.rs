@@ -1,5 +1,5 @@
 use crate::core::board::Point;
-use rand::seq::SliceRandom;
+use rand::{seq::SliceRandom, Rng};
 
 pub(crate) type PlayerColor = i8;
 
@@ -7,11 +7,8 @@ pub(crate) const BLACK_PLAYER: PlayerColor = -1;
 pub(crate) const RED_PLAYER: PlayerColor = 1;
 pub(crate) const NO_PLAYER: PlayerColor = 0;
 
-pub(crate) fn get_random_player() -> PlayerColor {
-    [BLACK_PLAYER, RED_PLAYER]
-        .choose(&mut rand::thread_rng())
-        .cloned()
-        .unwrap()
+pub(crate) fn get_random_player<R: Rng + ?Sized>(rng: &mut R) -> PlayerColor {
+    [BLACK_PLAYER, RED_PLAYER].choose(rng).cloned().unwrap()
 }
 
 pub(crate) fn get_opponent(player_color: PlayerColor) -> PlayerColor {
@@ -78,50 +78,6 @@ impl Iterator for PosIter {
     }
 }
 
-pub(crate) fn lte_bs(player_color: PlayerColor, pos: Position) -> bool {
-    if player_color == BLACK_PLAYER {
-        pos <= BOARD_START
-    } else {
-        pos >= BOARD_END
-    }
-}
-
-pub(crate) fn lt_bs(player_color: PlayerColor, pos: Position) -> bool {
-    if player_color == BLACK_PLAYER {
-        pos < BOARD_START
-    } else {
-        pos > BOARD_END
-    }
-}
-
-pub(crate) fn gte_be(player_color: PlayerColor, pos: Position) -> bool {
-    if player_color == BLACK_PLAYER {
-        pos >= BOARD_END
-    } else {
-        pos <= BOARD_START
-    }
-}
-
-pub(crate) fn gt_be(player_color: PlayerColor, pos: Position) -> bool {
-    if player_color == BLACK_PLAYER {
-        pos > BOARD_END
-    } else {
-        pos < BOARD_START
-    }
-}
-
-pub(crate) fn in_hb(player_color: PlayerColor, pos: Position) -> bool {
-    if player_color == BLACK_PLAYER {
-        pos >= 1 && pos <= 6
-    } else {
-        pos <= 24 && pos >= 19
-    }
-}
-
-pub(crate) fn eq_bo(player_color: PlayerColor, pos: Position) -> bool {
-    pos == norm_pos(BLACK_OFF, player_color)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,209 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    board::BackgammonBoard,
+    players::{b, r},
+    positions::{BLACK_BAR, RED_BAR},
+};
+
+/// How the first roll of a game is made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OpeningRoll {
+    /// each player rolls one die and the higher one plays both, so there are no doubles
+    HigherDie,
+    /// the player winning the opening roll rolls both dice again, doubles included
+    Reroll,
+}
+
+/// Rule set of a game: the starting position, the number of checkers each player bears
+/// off to win, the opening roll and whether the checkers on the bar enter before the
+/// others move. Every board carries its variant, so the end of the game and the bear-off
+/// are always worked out under the rules the position is played with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Variant {
+    #[default]
+    Standard,
+    /// the back checkers spread on the 24 and 23 points
+    Nackgammon,
+    /// 3 checkers on the 24, 23 and 22 points
+    Hypergammon,
+    /// every checker starts off the board, on the bar, and enters in the opponent's home
+    /// board while the entered ones move, as hit checkers do; the extra turn of the 1-2
+    /// roll is not played
+    AceyDeucey,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 4] = [
+        Variant::Standard,
+        Variant::Nackgammon,
+        Variant::Hypergammon,
+        Variant::AceyDeucey,
+    ];
+
+    pub(crate) fn starting_board(&self) -> BackgammonBoard {
+        match self {
+            Variant::Standard => BackgammonBoard::default_board(),
+            Variant::Nackgammon => BackgammonBoard::from_pairs(&[
+                (1, r(2)),
+                (2, r(2)),
+                (6, b(4)),
+                (8, b(3)),
+                (12, r(4)),
+                (13, b(4)),
+                (17, r(3)),
+                (19, r(4)),
+                (23, b(2)),
+                (24, b(2)),
+            ]),
+            Variant::Hypergammon => BackgammonBoard::from_pairs(&[
+                (1, r(1)),
+                (2, r(1)),
+                (3, r(1)),
+                (22, b(1)),
+                (23, b(1)),
+                (24, b(1)),
+            ]),
+            Variant::AceyDeucey => {
+                BackgammonBoard::from_pairs(&[(BLACK_BAR, b(15)), (RED_BAR, r(15))])
+            }
+        }
+        .with_variant(*self)
+    }
+
+    /// Checkers of each player.
+    pub fn checkers(&self) -> u8 {
+        match self {
+            Variant::Hypergammon => 3,
+            _ => 15,
+        }
+    }
+
+    pub fn opening_roll(&self) -> OpeningRoll {
+        match self {
+            Variant::AceyDeucey => OpeningRoll::Reroll,
+            _ => OpeningRoll::HigherDie,
+        }
+    }
+
+    /// The checkers on the bar enter before any other checker moves.
+    pub(crate) fn enter_first(&self) -> bool {
+        *self != Variant::AceyDeucey
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Variant::Standard => "standard",
+            Variant::Nackgammon => "nackgammon",
+            Variant::Hypergammon => "hypergammon",
+            Variant::AceyDeucey => "acey-deucey",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Variant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Variant::ALL
+            .into_iter()
+            .find(|variant| variant.to_string() == s)
+            .ok_or_else(|| {
+                format!(
+                    "unknown variant `{}`, expected standard, nackgammon, hypergammon or acey-deucey",
+                    s
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            players::{BLACK_PLAYER, RED_PLAYER},
+            positions::{BLACK_OFF, RED_OFF},
+        },
+        game::game::{get_victory_type, get_winner, VictoryType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_starting_positions() {
+        for variant in Variant::ALL {
+            let board = variant.starting_board();
+            let checkers = variant.checkers() as i8;
+
+            assert_eq!(board.variant(), variant);
+            assert_eq!(board.points(), (checkers, checkers), "{}", variant);
+            assert_eq!(get_winner(&board), None, "{}", variant);
+            assert!(!board.can_bear_off(BLACK_PLAYER), "{}", variant);
+            assert!(!board.can_bear_off(RED_PLAYER), "{}", variant);
+            assert_eq!(variant.to_string().parse(), Ok(variant));
+        }
+    }
+
+    #[test]
+    fn test_hypergammon_end_of_game() {
+        // the bear-off and the end of the game depend on the 3 checkers, not on 15
+        let board = BackgammonBoard::from_pairs(&[(1, b(1)), (BLACK_OFF, b(2)), (24, r(3))])
+            .with_variant(Variant::Hypergammon);
+        assert!(board.can_bear_off(BLACK_PLAYER));
+        assert_eq!(get_winner(&board), None);
+
+        let board = BackgammonBoard::from_pairs(&[(BLACK_OFF, b(3)), (3, r(3))])
+            .with_variant(Variant::Hypergammon);
+        assert_eq!(get_winner(&board), Some(BLACK_PLAYER));
+        assert_eq!(
+            get_victory_type(&board, BLACK_PLAYER),
+            VictoryType::Backgammon
+        );
+
+        let board = BackgammonBoard::from_pairs(&[(BLACK_OFF, b(3)), (7, r(2)), (RED_OFF, r(1))])
+            .with_variant(Variant::Hypergammon);
+        assert_eq!(get_victory_type(&board, BLACK_PLAYER), VictoryType::Game);
+    }
+
+    #[test]
+    fn test_position_read_under_its_own_rules() {
+        // the same checkers end a hypergammon game but not a standard one
+        let board = BackgammonBoard::from_pairs(&[(BLACK_OFF, b(3)), (3, r(3))]);
+        assert_eq!(get_winner(&board), None);
+        assert!(!board.can_bear_off(RED_PLAYER));
+
+        let board = board.with_variant(Variant::Hypergammon);
+        assert_eq!(get_winner(&board), Some(BLACK_PLAYER));
+    }
+
+    #[test]
+    fn test_nackgammon_end_of_game() {
+        let board = BackgammonBoard::from_pairs(&[(RED_OFF, r(15)), (2, b(1)), (13, b(14))])
+            .with_variant(Variant::Nackgammon);
+        assert_eq!(get_winner(&board), Some(RED_PLAYER));
+        assert_eq!(get_victory_type(&board, RED_PLAYER), VictoryType::Gammon);
+    }
+
+    #[test]
+    fn test_acey_deucey_start_and_end_of_game() {
+        let board = Variant::AceyDeucey.starting_board();
+        assert_eq!(board.checkers_at(BLACK_PLAYER, BLACK_BAR), 15);
+        assert_eq!(board.checkers_at(RED_PLAYER, RED_BAR), 15);
+        assert_eq!(Variant::AceyDeucey.opening_roll(), OpeningRoll::Reroll);
+
+        // checkers that never entered lose a backgammon
+        let board = BackgammonBoard::from_pairs(&[(BLACK_OFF, b(15)), (RED_BAR, r(2)), (9, r(13))])
+            .with_variant(Variant::AceyDeucey);
+        assert_eq!(get_winner(&board), Some(BLACK_PLAYER));
+        assert_eq!(
+            get_victory_type(&board, BLACK_PLAYER),
+            VictoryType::Backgammon
+        );
+    }
+}
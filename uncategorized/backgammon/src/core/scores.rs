@@ -20,7 +20,7 @@ pub(crate) fn get_game_phase(board: &BackgammonBoard, player_color: PlayerColor)
     }
 }
 
-fn is_race(board: &BackgammonBoard) -> bool {
+pub(crate) fn is_race(board: &BackgammonBoard) -> bool {
     if board.checkers_at(BLACK_PLAYER, BLACK_BAR) > 0 || board.checkers_at(RED_PLAYER, RED_BAR) > 0
     {
         return false;
@@ -74,7 +74,7 @@ pub(crate) fn get_pip_count_diff(board: &BackgammonBoard, player_color: PlayerCo
     get_pip_count(board, get_opponent(player_color)) - get_pip_count(board, player_color)
 }
 
-fn get_pip_count(board: &BackgammonBoard, player_color: PlayerColor) -> f32 {
+pub(crate) fn get_pip_count(board: &BackgammonBoard, player_color: PlayerColor) -> f32 {
     25.0 * board.checkers_at(player_color, bar_pos(player_color)) as f32
         + PosIter::full_board(player_color, None)
             .map(|pos| {
@@ -175,4 +175,4 @@ mod tests {
         assert_eq!(get_pip_count_diff(&board, BLACK_PLAYER), 0.0);
         assert_eq!(get_pip_count_diff(&board, RED_PLAYER), 0.0);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,331 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{
+    agents::search::{evaluate_plays, BoardEvaluator},
+    core::{
+        board::BackgammonBoard,
+        dice::DiceRoll,
+        moves::{generate_all_move_sequences, MoveSequence},
+        players::{PlayerColor, BLACK_PLAYER, RED_PLAYER},
+    },
+};
+
+use super::{
+    game::{get_victory_type, get_winner},
+    notation::{format_move_sequence, format_roll},
+    record::GameRecord,
+};
+
+/// Equity losses from which a play is an error or a blunder.
+#[derive(Debug, Clone, Copy)]
+pub struct AnalysisConfig {
+    pub error: f64,
+    pub blunder: f64,
+}
+
+impl Default for AnalysisConfig {
+    /// The thresholds of the doubtful and very bad plays of GNU Backgammon.
+    fn default() -> Self {
+        Self {
+            error: 0.04,
+            blunder: 0.16,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum PlayQuality {
+    Good,
+    Error,
+    Blunder,
+}
+
+/// A turn with more than one legal play, with the play made and the best one.
+#[derive(Debug, Clone)]
+pub struct PlayAnalysis {
+    /// index of the turn in the record
+    pub turn: usize,
+    pub player_color: PlayerColor,
+    pub roll: DiceRoll,
+    /// position before the play
+    pub board: BackgammonBoard,
+    pub played: MoveSequence,
+    pub best: MoveSequence,
+    /// cubeless equities of the player after the plays, from -3 to 3
+    pub played_equity: f64,
+    pub best_equity: f64,
+    /// 1 for the best play, among `plays` legal plays
+    pub rank: usize,
+    pub plays: usize,
+    pub quality: PlayQuality,
+}
+
+impl PlayAnalysis {
+    pub fn equity_loss(&self) -> f64 {
+        self.best_equity - self.played_equity
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct PlayerSummary {
+    pub player_color: PlayerColor,
+    /// turns with more than one legal play
+    pub decisions: usize,
+    /// plays losing at least the error threshold, the blunders included
+    pub errors: usize,
+    pub blunders: usize,
+    pub equity_loss: f64,
+}
+
+impl PlayerSummary {
+    pub fn error_rate(&self) -> f64 {
+        self.errors as f64 / self.decisions.max(1) as f64
+    }
+
+    pub fn loss_per_decision(&self) -> f64 {
+        self.equity_loss / self.decisions.max(1) as f64
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GameAnalysis {
+    pub config: AnalysisConfig,
+    pub plays: Vec<PlayAnalysis>,
+}
+
+impl GameAnalysis {
+    pub fn summary(&self, player_color: PlayerColor) -> PlayerSummary {
+        let mut summary = PlayerSummary {
+            player_color,
+            decisions: 0,
+            errors: 0,
+            blunders: 0,
+            equity_loss: 0.0,
+        };
+        for play in self.plays.iter().filter(|p| p.player_color == player_color) {
+            summary.decisions += 1;
+            summary.errors += (play.quality != PlayQuality::Good) as usize;
+            summary.blunders += (play.quality == PlayQuality::Blunder) as usize;
+            summary.equity_loss += play.equity_loss();
+        }
+        summary
+    }
+
+    pub fn blunders(&self) -> impl Iterator<Item = &PlayAnalysis> {
+        self.plays
+            .iter()
+            .filter(|play| play.quality == PlayQuality::Blunder)
+    }
+}
+
+/// Rates every play of `record` against the other legal plays of its roll, all of them
+/// ranked at 1-ply by `evaluator`. The turns without a choice are left out.
+pub fn analyze_game(
+    record: &GameRecord,
+    evaluator: &dyn BoardEvaluator,
+    config: &AnalysisConfig,
+) -> GameAnalysis {
+    let mut plays = Vec::new();
+
+    for (i, (turn, board)) in record.turns.iter().zip(record.positions()).enumerate() {
+        let Some(played) = &turn.move_sequence else {
+            continue;
+        };
+        let move_sequences = generate_all_move_sequences(&board, turn.player_color, &turn.roll);
+        if move_sequences.len() < 2 {
+            continue;
+        }
+
+        // the play made is valued with the legal plays, so that all are on the same scale
+        let boards = move_sequences
+            .iter()
+            .chain([played])
+            .map(|move_sequence| board.apply_move_sequence(move_sequence, turn.player_color))
+            .collect::<Vec<_>>();
+        let mut equities = evaluate_plays(evaluator, &boards, turn.player_color)
+            .into_iter()
+            .zip(&boards)
+            .map(|(value, after)| equity(value, after, turn.player_color))
+            .collect::<Vec<_>>();
+        let played_equity = equities.pop().unwrap();
+
+        let (best_equity, best) = equities
+            .iter()
+            .zip(&move_sequences)
+            .max_by(|(x, _), (y, _)| x.total_cmp(y))
+            .map(|(&equity, move_sequence)| (equity, move_sequence))
+            .unwrap();
+        let rank = 1 + equities
+            .iter()
+            .filter(|&&equity| equity > played_equity + 1e-9)
+            .count();
+
+        let loss = best_equity - played_equity;
+        let quality = if loss >= config.blunder {
+            PlayQuality::Blunder
+        } else if loss >= config.error {
+            PlayQuality::Error
+        } else {
+            PlayQuality::Good
+        };
+
+        plays.push(PlayAnalysis {
+            turn: i,
+            player_color: turn.player_color,
+            roll: turn.roll,
+            board: board.clone(),
+            played: played.clone(),
+            best: best.clone(),
+            played_equity,
+            best_equity,
+            rank,
+            plays: move_sequences.len(),
+            quality,
+        });
+    }
+
+    GameAnalysis {
+        config: *config,
+        plays,
+    }
+}
+
+/// Cubeless equity of `player_color` right after its play: the points of the game when
+/// it ends with the play, or else its winning chances `value` minus its losing chances.
+fn equity(value: f64, board: &BackgammonBoard, player_color: PlayerColor) -> f64 {
+    if get_winner(board).is_some() {
+        return get_victory_type(board, player_color).value() as f64;
+    }
+    2.0 * value - 1.0
+}
+
+fn player_name(player_color: PlayerColor) -> &'static str {
+    if player_color == BLACK_PLAYER {
+        "Black"
+    } else {
+        "Red"
+    }
+}
+
+/// One line per decision, with the best play after the errors, and the summaries.
+impl fmt::Display for GameAnalysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for play in &self.plays {
+            let mark = match play.quality {
+                PlayQuality::Good => "",
+                PlayQuality::Error => "?",
+                PlayQuality::Blunder => "??",
+            };
+            let mut line = format!(
+                "{:>4}. {:<5} {}: {:<24} {:>+7.3} {}",
+                play.turn + 1,
+                player_name(play.player_color),
+                format_roll(&play.roll),
+                format_move_sequence(&play.board, &play.played, play.player_color),
+                -play.equity_loss(),
+                mark
+            );
+            if play.quality != PlayQuality::Good {
+                line += &format!(
+                    " best {} (rank {} of {})",
+                    format_move_sequence(&play.board, &play.best, play.player_color),
+                    play.rank,
+                    play.plays
+                );
+            }
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        writeln!(f)?;
+        for player_color in [BLACK_PLAYER, RED_PLAYER] {
+            let summary = self.summary(player_color);
+            writeln!(
+                f,
+                "{}: {} decisions, {} errors ({:.1}%), {} blunders, equity lost {:.3} \
+                 ({:.3} per decision)",
+                player_name(player_color),
+                summary.decisions,
+                summary.errors,
+                summary.error_rate() * 100.0,
+                summary.blunders,
+                summary.equity_loss,
+                summary.loss_per_decision()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        agents::{agent::TestingAgent, logic::LogicAgent, random::RandomAgent},
+        core::players::BLACK_PLAYER,
+        game::{game::play_game, record::TurnRecord, rng::GameRng},
+    };
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_analyze_game() {
+        let black: Box<dyn TestingAgent> = Box::new(RandomAgent::new());
+        let red: Box<dyn TestingAgent> = Box::new(LogicAgent::new_hard());
+        let agents = HashMap::from([(BLACK_PLAYER, black.as_ref()), (RED_PLAYER, red.as_ref())]);
+        let record = play_game(&agents, &mut GameRng::from_seed(5), false).record;
+
+        let analysis = analyze_game(&record, &LogicAgent::new_hard(), &Default::default());
+
+        assert!(!analysis.plays.is_empty());
+        for play in &analysis.plays {
+            assert!(play.equity_loss() >= 0.0);
+            assert!(play.rank >= 1 && play.rank <= play.plays);
+            assert_eq!(play.rank == 1, play.equity_loss() < 1e-9);
+        }
+        // the logic agent plays the best play of its own evaluation
+        let red_summary = analysis.summary(RED_PLAYER);
+        assert!(red_summary.decisions > 0);
+        assert_eq!(red_summary.errors, 0);
+        assert!(red_summary.equity_loss.abs() < 1e-9);
+        assert!(analysis.summary(BLACK_PLAYER).errors > 0);
+    }
+
+    #[test]
+    fn test_worst_play_is_a_blunder() {
+        let evaluator = LogicAgent::new_hard();
+        let board = BackgammonBoard::default_board();
+        let roll = DiceRoll::Single(6, 5);
+
+        let worst = generate_all_move_sequences(&board, BLACK_PLAYER, &roll)
+            .into_iter()
+            .min_by(|x, y| {
+                let x =
+                    evaluator.evaluate(&board.apply_move_sequence(x, BLACK_PLAYER), BLACK_PLAYER);
+                let y =
+                    evaluator.evaluate(&board.apply_move_sequence(y, BLACK_PLAYER), BLACK_PLAYER);
+                x.total_cmp(&y)
+            })
+            .unwrap();
+        let mut record = GameRecord::new(0, board, BLACK_PLAYER);
+        record.turns.push(TurnRecord {
+            player_color: BLACK_PLAYER,
+            doubled: false,
+            roll,
+            move_sequence: Some(worst),
+        });
+
+        let config = AnalysisConfig {
+            error: 0.0,
+            blunder: 0.0,
+        };
+        let analysis = analyze_game(&record, &evaluator, &config);
+
+        let play = &analysis.plays[0];
+        assert_eq!(play.rank, play.plays);
+        assert!(play.equity_loss() > 0.0);
+        assert_eq!(analysis.blunders().count(), 1);
+        assert_eq!(analysis.summary(BLACK_PLAYER).error_rate(), 1.0);
+    }
+}
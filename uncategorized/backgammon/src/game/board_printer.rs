@@ -21,6 +21,28 @@ impl BoardPrinter {
         roll: &DiceRoll,
         move_sequence: Option<MoveSequence>,
     ) {
+        self.print_position(board);
+        println!("Player Color: {}", player_color);
+        println!("Roll: {:?}", roll);
+        if let Some(ms) = move_sequence {
+            println!("Moves: {:?}", ms)
+        }
+
+        // both players have the checkers of the variant of the board
+        let checkers = board.variant().checkers() as i8;
+        let (blk_points, red_points) = board.points();
+        if blk_points != checkers {
+            println!("black points are wrong");
+            println!();
+        }
+        if red_points != checkers {
+            println!("red points are wrong");
+            println!();
+        }
+    }
+
+    /// Prints only the checkers on the board.
+    pub(crate) fn print_position(&self, board: &BackgammonBoard) {
         println!("||-----------------------------||||||--------- Red Home Board ---------||");
         println!("|| 13 | 14 | 15 | 16 | 17 | 18 |||||| 19 | 20 | 21 | 22 | 23 | 24 | 25 ||");
         print_line(board, "||||", UPER_POS, 1);
@@ -39,23 +61,6 @@ impl BoardPrinter {
         println!("|| 12 | 11 | 10 |  9 |  8 |  7 ||||||  6 |  5 |  4 |  3 |  2 |  1 |  0 ||");
         println!("||-----------------------------||||||-------- Black Home Board --------||");
         println!();
-        println!("Player Color: {}", player_color);
-        println!("Roll: {:?}", roll);
-        if let Some(ms) = move_sequence {
-            println!("Moves: {:?}", ms)
-        }
-
-        let (blk_points, red_points) = board.points();
-        if blk_points > 15 {
-            println!("black points are wrong");
-            println!();
-        }
-        if red_points > 15 {
-            println!("red points are wrong");
-            println!();
-        }
-
-        pause();
     }
 }
 
@@ -115,7 +120,7 @@ fn print_bar(board: &BackgammonBoard, player_color: PlayerColor) -> String {
 }
 
 // pauses the execution of the program until the user press a key on the terminal
-fn pause() {
+pub(crate) fn pause() {
     let mut stdin = io::stdin();
 
     // Read a single byte and discard
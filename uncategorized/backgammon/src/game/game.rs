@@ -1,27 +1,34 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Mutex, thread};
+
+use serde::{Deserialize, Serialize};
 
 use crate::{
     agents::agent::TestingAgent,
     core::{
         board::BackgammonBoard,
-        dice::DiceRoll,
-        players::{get_opponent, get_random_player, PlayerColor, BLACK_PLAYER, RED_PLAYER},
+        cube::{Cube, CubeState},
+        players::{get_opponent, PlayerColor, BLACK_PLAYER, RED_PLAYER},
         positions::{bar_pos, norm_pos, BLACK_OFF, RED_OFF},
+        rules::Variant,
     },
 };
 
-use super::board_printer::BoardPrinter;
+use super::{
+    board_printer::{pause, BoardPrinter},
+    record::{GameRecord, TurnRecord},
+    rng::GameRng,
+};
 
+/// The player with all the checkers of the variant of the board borne off.
 pub(crate) fn get_winner(board: &BackgammonBoard) -> Option<PlayerColor> {
-    if board.checkers_at(BLACK_PLAYER, BLACK_OFF) == 15 {
-        return Some(BLACK_PLAYER);
-    }
-    if board.checkers_at(RED_PLAYER, RED_OFF) == 15 {
-        return Some(RED_PLAYER);
-    }
-    return None;
+    let checkers = board.variant().checkers() as usize;
+    [(BLACK_PLAYER, BLACK_OFF), (RED_PLAYER, RED_OFF)]
+        .into_iter()
+        .find(|&(player_color, off)| board.checkers_at(player_color, off) == checkers)
+        .map(|(player_color, _)| player_color)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum VictoryType {
     Game,
     Gammon,
@@ -62,63 +69,444 @@ pub struct GameResult {
     pub winner: PlayerColor,
     pub turn: usize,
     pub victory_type: VictoryType,
+    pub cube: Cube,
+    /// the loser refused a double, the winner gets the value of the cube
+    pub dropped: bool,
+    /// the loser resigned, the winner gets the value of the cube
+    pub resigned: bool,
+    pub record: GameRecord,
 }
 
-pub fn play_game(agents: HashMap<PlayerColor, &Box<dyn TestingAgent>>, print: bool) -> GameResult {
-    let mut board = BackgammonBoard::default_board();
-    let mut player_color = get_random_player();
-    let mut roll = DiceRoll::get_dice_roll();
+impl GameResult {
+    /// Points won by the winner.
+    pub fn points(&self) -> i32 {
+        self.victory_type.value() * self.cube.value as i32
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GameOptions {
+    /// whether the players can double
+    pub cube: bool,
+    /// points black and red still need to win the match, `None` in money play
+    pub away: Option<(u32, u32)>,
+    /// starting position and opening roll
+    pub variant: Variant,
+}
+
+impl GameOptions {
+    fn cube_state(&self, cube: Cube, player_color: PlayerColor) -> CubeState {
+        CubeState {
+            cube,
+            away: self.away.map(|(black_away, red_away)| {
+                if player_color == BLACK_PLAYER {
+                    (black_away, red_away)
+                } else {
+                    (red_away, black_away)
+                }
+            }),
+        }
+    }
+
+    /// Doubling is pointless once the cube already covers the points the player needs.
+    fn can_double(&self, cube: Cube, player_color: PlayerColor) -> bool {
+        self.cube
+            && cube.can_double(player_color)
+            && self
+                .cube_state(cube, player_color)
+                .away
+                .is_none_or(|(own, _)| cube.value < own)
+    }
+}
+
+/// Plays a game without doubling cube.
+pub fn play_game(
+    agents: &HashMap<PlayerColor, &dyn TestingAgent>,
+    rng: &mut GameRng,
+    print: bool,
+) -> GameResult {
+    play_game_with_options(agents, rng, GameOptions::default(), print)
+}
+
+pub fn play_game_with_options(
+    agents: &HashMap<PlayerColor, &dyn TestingAgent>,
+    rng: &mut GameRng,
+    options: GameOptions,
+    print: bool,
+) -> GameResult {
+    let mut board = options.variant.starting_board();
+    let mut player_color = rng.first_player();
+    let mut roll = rng.opening_roll(options.variant.opening_roll());
+    let mut record = GameRecord::new(rng.seed(), board.clone(), player_color);
+    let mut cube = Cube::default();
+    let mut doubled = false;
 
     let printer = BoardPrinter;
     if print {
-        printer.print_board(&board, player_color, &roll, None)
+        printer.print_board(&board, player_color, &roll, None);
+        pause();
     }
 
     let mut turn = 0;
     loop {
         let agent = agents.get(&player_color).unwrap();
 
-        let move_sequence = agent.get_best_action(&board, player_color, &roll);
+        let move_sequence = agent.get_best_action(&board, player_color, &roll, rng.agent_rng());
+        if agent.has_resigned() {
+            let winner = get_opponent(player_color);
+            record.winner = Some(winner);
+            record.victory_type = Some(VictoryType::Game);
+            record.cube = cube;
+            record.resigned = true;
+
+            return GameResult {
+                winner,
+                turn,
+                victory_type: VictoryType::Game,
+                cube,
+                dropped: false,
+                resigned: true,
+                record,
+            };
+        }
+        record.turns.push(TurnRecord {
+            player_color,
+            doubled,
+            roll,
+            move_sequence: move_sequence.clone(),
+        });
+
         if let Some(move_sequence) = move_sequence.clone() {
             board = board.apply_move_sequence(&move_sequence, player_color);
 
             if let Some(winner) = get_winner(&board) {
+                let victory_type = get_victory_type(&board, player_color);
+                record.winner = Some(winner);
+                record.victory_type = Some(victory_type);
+                record.cube = cube;
+
                 return GameResult {
                     winner,
                     turn,
-                    victory_type: get_victory_type(&board, player_color),
+                    victory_type,
+                    cube,
+                    dropped: false,
+                    resigned: false,
+                    record,
                 };
             }
         }
 
         player_color = get_opponent(player_color);
-        roll = DiceRoll::get_dice_roll();
+
+        doubled = false;
+        if options.can_double(cube, player_color) {
+            let agent = agents.get(&player_color).unwrap();
+            if agent.should_double(
+                &board,
+                player_color,
+                &options.cube_state(cube, player_color),
+            ) {
+                let opponent = get_opponent(player_color);
+                let opponent_agent = agents.get(&opponent).unwrap();
+                if opponent_agent.should_take(&board, opponent, &options.cube_state(cube, opponent))
+                {
+                    cube = cube.doubled(opponent);
+                    doubled = true;
+                } else {
+                    record.winner = Some(player_color);
+                    record.victory_type = Some(VictoryType::Game);
+                    record.cube = cube;
+                    record.dropped = true;
+
+                    return GameResult {
+                        winner: player_color,
+                        turn,
+                        victory_type: VictoryType::Game,
+                        cube,
+                        dropped: true,
+                        resigned: false,
+                        record,
+                    };
+                }
+            }
+        }
+
+        roll = rng.roll_dice();
 
         if print {
             printer.print_board(&board, player_color, &roll, move_sequence);
+            pause();
         }
         turn += 1;
     }
 }
 
-#[derive(Default)]
-pub struct EvaluateMetrics {
-    wins: usize,
-    overall_score: i32,
-    score_on_wins: i32,
-    score_on_lose: i32,
-    hits_to_opponent: i32,
-    hits_from_opponent: i32,
+/// Builds the agents of `play_games`, one for each thread.
+pub type AgentFactory = dyn Fn() -> Box<dyn TestingAgent> + Sync;
+
+/// Plays a game with each of `rngs` between the agents built by `black` and `red`, on up
+/// to `threads` threads. The results are in the order of `rngs`, whatever the number of
+/// threads.
+pub fn play_games(
+    black: &AgentFactory,
+    red: &AgentFactory,
+    rngs: Vec<GameRng>,
+    options: GameOptions,
+    threads: usize,
+) -> Vec<GameResult> {
+    let threads = threads.clamp(1, rngs.len().max(1));
+    let queue = Mutex::new(rngs.into_iter().enumerate());
+
+    let mut results = thread::scope(|scope| {
+        let handles = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let black_agent = black();
+                    let red_agent = red();
+                    let agents = HashMap::from([
+                        (BLACK_PLAYER, black_agent.as_ref()),
+                        (RED_PLAYER, red_agent.as_ref()),
+                    ]);
+
+                    let mut results = Vec::new();
+                    loop {
+                        let next = queue.lock().unwrap().next();
+                        let Some((i, mut rng)) = next else {
+                            return results;
+                        };
+                        results
+                            .push((i, play_game_with_options(&agents, &mut rng, options, false)));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
 }
 
-impl EvaluateMetrics {}
+#[cfg(test)]
+mod tests {
+    use crate::agents::random::RandomAgent;
 
-pub fn run_n_games(
-    agents: HashMap<PlayerColor, &Box<dyn TestingAgent>>,
-    print: bool,
-    parallel: bool,
-) -> EvaluateMetrics {
-    let mut metrics = EvaluateMetrics::default();
+    use super::*;
+
+    fn random_agents() -> (Box<dyn TestingAgent>, Box<dyn TestingAgent>) {
+        (Box::new(RandomAgent::new()), Box::new(RandomAgent::new()))
+    }
+
+    #[test]
+    fn test_play_game_is_deterministic_for_a_seed() {
+        let (black_agent, red_agent) = random_agents();
+        let agents = HashMap::from([
+            (BLACK_PLAYER, black_agent.as_ref()),
+            (RED_PLAYER, red_agent.as_ref()),
+        ]);
+
+        let result1 = play_game(&agents, &mut GameRng::from_seed(7), false);
+        let result2 = play_game(&agents, &mut GameRng::from_seed(7), false);
+
+        assert_eq!(result1.winner, result2.winner);
+        assert_eq!(result1.turn, result2.turn);
+        assert_eq!(result1.victory_type, result2.victory_type);
+        assert_eq!(result1.record.rolls(), result2.record.rolls());
+    }
+
+    #[test]
+    fn test_play_game_replay_from_recorded_rolls() {
+        let (black_agent, red_agent) = random_agents();
+        let agents = HashMap::from([
+            (BLACK_PLAYER, black_agent.as_ref()),
+            (RED_PLAYER, red_agent.as_ref()),
+        ]);
 
-    metrics
+        let result = play_game(&agents, &mut GameRng::from_entropy(), false);
+        let mut rng = GameRng::with_recorded_rolls(result.record.seed, result.record.rolls());
+        let replayed = play_game(&agents, &mut rng, false);
+
+        assert_eq!(replayed.record.seed, result.record.seed);
+        assert_eq!(replayed.winner, result.winner);
+        assert_eq!(replayed.turn, result.turn);
+        assert_eq!(replayed.victory_type, result.victory_type);
+        assert_eq!(replayed.record.rolls(), result.record.rolls());
+    }
+
+    struct CubeAgent {
+        double: bool,
+        take: bool,
+    }
+
+    impl TestingAgent for CubeAgent {
+        fn get_best_action(
+            &self,
+            board: &BackgammonBoard,
+            player_color: PlayerColor,
+            roll: &crate::core::dice::DiceRoll,
+            rng: &mut dyn rand::RngCore,
+        ) -> Option<crate::core::moves::MoveSequence> {
+            RandomAgent::new().get_best_action(board, player_color, roll, rng)
+        }
+
+        fn should_double(&self, _: &BackgammonBoard, _: PlayerColor, _: &CubeState) -> bool {
+            self.double
+        }
+
+        fn should_take(&self, _: &BackgammonBoard, _: PlayerColor, _: &CubeState) -> bool {
+            self.take
+        }
+    }
+
+    #[test]
+    fn test_play_game_double_drop() {
+        let black_agent: Box<dyn TestingAgent> = Box::new(CubeAgent {
+            double: true,
+            take: false,
+        });
+        let red_agent: Box<dyn TestingAgent> = Box::new(CubeAgent {
+            double: true,
+            take: false,
+        });
+        let agents = HashMap::from([
+            (BLACK_PLAYER, black_agent.as_ref()),
+            (RED_PLAYER, red_agent.as_ref()),
+        ]);
+        let options = GameOptions {
+            cube: true,
+            ..Default::default()
+        };
+
+        let result = play_game_with_options(&agents, &mut GameRng::from_seed(3), options, false);
+
+        assert!(result.dropped);
+        assert_eq!(result.turn, 0);
+        assert_eq!(result.points(), 1);
+        assert_ne!(result.winner, result.record.first_player);
+    }
+
+    struct ResigningAgent;
+
+    impl TestingAgent for ResigningAgent {
+        fn get_best_action(
+            &self,
+            _: &BackgammonBoard,
+            _: PlayerColor,
+            _: &crate::core::dice::DiceRoll,
+            _: &mut dyn rand::RngCore,
+        ) -> Option<crate::core::moves::MoveSequence> {
+            None
+        }
+
+        fn has_resigned(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_play_game_resign() {
+        let random_agent = RandomAgent::new();
+        let agents = HashMap::from([
+            (BLACK_PLAYER, &ResigningAgent as &dyn TestingAgent),
+            (RED_PLAYER, &random_agent as &dyn TestingAgent),
+        ]);
+
+        let result = play_game(&agents, &mut GameRng::from_seed(3), false);
+
+        assert!(result.resigned);
+        assert!(result.record.resigned);
+        assert_eq!(result.winner, RED_PLAYER);
+        assert_eq!(result.points(), 1);
+    }
+
+    #[test]
+    fn test_play_game_double_take() {
+        let black_agent: Box<dyn TestingAgent> = Box::new(CubeAgent {
+            double: true,
+            take: true,
+        });
+        let red_agent: Box<dyn TestingAgent> = Box::new(CubeAgent {
+            double: true,
+            take: true,
+        });
+        let agents = HashMap::from([
+            (BLACK_PLAYER, black_agent.as_ref()),
+            (RED_PLAYER, red_agent.as_ref()),
+        ]);
+
+        // without cube the doubles are never offered
+        let result = play_game(&agents, &mut GameRng::from_seed(3), false);
+        assert_eq!(result.cube.value, 1);
+
+        // 7 points away the cube is turned until it covers the points both players need
+        let options = GameOptions {
+            cube: true,
+            away: Some((7, 7)),
+            ..Default::default()
+        };
+        let result = play_game_with_options(&agents, &mut GameRng::from_seed(3), options, false);
+
+        assert!(!result.dropped);
+        assert_eq!(result.cube.value, 8);
+        assert_eq!(result.points(), result.victory_type.value() * 8);
+        assert_eq!(result.record.turns.iter().filter(|t| t.doubled).count(), 3);
+    }
+
+    #[test]
+    fn test_play_game_variants() {
+        let (black_agent, red_agent) = random_agents();
+        let agents = HashMap::from([
+            (BLACK_PLAYER, black_agent.as_ref()),
+            (RED_PLAYER, red_agent.as_ref()),
+        ]);
+
+        for variant in Variant::ALL {
+            let options = GameOptions {
+                variant,
+                ..Default::default()
+            };
+            let result =
+                play_game_with_options(&agents, &mut GameRng::from_seed(9), options, false);
+
+            assert_eq!(result.record.initial_board, variant.starting_board());
+            let last = result.record.positions().pop().unwrap();
+            assert_eq!(get_winner(&last), Some(result.winner));
+            assert_eq!(
+                last.checkers_at(result.winner, norm_pos(BLACK_OFF, result.winner)),
+                variant.checkers() as usize
+            );
+        }
+    }
+
+    #[test]
+    fn test_play_games_in_parallel_as_in_sequence() {
+        let factory = || Box::new(RandomAgent::new()) as Box<dyn TestingAgent>;
+        let seeds = [1, 2, 3, 4, 5];
+
+        let results = play_games(
+            &factory,
+            &factory,
+            seeds.iter().map(|&seed| GameRng::from_seed(seed)).collect(),
+            GameOptions::default(),
+            3,
+        );
+
+        let (black_agent, red_agent) = random_agents();
+        let agents = HashMap::from([
+            (BLACK_PLAYER, black_agent.as_ref()),
+            (RED_PLAYER, red_agent.as_ref()),
+        ]);
+        assert_eq!(results.len(), seeds.len());
+        for (result, seed) in results.iter().zip(seeds) {
+            let expected = play_game(&agents, &mut GameRng::from_seed(seed), false);
+            assert_eq!(result.record.seed, seed);
+            assert_eq!(result.winner, expected.winner);
+            assert_eq!(result.turn, expected.turn);
+        }
+    }
 }
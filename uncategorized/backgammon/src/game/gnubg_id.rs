@@ -0,0 +1,493 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::core::{
+    board::{BackgammonBoard, Point},
+    cube::Cube,
+    dice::DiceRoll,
+    players::{b, get_opponent, r, PlayerColor, BLACK_PLAYER, RED_PLAYER},
+    positions::{bar_pos, norm_pos, Position, BLACK_OFF},
+};
+
+use super::{board_printer::BoardPrinter, game::VictoryType, notation::format_roll};
+
+const CHECKERS: usize = 15;
+const POSITION_KEY_BITS: usize = 80;
+const MATCH_KEY_BITS: usize = 66;
+
+// GNU Backgammon numbers the players: black is player 0 and red player 1.
+fn player_index(player_color: PlayerColor) -> u32 {
+    if player_color == BLACK_PLAYER {
+        0
+    } else {
+        1
+    }
+}
+
+fn player_color(index: u32) -> PlayerColor {
+    if index == 0 {
+        BLACK_PLAYER
+    } else {
+        RED_PLAYER
+    }
+}
+
+/// Position ID of `board`: for the player not on roll and then the player on roll, one bit
+/// set for each checker on its points 1 to 24 and the bar, each point ended by a clear bit.
+pub fn encode_position_id(board: &BackgammonBoard, player_on_roll: PlayerColor) -> String {
+    let mut key = [0u8; POSITION_KEY_BITS / 8];
+    let mut bit = 0;
+
+    for player_color in [get_opponent(player_on_roll), player_on_roll] {
+        let positions = (1..=24)
+            .map(|point| norm_pos(point, player_color))
+            .chain([bar_pos(player_color)]);
+        for pos in positions {
+            for _ in 0..board.checkers_at(player_color, pos) {
+                key[bit / 8] |= 1 << (bit % 8);
+                bit += 1;
+            }
+            bit += 1;
+        }
+    }
+
+    STANDARD.encode(key)[..14].to_string()
+}
+
+pub fn decode_position_id(
+    id: &str,
+    player_on_roll: PlayerColor,
+) -> Result<BackgammonBoard, String> {
+    let key = decode_key::<{ POSITION_KEY_BITS / 8 }>(id, 14)
+        .ok_or(format!("invalid position ID `{}`", id))?;
+
+    let mut pairs: Vec<(Position, Point)> = Vec::new();
+    let mut bits = (0..POSITION_KEY_BITS).map(|bit| key[bit / 8] >> (bit % 8) & 1 == 1);
+    for player_color in [get_opponent(player_on_roll), player_on_roll] {
+        let point = |checkers| {
+            if player_color == BLACK_PLAYER {
+                b(checkers)
+            } else {
+                r(checkers)
+            }
+        };
+        let positions = (1..=24)
+            .map(|point| norm_pos(point, player_color))
+            .chain([bar_pos(player_color)]);
+
+        let mut total = 0;
+        for pos in positions {
+            let mut checkers = 0;
+            while bits.next() == Some(true) {
+                checkers += 1;
+            }
+            if checkers == 0 {
+                continue;
+            }
+            if pairs.iter().any(|(other, _)| *other == pos) {
+                return Err(format!(
+                    "both players have checkers on the same point in `{}`",
+                    id
+                ));
+            }
+            total += checkers as usize;
+            pairs.push((pos, point(checkers)));
+        }
+        if total > CHECKERS {
+            return Err(format!(
+                "the position ID `{}` has more than 15 checkers",
+                id
+            ));
+        }
+        let off = (CHECKERS - total) as u8;
+        pairs.push((norm_pos(BLACK_OFF, player_color), point(off)));
+    }
+
+    Ok(BackgammonBoard::from_pairs(&pairs))
+}
+
+/// State of the game stored in a Match ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameState {
+    NoGame,
+    Playing,
+    Over,
+    Resigned,
+    /// ended by a refused double
+    Dropped,
+}
+
+/// Everything in a GNU Backgammon Match ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchId {
+    pub cube: Cube,
+    /// player who rolls or rolled the dice
+    pub player_on_roll: PlayerColor,
+    pub crawford: bool,
+    pub game_state: GameState,
+    /// player who makes the next decision, the opponent of the player on roll when a
+    /// double is offered
+    pub turn: PlayerColor,
+    pub double_offered: bool,
+    pub resignation_offered: Option<VictoryType>,
+    /// `None` before rolling
+    pub roll: Option<DiceRoll>,
+    /// 0 in money play
+    pub match_length: u32,
+    /// score of black and red
+    pub score: (u32, u32),
+}
+
+impl MatchId {
+    /// A money game being played, with `player_on_roll` to play `roll`.
+    pub fn money_game(player_on_roll: PlayerColor, roll: Option<DiceRoll>) -> Self {
+        Self {
+            cube: Cube::default(),
+            player_on_roll,
+            crawford: false,
+            game_state: GameState::Playing,
+            turn: player_on_roll,
+            double_offered: false,
+            resignation_offered: None,
+            roll,
+            match_length: 0,
+            score: (0, 0),
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let (die1, die2) = match self.roll {
+            None => (0, 0),
+            Some(DiceRoll::Single(die1, die2)) => (die1, die2),
+            Some(DiceRoll::Double(die)) => (die, die),
+        };
+        let fields = [
+            (self.cube.value.trailing_zeros(), 4),
+            (self.cube.owner.map_or(3, player_index), 2),
+            (player_index(self.player_on_roll), 1),
+            (self.crawford as u32, 1),
+            (
+                match self.game_state {
+                    GameState::NoGame => 0,
+                    GameState::Playing => 1,
+                    GameState::Over => 2,
+                    GameState::Resigned => 3,
+                    GameState::Dropped => 4,
+                },
+                3,
+            ),
+            (player_index(self.turn), 1),
+            (self.double_offered as u32, 1),
+            (
+                self.resignation_offered
+                    .map_or(0, |victory_type| victory_type.value() as u32),
+                2,
+            ),
+            (die1 as u32, 3),
+            (die2 as u32, 3),
+            (self.match_length, 15),
+            (self.score.0, 15),
+            (self.score.1, 15),
+        ];
+
+        let mut key = [0u8; 9];
+        let mut bit = 0;
+        for (value, bits) in fields {
+            for i in 0..bits {
+                if value >> i & 1 == 1 {
+                    key[bit / 8] |= 1 << (bit % 8);
+                }
+                bit += 1;
+            }
+        }
+        assert_eq!(bit, MATCH_KEY_BITS);
+
+        STANDARD.encode(key)
+    }
+
+    pub fn decode(id: &str) -> Result<Self, String> {
+        let key = decode_key::<9>(id, 12).ok_or(format!("invalid match ID `{}`", id))?;
+
+        let mut bit = 0;
+        let mut field = |bits: usize| {
+            let value = (0..bits).fold(0, |value, i| {
+                value | ((key[(bit + i) / 8] >> ((bit + i) % 8) & 1) as u32) << i
+            });
+            bit += bits;
+            value
+        };
+        let invalid = |what: &str| format!("invalid {} in the match ID `{}`", what, id);
+
+        let cube_value = 1 << field(4);
+        let cube_owner = match field(2) {
+            3 => None,
+            2 => return Err(invalid("cube owner")),
+            index => Some(player_color(index)),
+        };
+        let player_on_roll = player_color(field(1));
+        let crawford = field(1) == 1;
+        let game_state = match field(3) {
+            0 => GameState::NoGame,
+            1 => GameState::Playing,
+            2 => GameState::Over,
+            3 => GameState::Resigned,
+            4 => GameState::Dropped,
+            _ => return Err(invalid("game state")),
+        };
+        let turn = player_color(field(1));
+        let double_offered = field(1) == 1;
+        let resignation_offered = match field(2) {
+            0 => None,
+            1 => Some(VictoryType::Game),
+            2 => Some(VictoryType::Gammon),
+            _ => Some(VictoryType::Backgammon),
+        };
+        let roll = match (field(3) as u8, field(3) as u8) {
+            (0, 0) => None,
+            (die1, die2) if (1..=6).contains(&die1) && (1..=6).contains(&die2) => {
+                Some(if die1 == die2 {
+                    DiceRoll::Double(die1)
+                } else {
+                    DiceRoll::Single(die1, die2)
+                })
+            }
+            _ => return Err(invalid("dice")),
+        };
+        let match_length = field(15);
+        let score = (field(15), field(15));
+        // a match is over, and has no position left, once a player gets its length
+        if match_length > 0 && (score.0 >= match_length || score.1 >= match_length) {
+            return Err(invalid("score"));
+        }
+
+        Ok(Self {
+            cube: Cube {
+                value: cube_value,
+                owner: cube_owner,
+            },
+            player_on_roll,
+            crawford,
+            game_state,
+            turn,
+            double_offered,
+            resignation_offered,
+            roll,
+            match_length,
+            score,
+        })
+    }
+}
+
+/// Decodes an ID of `len` base64 characters, without its padding, into `N` bytes.
+fn decode_key<const N: usize>(id: &str, len: usize) -> Option<[u8; N]> {
+    if id.len() != len {
+        return None;
+    }
+    let padding = "=".repeat((4 - len % 4) % 4);
+    STANDARD
+        .decode(format!("{}{}", id, padding))
+        .ok()?
+        .try_into()
+        .ok()
+}
+
+/// Decodes a GNU Backgammon ID, either a Position ID or `PositionID:MatchID`. Without Match
+/// ID the position is read with `player_on_roll` on roll.
+pub fn decode_gnubg_id(
+    id: &str,
+    player_on_roll: PlayerColor,
+) -> Result<(BackgammonBoard, MatchId), String> {
+    let id = id.trim();
+    let match_id = match id.split_once(':') {
+        Some((_, match_id)) => MatchId::decode(match_id)?,
+        None => MatchId::money_game(player_on_roll, None),
+    };
+    let position_id = id.split(':').next().unwrap();
+    let board = decode_position_id(position_id, match_id.player_on_roll)?;
+
+    Ok((board, match_id))
+}
+
+/// Prints the board and the state of the game of a GNU Backgammon ID.
+pub fn print_gnubg_id(id: &str, player_on_roll: PlayerColor) -> Result<(), String> {
+    let (board, match_id) = decode_gnubg_id(id, player_on_roll)?;
+
+    let name = |player_color| {
+        if player_color == BLACK_PLAYER {
+            "Black (X)"
+        } else {
+            "Red (O)"
+        }
+    };
+    BoardPrinter.print_position(&board);
+    println!(
+        "Position ID: {}",
+        encode_position_id(&board, match_id.player_on_roll)
+    );
+    println!("Match ID: {}", match_id.encode());
+    match match_id.roll {
+        Some(roll) => println!(
+            "{} on roll, rolled {}",
+            name(match_id.player_on_roll),
+            format_roll(&roll)
+        ),
+        None => println!("{} on roll", name(match_id.player_on_roll)),
+    }
+    match match_id.cube.owner {
+        Some(owner) => println!("Cube at {}, owned by {}", match_id.cube.value, name(owner)),
+        None => println!("Cube at {}, centered", match_id.cube.value),
+    }
+    if match_id.match_length > 0 {
+        println!(
+            "{} point match, Black {} - Red {}{}",
+            match_id.match_length,
+            match_id.score.0,
+            match_id.score.1,
+            if match_id.crawford { " (Crawford)" } else { "" }
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::positions::{BLACK_BAR, RED_OFF};
+
+    use super::*;
+
+    #[test]
+    fn test_starting_position_id() {
+        let board = BackgammonBoard::default_board();
+
+        assert_eq!(encode_position_id(&board, BLACK_PLAYER), "4HPwATDgc/ABMA");
+        assert_eq!(encode_position_id(&board, RED_PLAYER), "4HPwATDgc/ABMA");
+        assert_eq!(
+            decode_position_id("4HPwATDgc/ABMA", BLACK_PLAYER).unwrap(),
+            board
+        );
+        assert_eq!(
+            decode_position_id("4HPwATDgc/ABMA", RED_PLAYER).unwrap(),
+            board
+        );
+    }
+
+    #[test]
+    fn test_position_id_player_not_on_roll_first() {
+        // the player not on roll played 6/5
+        let board = decode_position_id("0HPwATDgc/ABMA", RED_PLAYER).unwrap();
+        assert_eq!(board.checkers_at(BLACK_PLAYER, 6), 4);
+        assert_eq!(board.checkers_at(BLACK_PLAYER, 5), 1);
+        assert_eq!(board.checkers_at(RED_PLAYER, 19), 5);
+
+        let board = decode_position_id("0HPwATDgc/ABMA", BLACK_PLAYER).unwrap();
+        assert_eq!(board.checkers_at(RED_PLAYER, 19), 4);
+        assert_eq!(board.checkers_at(RED_PLAYER, 20), 1);
+        assert_eq!(board.checkers_at(BLACK_PLAYER, 6), 5);
+    }
+
+    #[test]
+    fn test_position_id_bar_and_off() {
+        let board = decode_position_id("zGbiIQgxH/AAWA", BLACK_PLAYER).unwrap();
+        let expected = BackgammonBoard::from_pairs(&[
+            (BLACK_BAR, b(1)),
+            (1, b(1)),
+            (4, b(2)),
+            (6, b(5)),
+            (13, b(4)),
+            (24, b(2)),
+            (22, r(2)),
+            (20, r(2)),
+            (19, r(2)),
+            (17, r(2)),
+            (15, r(1)),
+            (12, r(4)),
+            (8, r(1)),
+            (3, r(1)),
+        ]);
+        assert_eq!(board, expected);
+
+        let board = decode_position_id("zGbiIYCYD3gALA", BLACK_PLAYER).unwrap();
+        assert_eq!(board.checkers_at(RED_PLAYER, RED_OFF), 1);
+        assert_eq!(board.checkers_at(BLACK_PLAYER, BLACK_BAR), 1);
+    }
+
+    #[test]
+    fn test_position_id_roundtrip() {
+        for id in [
+            "4HPwATDgc/ABMA",
+            "jGfkASjg8wcBMA",
+            "zGbiIQgxH/AAWA",
+            "zGbiIYCYD3gALA",
+            "4DnyATDgc/ABMA",
+        ] {
+            for player_on_roll in [BLACK_PLAYER, RED_PLAYER] {
+                let board = decode_position_id(id, player_on_roll).unwrap();
+                assert_eq!(encode_position_id(&board, player_on_roll), id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_position_id_errors() {
+        assert!(decode_position_id("4HPwATDgc/AB", BLACK_PLAYER).is_err());
+        assert!(decode_position_id("4HPwATDgc/AB!A", BLACK_PLAYER).is_err());
+        // all bits set: too many checkers
+        assert!(decode_position_id("//////////////", BLACK_PLAYER).is_err());
+    }
+
+    #[test]
+    fn test_match_id() {
+        // 9 point match, 2-4, cube at 2 owned by player 0, player 1 rolled 52
+        let match_id = MatchId::decode("QYkqASAAIAAA").unwrap();
+        assert_eq!(
+            match_id,
+            MatchId {
+                cube: Cube {
+                    value: 2,
+                    owner: Some(BLACK_PLAYER),
+                },
+                player_on_roll: RED_PLAYER,
+                crawford: false,
+                game_state: GameState::Playing,
+                turn: RED_PLAYER,
+                double_offered: false,
+                resignation_offered: None,
+                roll: Some(DiceRoll::Single(5, 2)),
+                match_length: 9,
+                score: (2, 4),
+            }
+        );
+        assert_eq!(match_id.encode(), "QYkqASAAIAAA");
+
+        let money_game = MatchId::money_game(RED_PLAYER, None);
+        assert_eq!(MatchId::decode("cAkAAAAAAAAA").unwrap(), money_game);
+        assert_eq!(money_game.encode(), "cAkAAAAAAAAA");
+
+        assert!(MatchId::decode("cAkAAAAAAAA").is_err());
+
+        // 1 point match at 14-3
+        let over = MatchId {
+            match_length: 1,
+            score: (14, 3),
+            ..money_game
+        };
+        assert!(MatchId::decode(&over.encode()).is_err());
+        let over = MatchId {
+            match_length: 9,
+            score: (2, 9),
+            ..money_game
+        };
+        assert!(MatchId::decode(&over.encode()).is_err());
+    }
+
+    #[test]
+    fn test_decode_gnubg_id() {
+        let (board, match_id) =
+            decode_gnubg_id("4HPwATDgc/ABMA:QYkqASAAIAAA", BLACK_PLAYER).unwrap();
+        assert_eq!(board, BackgammonBoard::default_board());
+        assert_eq!(match_id.player_on_roll, RED_PLAYER);
+
+        let (_, match_id) = decode_gnubg_id("4HPwATDgc/ABMA", BLACK_PLAYER).unwrap();
+        assert_eq!(match_id, MatchId::money_game(BLACK_PLAYER, None));
+    }
+}
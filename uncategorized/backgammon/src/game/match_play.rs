@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use crate::{
+    agents::agent::TestingAgent,
+    core::{
+        cube::match_equity,
+        players::{PlayerColor, BLACK_PLAYER, RED_PLAYER},
+        rules::Variant,
+    },
+};
+
+use super::{
+    game::{play_game_with_options, GameOptions, GameResult},
+    rng::GameRng,
+};
+
+pub struct MatchResult {
+    pub match_length: u32,
+    pub winner: PlayerColor,
+    /// final score of black and red
+    pub score: (u32, u32),
+    pub games: Vec<GameResult>,
+}
+
+/// Plays games with the doubling cube until a player gets `match_length` points.
+///
+/// The game right after a player gets to one point from winning the match is played
+/// without cube (Crawford rule); every game gets its own `GameRng` drawn from `rng`.
+pub fn play_match(
+    agents: &HashMap<PlayerColor, &dyn TestingAgent>,
+    match_length: u32,
+    variant: Variant,
+    rng: &mut GameRng,
+    print: bool,
+) -> MatchResult {
+    assert!(
+        match_length > 0,
+        "the match must be played to at least 1 point"
+    );
+
+    let (mut black_score, mut red_score) = (0, 0);
+    let mut crawford_played = false;
+    let mut games = Vec::new();
+
+    loop {
+        let away = (match_length - black_score, match_length - red_score);
+        let crawford = !crawford_played && (away.0 == 1 || away.1 == 1);
+        crawford_played |= crawford;
+
+        let options = GameOptions {
+            cube: !crawford,
+            away: Some(away),
+            variant,
+        };
+        let game_result = play_game_with_options(agents, &mut rng.next_game_rng(), options, false);
+
+        let points = game_result.points() as u32;
+        if game_result.winner == BLACK_PLAYER {
+            black_score = (black_score + points).min(match_length);
+        } else {
+            red_score = (red_score + points).min(match_length);
+        }
+
+        if print {
+            println!(
+                "Game {}{}: {} wins {} point{} | Black {} - Red {} | Black match equity {:.1}%",
+                games.len() + 1,
+                if crawford { " (Crawford)" } else { "" },
+                if game_result.winner == RED_PLAYER {
+                    "Red"
+                } else {
+                    "Black"
+                },
+                points,
+                if points > 1 { "s" } else { "" },
+                black_score,
+                red_score,
+                match_equity(match_length - black_score, match_length - red_score) * 100.0
+            );
+        }
+
+        games.push(game_result);
+
+        if black_score == match_length || red_score == match_length {
+            return MatchResult {
+                match_length,
+                winner: if black_score == match_length {
+                    BLACK_PLAYER
+                } else {
+                    RED_PLAYER
+                },
+                score: (black_score, red_score),
+                games,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::agents::{logic::LogicAgent, random::RandomAgent};
+
+    use super::*;
+
+    #[test]
+    fn test_play_match() {
+        let black_agent: Box<dyn TestingAgent> = Box::new(LogicAgent::new_hard());
+        let red_agent: Box<dyn TestingAgent> = Box::new(RandomAgent::new());
+        let agents = HashMap::from([
+            (BLACK_PLAYER, black_agent.as_ref()),
+            (RED_PLAYER, red_agent.as_ref()),
+        ]);
+
+        let result = play_match(
+            &agents,
+            5,
+            Variant::Standard,
+            &mut GameRng::from_seed(11),
+            false,
+        );
+
+        let (black_score, red_score) = result.score;
+        assert!(black_score == 5 || red_score == 5);
+        assert_eq!(result.winner == BLACK_PLAYER, black_score == 5);
+
+        let total = result.games.iter().map(|g| g.points() as u32).sum::<u32>();
+        assert!(total >= black_score + red_score);
+
+        // the game after the first player gets to 4 points is played without cube
+        let mut score = (0, 0);
+        let mut crawford_seen = false;
+        for game in &result.games {
+            if !crawford_seen && (score.0 == 4 || score.1 == 4) {
+                crawford_seen = true;
+                assert_eq!(game.cube.value, 1);
+                assert!(!game.dropped);
+            }
+            if game.winner == BLACK_PLAYER {
+                score.0 += game.points() as u32;
+            } else {
+                score.1 += game.points() as u32;
+            }
+        }
+    }
+}
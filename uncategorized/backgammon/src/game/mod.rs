@@ -1,2 +1,10 @@
+pub mod analysis;
 pub mod board_printer;
 pub mod game;
+pub mod gnubg_id;
+pub mod match_play;
+pub(crate) mod notation;
+pub mod record;
+pub mod replay;
+pub mod rng;
+pub mod tournament;
@@ -0,0 +1,229 @@
+use crate::core::{
+    board::BackgammonBoard,
+    dice::{DiceRoll, Die},
+    moves::{MoveSequence, MoveWithDie},
+    players::{get_opponent, PlayerColor},
+    positions::{bar_pos, norm_pos, Position},
+};
+
+const BAR_POINT: Position = 25;
+const OFF_POINT: Position = 0;
+
+/// Point number of `pos` from the point of view of `player_color`: the bar is 25,
+/// the home board is 1 to 6 and the checkers borne off are at 0.
+pub(crate) fn point_number(pos: Position, player_color: PlayerColor) -> Position {
+    if pos == bar_pos(player_color) {
+        BAR_POINT
+    } else {
+        norm_pos(pos, player_color)
+    }
+}
+
+/// Inverse of `point_number`.
+pub(crate) fn point_position(point: Position, player_color: PlayerColor) -> Position {
+    if point == BAR_POINT {
+        bar_pos(player_color)
+    } else {
+        norm_pos(point, player_color)
+    }
+}
+
+fn format_point(point: Position) -> String {
+    match point {
+        BAR_POINT => "bar".to_string(),
+        OFF_POINT => "off".to_string(),
+        point => point.to_string(),
+    }
+}
+
+pub(crate) fn format_roll(roll: &DiceRoll) -> String {
+    match roll {
+        DiceRoll::Single(_, _) => {
+            let (high_die, low_die) = roll.get_high_low_die();
+            format!("{high_die}{low_die}")
+        }
+        DiceRoll::Double(die) => format!("{die}{die}"),
+    }
+}
+
+pub(crate) fn format_move(m: &MoveWithDie, player_color: PlayerColor, hit: bool) -> String {
+    format!(
+        "{}/{}{}",
+        format_point(point_number(m.src, player_color)),
+        format_point(point_number(m.dst, player_color)),
+        if hit { "*" } else { "" }
+    )
+}
+
+/// Formats the moves in standard notation (e.g. "8/5 6/5", "bar/22* 13/11"), marking
+/// the hits according to the position on `board` before the sequence is played.
+pub(crate) fn format_move_sequence(
+    board: &BackgammonBoard,
+    move_sequence: &MoveSequence,
+    player_color: PlayerColor,
+) -> String {
+    let mut board = board.clone();
+
+    move_sequence
+        .iter()
+        .map(|m| {
+            let hit = board.checkers_at(get_opponent(player_color), m.dst) == 1;
+            board = board.apply_n_moves(m, player_color, 1);
+            format_move(m, player_color, hit)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_point(text: &str) -> Result<Position, String> {
+    match text.trim_end_matches('*') {
+        "bar" => Ok(BAR_POINT),
+        "off" => Ok(OFF_POINT),
+        point => match point.parse::<Position>() {
+            Ok(point) if (1..=24).contains(&point) => Ok(point),
+            _ => Err(format!("invalid point '{text}'")),
+        },
+    }
+}
+
+/// Parses moves in standard notation (e.g. "8/5 6/5", "bar/22*", "13/7/3", "6/2(2)") into
+/// the individual checker moves of `player_color`, in the order they are written.
+///
+/// The dice of the returned moves are the distance between the points, no legality is checked.
+pub(crate) fn parse_moves(
+    text: &str,
+    player_color: PlayerColor,
+) -> Result<Vec<MoveWithDie>, String> {
+    let mut moves = Vec::new();
+
+    for token in text.split_whitespace() {
+        let (path, times) = match token.strip_suffix(')').and_then(|t| t.split_once('(')) {
+            Some((path, times)) => (
+                path,
+                times
+                    .parse::<usize>()
+                    .map_err(|_| format!("invalid repetition in '{token}'"))?,
+            ),
+            None => (token, 1),
+        };
+
+        let points = path
+            .split('/')
+            .map(parse_point)
+            .collect::<Result<Vec<_>, _>>()?;
+        if points.len() < 2 {
+            return Err(format!("'{token}' must be written as <from>/<to>"));
+        }
+
+        for _ in 0..times {
+            for pair in points.windows(2) {
+                let (src, dst) = (pair[0], pair[1]);
+                if src <= dst {
+                    return Err(format!("'{token}' moves backwards"));
+                }
+                moves.push(MoveWithDie {
+                    src: point_position(src, player_color),
+                    dst: point_position(dst, player_color),
+                    die: (src - dst) as Die,
+                });
+            }
+        }
+    }
+
+    if moves.is_empty() {
+        return Err("no moves given".to_string());
+    }
+
+    Ok(moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::players::{b, r, BLACK_PLAYER, RED_PLAYER};
+
+    use super::*;
+
+    #[test]
+    fn test_format_roll() {
+        assert_eq!(format_roll(&DiceRoll::Single(1, 3)), "31");
+        assert_eq!(format_roll(&DiceRoll::Single(5, 2)), "52");
+        assert_eq!(format_roll(&DiceRoll::Double(6)), "66");
+    }
+
+    #[test]
+    fn test_format_move_sequence() {
+        let board = BackgammonBoard::default_board();
+
+        let black_31 = MoveSequence::TwoMoves(
+            MoveWithDie::with_die(BLACK_PLAYER, 8, 3),
+            MoveWithDie::with_die(BLACK_PLAYER, 6, 1),
+        );
+        assert_eq!(
+            format_move_sequence(&board, &black_31, BLACK_PLAYER),
+            "8/5 6/5"
+        );
+
+        let red_31 = MoveSequence::TwoMoves(
+            MoveWithDie::with_die(RED_PLAYER, 17, 3),
+            MoveWithDie::with_die(RED_PLAYER, 19, 1),
+        );
+        assert_eq!(format_move_sequence(&board, &red_31, RED_PLAYER), "8/5 6/5");
+    }
+
+    #[test]
+    fn test_format_move_sequence_bar_hit_and_off() {
+        let board = BackgammonBoard::from_pairs(&[(26, b(1)), (22, r(1)), (3, b(2))]);
+        let moves = MoveSequence::TwoMoves(
+            MoveWithDie::from_bar_with_die(BLACK_PLAYER, 3),
+            MoveWithDie::with_die(BLACK_PLAYER, 3, 3),
+        );
+
+        assert_eq!(
+            format_move_sequence(&board, &moves, BLACK_PLAYER),
+            "bar/22* 3/off"
+        );
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        let moves = parse_moves("8/5 6/5", BLACK_PLAYER).unwrap();
+        assert_eq!(
+            moves,
+            vec![
+                MoveWithDie::with_die(BLACK_PLAYER, 8, 3),
+                MoveWithDie::with_die(BLACK_PLAYER, 6, 1)
+            ]
+        );
+
+        let moves = parse_moves("8/5 6/5", RED_PLAYER).unwrap();
+        assert_eq!(
+            moves,
+            vec![
+                MoveWithDie::with_die(RED_PLAYER, 17, 3),
+                MoveWithDie::with_die(RED_PLAYER, 19, 1)
+            ]
+        );
+
+        let moves = parse_moves("bar/22* 13/9/5 6/2(2) 3/off", BLACK_PLAYER).unwrap();
+        assert_eq!(
+            moves,
+            vec![
+                MoveWithDie::from_bar_with_die(BLACK_PLAYER, 3),
+                MoveWithDie::with_die(BLACK_PLAYER, 13, 4),
+                MoveWithDie::with_die(BLACK_PLAYER, 9, 4),
+                MoveWithDie::with_die(BLACK_PLAYER, 6, 4),
+                MoveWithDie::with_die(BLACK_PLAYER, 6, 4),
+                MoveWithDie::with_die(BLACK_PLAYER, 3, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_moves_errors() {
+        assert!(parse_moves("", BLACK_PLAYER).is_err());
+        assert!(parse_moves("8", BLACK_PLAYER).is_err());
+        assert!(parse_moves("5/8", BLACK_PLAYER).is_err());
+        assert!(parse_moves("26/20", BLACK_PLAYER).is_err());
+        assert!(parse_moves("8/5(x)", BLACK_PLAYER).is_err());
+    }
+}
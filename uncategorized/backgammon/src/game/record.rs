@@ -0,0 +1,302 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    board::BackgammonBoard,
+    cube::Cube,
+    dice::DiceRoll,
+    moves::MoveSequence,
+    players::{get_opponent, PlayerColor, BLACK_PLAYER},
+};
+
+use super::{
+    game::VictoryType,
+    notation::{format_move_sequence, format_roll},
+};
+
+const NOTATION_COLUMN_WIDTH: usize = 28;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnRecord {
+    pub player_color: PlayerColor,
+    /// the player doubled before rolling and the opponent took
+    #[serde(default)]
+    pub doubled: bool,
+    pub roll: DiceRoll,
+    /// `None` when the player could not move
+    pub move_sequence: Option<MoveSequence>,
+}
+
+/// Everything needed to reconstruct a game: the initial position, who started and
+/// every roll and move that followed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub seed: u64,
+    pub initial_board: BackgammonBoard,
+    pub first_player: PlayerColor,
+    pub turns: Vec<TurnRecord>,
+    pub winner: Option<PlayerColor>,
+    pub victory_type: Option<VictoryType>,
+    /// the cube at the end of the game
+    #[serde(default)]
+    pub cube: Cube,
+    /// the game ended with the loser refusing a double
+    #[serde(default)]
+    pub dropped: bool,
+    /// the game ended with the loser resigning
+    #[serde(default)]
+    pub resigned: bool,
+}
+
+impl GameRecord {
+    pub(crate) fn new(
+        seed: u64,
+        initial_board: BackgammonBoard,
+        first_player: PlayerColor,
+    ) -> Self {
+        Self {
+            seed,
+            initial_board,
+            first_player,
+            turns: Vec::new(),
+            winner: None,
+            victory_type: None,
+            cube: Cube::default(),
+            dropped: false,
+            resigned: false,
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    pub fn rolls(&self) -> Vec<DiceRoll> {
+        self.turns.iter().map(|turn| turn.roll).collect()
+    }
+
+    /// The position before each turn, followed by the final position.
+    pub(crate) fn positions(&self) -> Vec<BackgammonBoard> {
+        let mut board = self.initial_board.clone();
+        let mut positions = vec![board.clone()];
+
+        for turn in &self.turns {
+            if let Some(move_sequence) = &turn.move_sequence {
+                board = board.apply_move_sequence(move_sequence, turn.player_color);
+            }
+            positions.push(board.clone());
+        }
+
+        positions
+    }
+
+    /// Exports the game in the text format of the usual match files (`.mat`), black
+    /// on the left column and red on the right one.
+    pub fn to_match_notation(&self) -> String {
+        let mut lines = vec![
+            " 0 point match".to_string(),
+            String::new(),
+            " Game 1".to_string(),
+            format!(
+                " {:<width$}{}",
+                "Black : 0",
+                "Red : 0",
+                width = NOTATION_COLUMN_WIDTH + 4
+            ),
+        ];
+
+        // every action of the game with the player doing it
+        let mut entries = Vec::new();
+        let mut cube_value = 1;
+        for (turn, board) in self.turns.iter().zip(self.positions().iter()) {
+            if turn.doubled {
+                cube_value *= 2;
+                entries.push((turn.player_color, format!("Doubles => {cube_value}")));
+                entries.push((get_opponent(turn.player_color), "Takes".to_string()));
+            }
+
+            let moves = turn
+                .move_sequence
+                .as_ref()
+                .map(|ms| format_move_sequence(board, ms, turn.player_color))
+                .unwrap_or_default();
+            entries.push((
+                turn.player_color,
+                format!("{}: {}", format_roll(&turn.roll), moves)
+                    .trim_end()
+                    .to_string(),
+            ));
+        }
+        if let (true, Some(winner)) = (self.dropped, self.winner) {
+            entries.push((winner, format!("Doubles => {}", cube_value * 2)));
+            entries.push((get_opponent(winner), "Drops".to_string()));
+        }
+
+        // black on the left column and red on the right one
+        let mut row: Vec<String> = Vec::new();
+        for (player_color, entry) in entries {
+            if player_color == BLACK_PLAYER {
+                if !row.is_empty() {
+                    lines.push(notation_row(lines.len() - 3, &row));
+                    row.clear();
+                }
+                row.push(entry);
+            } else {
+                if row.is_empty() {
+                    row.push(String::new());
+                }
+                row.push(entry);
+                lines.push(notation_row(lines.len() - 3, &row));
+                row.clear();
+            }
+        }
+        if !row.is_empty() {
+            lines.push(notation_row(lines.len() - 3, &row));
+        }
+
+        if let (Some(winner), Some(victory_type)) = (self.winner, self.victory_type) {
+            let points = victory_type.value() * self.cube.value as i32;
+            let indent = if winner == BLACK_PLAYER {
+                5
+            } else {
+                NOTATION_COLUMN_WIDTH + 5
+            };
+            lines.push(format!(
+                "{:indent$}Wins {} point{}",
+                "",
+                points,
+                if points > 1 { "s" } else { "" }
+            ));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+fn notation_row(number: usize, row: &[String]) -> String {
+    format!(
+        "{:>3}) {:<width$}{}",
+        number,
+        row[0],
+        row.get(1).map(String::as_str).unwrap_or_default(),
+        width = NOTATION_COLUMN_WIDTH
+    )
+    .trim_end()
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{moves::MoveWithDie, players::RED_PLAYER};
+
+    use super::*;
+
+    fn opening_record() -> GameRecord {
+        let mut record = GameRecord::new(7, BackgammonBoard::default_board(), RED_PLAYER);
+        record.turns.push(TurnRecord {
+            player_color: RED_PLAYER,
+            doubled: false,
+            roll: DiceRoll::Single(1, 3),
+            move_sequence: Some(MoveSequence::TwoMoves(
+                MoveWithDie::with_die(RED_PLAYER, 17, 3),
+                MoveWithDie::with_die(RED_PLAYER, 19, 1),
+            )),
+        });
+        record.turns.push(TurnRecord {
+            player_color: BLACK_PLAYER,
+            doubled: false,
+            roll: DiceRoll::Single(6, 4),
+            move_sequence: Some(MoveSequence::TwoMoves(
+                MoveWithDie::with_die(BLACK_PLAYER, 24, 6),
+                MoveWithDie::with_die(BLACK_PLAYER, 18, 4),
+            )),
+        });
+        record.winner = Some(RED_PLAYER);
+        record.victory_type = Some(VictoryType::Gammon);
+        record
+    }
+
+    #[test]
+    fn test_game_record_positions() {
+        let record = opening_record();
+        let positions = record.positions();
+
+        assert_eq!(positions.len(), 3);
+        assert_eq!(positions[1].checkers_at(RED_PLAYER, 20), 2);
+        assert_eq!(positions[2].checkers_at(BLACK_PLAYER, 14), 1);
+        assert_eq!(positions[2].checkers_at(RED_PLAYER, 14), 0);
+    }
+
+    #[test]
+    fn test_game_record_save_load() {
+        let record = opening_record();
+        let path = std::env::temp_dir().join("backgammon_test_game_record_save_load.json");
+
+        record.save(&path).unwrap();
+        let loaded = GameRecord::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.seed, record.seed);
+        assert_eq!(loaded.first_player, record.first_player);
+        assert_eq!(loaded.rolls(), record.rolls());
+        assert_eq!(loaded.victory_type, record.victory_type);
+        assert_eq!(loaded.to_match_notation(), record.to_match_notation());
+    }
+
+    #[test]
+    fn test_game_record_to_match_notation() {
+        let expected = [
+            " 0 point match",
+            "",
+            " Game 1",
+            " Black : 0                       Red : 0",
+            "  1)                             31: 8/5 6/5",
+            "  2) 64: 24/18 18/14",
+            "                                 Wins 2 points",
+        ];
+
+        assert_eq!(
+            opening_record().to_match_notation(),
+            expected.join("\n") + "\n"
+        );
+    }
+
+    #[test]
+    fn test_game_record_to_match_notation_with_cube() {
+        let mut record = opening_record();
+        record.turns[1].doubled = true;
+        record.winner = Some(RED_PLAYER);
+        record.victory_type = Some(VictoryType::Game);
+        record.cube = Cube {
+            value: 2,
+            owner: Some(RED_PLAYER),
+        };
+        record.dropped = true;
+
+        let expected = [
+            " 0 point match",
+            "",
+            " Game 1",
+            " Black : 0                       Red : 0",
+            "  1)                             31: 8/5 6/5",
+            "  2) Doubles => 2                Takes",
+            "  3) 64: 24/18 18/14             Doubles => 4",
+            "  4) Drops",
+            "                                 Wins 2 points",
+        ];
+
+        assert_eq!(record.to_match_notation(), expected.join("\n") + "\n");
+    }
+}
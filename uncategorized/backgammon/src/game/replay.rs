@@ -0,0 +1,108 @@
+use std::io::{self, BufRead, Write};
+
+use crate::{api, core::board::BackgammonBoard};
+
+use super::{board_printer::BoardPrinter, notation::format_move_sequence, record::GameRecord};
+
+/// Steps through a recorded game on the terminal, one turn at a time, forward and backward.
+pub fn replay(record: &GameRecord) -> io::Result<()> {
+    let positions = record.positions();
+    let printer = BoardPrinter;
+
+    let stdin = io::stdin();
+    let mut step = 0;
+    loop {
+        print_step(&printer, record, &positions, step);
+
+        print!("[n]ext (default) | [p]revious | [f]irst | [l]ast | [q]uit > ");
+        io::stdout().flush()?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+
+        match line.trim() {
+            "" | "n" => step = (step + 1).min(record.turns.len()),
+            "p" => step = step.saturating_sub(1),
+            "f" => step = 0,
+            "l" => step = record.turns.len(),
+            "q" => return Ok(()),
+            _ => {}
+        }
+    }
+}
+
+fn print_step(
+    printer: &BoardPrinter,
+    record: &GameRecord,
+    positions: &[BackgammonBoard],
+    step: usize,
+) {
+    let board = &positions[step];
+
+    if let Some(turn) = record.turns.get(step) {
+        printer.print_board(board, turn.player_color, &turn.roll, None);
+        println!("Turn {} of {}", step + 1, record.turns.len());
+        match &turn.move_sequence {
+            Some(move_sequence) => println!(
+                "Plays: {}",
+                format_move_sequence(board, move_sequence, turn.player_color)
+            ),
+            None => println!("Plays: no legal move"),
+        }
+    } else if let Some(turn) = record.turns.last() {
+        printer.print_board(board, turn.player_color, &turn.roll, None);
+        println!("Final position");
+        if let Some(line) = winner_line(record) {
+            println!("{line}");
+        }
+    }
+}
+
+fn winner_line(record: &GameRecord) -> Option<String> {
+    let (winner, victory_type) = record.winner.zip(record.victory_type)?;
+    Some(format!(
+        "Winner: {} ({:?})",
+        api::PlayerColor::from_raw(winner),
+        victory_type
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        agents::{agent::TestingAgent, random::RandomAgent},
+        core::players::{BLACK_PLAYER, RED_PLAYER},
+        game::{game::play_game, rng::GameRng},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_winner_line() {
+        let black_agent = RandomAgent::new();
+        let red_agent = RandomAgent::new();
+        let agents = HashMap::from([
+            (BLACK_PLAYER, &black_agent as &dyn TestingAgent),
+            (RED_PLAYER, &red_agent as &dyn TestingAgent),
+        ]);
+        let result = play_game(&agents, &mut GameRng::from_seed(7), false);
+
+        let winner = if result.winner == BLACK_PLAYER {
+            "Black"
+        } else {
+            "Red"
+        };
+        assert_eq!(
+            winner_line(&result.record),
+            Some(format!("Winner: {winner} ({:?})", result.victory_type))
+        );
+
+        let mut unfinished = result.record;
+        unfinished.winner = None;
+        assert_eq!(winner_line(&unfinished), None);
+    }
+}
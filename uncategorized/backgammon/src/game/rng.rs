@@ -0,0 +1,151 @@
+use std::collections::VecDeque;
+
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::core::{
+    dice::DiceRoll,
+    players::{get_random_player, PlayerColor},
+    rules::OpeningRoll,
+};
+
+const DICE_STREAM: u64 = 0;
+const AGENTS_STREAM: u64 = 1;
+const HINTS_STREAM: u64 = 2;
+
+/// Source of randomness for a single game.
+///
+/// The dice (including who starts), the agents and the hints shown to a person draw from
+/// separate streams of the same seed, so swapping an agent or asking for a hint does not
+/// change the rest of the game. Recorded rolls, when given, are used before any new roll
+/// is drawn from the dice stream.
+pub struct GameRng {
+    seed: u64,
+    dice: ChaCha8Rng,
+    agents: ChaCha8Rng,
+    hints: ChaCha8Rng,
+    recorded_rolls: VecDeque<DiceRoll>,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        let mut dice = ChaCha8Rng::seed_from_u64(seed);
+        dice.set_stream(DICE_STREAM);
+        let mut agents = ChaCha8Rng::seed_from_u64(seed);
+        agents.set_stream(AGENTS_STREAM);
+        let mut hints = ChaCha8Rng::seed_from_u64(seed);
+        hints.set_stream(HINTS_STREAM);
+
+        Self {
+            seed,
+            dice,
+            agents,
+            hints,
+            recorded_rolls: VecDeque::new(),
+        }
+    }
+
+    pub fn from_entropy() -> Self {
+        Self::from_seed(rand::random())
+    }
+
+    /// Replays a game: the rolls are taken from `rolls` in order and, once exhausted,
+    /// drawn from the dice stream of `seed` as usual.
+    pub fn with_recorded_rolls(seed: u64, rolls: Vec<DiceRoll>) -> Self {
+        let mut rng = Self::from_seed(seed);
+        rng.recorded_rolls = rolls.into();
+        rng
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub(crate) fn first_player(&mut self) -> PlayerColor {
+        get_random_player(&mut self.dice)
+    }
+
+    pub(crate) fn roll_dice(&mut self) -> DiceRoll {
+        self.recorded_rolls
+            .pop_front()
+            .unwrap_or_else(|| DiceRoll::get_dice_roll(&mut self.dice))
+    }
+
+    /// The roll of the first turn, drawn again on doubles when the higher die of each
+    /// player decides who starts.
+    pub(crate) fn opening_roll(&mut self, opening_roll: OpeningRoll) -> DiceRoll {
+        if let Some(roll) = self.recorded_rolls.pop_front() {
+            return roll;
+        }
+        loop {
+            let roll = DiceRoll::get_dice_roll(&mut self.dice);
+            if opening_roll == OpeningRoll::Reroll || matches!(roll, DiceRoll::Single(_, _)) {
+                return roll;
+            }
+        }
+    }
+
+    pub(crate) fn agent_rng(&mut self) -> &mut ChaCha8Rng {
+        &mut self.agents
+    }
+
+    /// The rng of the agents giving hints to a person, which never touch the dice or the
+    /// agents playing the game.
+    pub fn hint_rng(&mut self) -> &mut ChaCha8Rng {
+        &mut self.hints
+    }
+
+    /// A new rng for the next game of a series, drawn from this one's dice stream.
+    pub fn next_game_rng(&mut self) -> GameRng {
+        GameRng::from_seed(self.dice.next_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_rng_same_seed_same_rolls() {
+        let mut rng1 = GameRng::from_seed(42);
+        let mut rng2 = GameRng::from_seed(42);
+
+        assert_eq!(rng1.first_player(), rng2.first_player());
+        for _ in 0..100 {
+            assert_eq!(rng1.roll_dice(), rng2.roll_dice());
+        }
+    }
+
+    #[test]
+    fn test_game_rng_recorded_rolls() {
+        let mut rng =
+            GameRng::with_recorded_rolls(42, vec![DiceRoll::Single(3, 1), DiceRoll::Double(6)]);
+        let mut fresh = GameRng::from_seed(42);
+
+        assert_eq!(rng.roll_dice(), DiceRoll::Single(3, 1));
+        assert_eq!(rng.roll_dice(), DiceRoll::Double(6));
+        assert_eq!(rng.roll_dice(), fresh.roll_dice());
+    }
+
+    #[test]
+    fn test_game_rng_opening_roll() {
+        for seed in 0..50 {
+            let mut rng = GameRng::from_seed(seed);
+            assert!(matches!(
+                rng.opening_roll(OpeningRoll::HigherDie),
+                DiceRoll::Single(_, _)
+            ));
+        }
+        // some of the rerolls are doubles
+        assert!((0..50).any(|seed| matches!(
+            GameRng::from_seed(seed).opening_roll(OpeningRoll::Reroll),
+            DiceRoll::Double(_)
+        )));
+
+        let mut rng = GameRng::with_recorded_rolls(42, vec![DiceRoll::Double(6)]);
+        assert_eq!(
+            rng.opening_roll(OpeningRoll::HigherDie),
+            DiceRoll::Double(6)
+        );
+    }
+}
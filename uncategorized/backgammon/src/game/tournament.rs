@@ -0,0 +1,351 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use serde::Serialize;
+
+use crate::core::{players::BLACK_PLAYER, rules::Variant};
+
+use super::{
+    game::{play_games, AgentFactory, GameOptions, GameResult},
+    rng::GameRng,
+};
+
+const INITIAL_ELO: f64 = 1500.0;
+
+/// An agent taking part in a tournament.
+pub struct Entrant {
+    pub name: String,
+    pub factory: Box<AgentFactory>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TournamentConfig {
+    /// games of each pairing, half with each agent playing black
+    pub games: usize,
+    pub seed: u64,
+    pub threads: usize,
+    /// change of the Elo ratings after each game
+    pub k_factor: f64,
+    pub variant: Variant,
+}
+
+impl Default for TournamentConfig {
+    fn default() -> Self {
+        Self {
+            games: 100,
+            seed: 0,
+            threads: 1,
+            k_factor: 16.0,
+            variant: Variant::Standard,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Standing {
+    pub agent: String,
+    pub games: usize,
+    pub wins: usize,
+    /// points won minus points lost
+    pub points: i32,
+    pub elo: f64,
+}
+
+impl Standing {
+    pub fn win_rate(&self) -> f64 {
+        self.wins as f64 / self.games.max(1) as f64
+    }
+
+    pub fn points_per_game(&self) -> f64 {
+        self.points as f64 / self.games.max(1) as f64
+    }
+}
+
+/// Games between two agents, from the point of view of the first one.
+#[derive(Debug, Clone, Serialize)]
+pub struct Pairing {
+    pub agent: String,
+    pub opponent: String,
+    pub games: usize,
+    pub wins: usize,
+    pub points: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TournamentReport {
+    pub games_per_pairing: usize,
+    pub seed: u64,
+    /// from the best Elo rating to the worst
+    pub standings: Vec<Standing>,
+    pub pairings: Vec<Pairing>,
+}
+
+/// Plays a round robin between `entrants`: each pairing plays `config.games` games, the
+/// games of each seed once with each agent playing black, on the parallel game runner.
+///
+/// The Elo ratings are updated after every game, in the order of the pairings and then of
+/// the games, so a tournament is reproduced by its seed.
+pub fn run_tournament(entrants: &[Entrant], config: &TournamentConfig) -> TournamentReport {
+    let mut standings = entrants
+        .iter()
+        .map(|entrant| Standing {
+            agent: entrant.name.clone(),
+            games: 0,
+            wins: 0,
+            points: 0,
+            elo: INITIAL_ELO,
+        })
+        .collect::<Vec<_>>();
+    let mut pairings = Vec::new();
+
+    let options = GameOptions {
+        variant: config.variant,
+        ..Default::default()
+    };
+    let mut series = GameRng::from_seed(config.seed);
+    for i in 0..entrants.len() {
+        for j in i + 1..entrants.len() {
+            let seeds = (0..config.games.div_ceil(2))
+                .map(|_| series.next_game_rng().seed())
+                .collect::<Vec<_>>();
+            let rngs = || seeds.iter().map(|&seed| GameRng::from_seed(seed)).collect();
+
+            let (first, second) = (&entrants[i], &entrants[j]);
+            let first_black = play_games(
+                first.factory.as_ref(),
+                second.factory.as_ref(),
+                rngs(),
+                options,
+                config.threads,
+            );
+            let second_black = play_games(
+                second.factory.as_ref(),
+                first.factory.as_ref(),
+                rngs(),
+                options,
+                config.threads,
+            );
+
+            let mut pairing = Pairing {
+                agent: first.name.clone(),
+                opponent: second.name.clone(),
+                games: 0,
+                wins: 0,
+                points: 0,
+            };
+            let games = first_black
+                .iter()
+                .map(|result| (result, true))
+                .chain(second_black.iter().map(|result| (result, false)));
+            for (result, first_is_black) in games {
+                let first_wins = (result.winner == BLACK_PLAYER) == first_is_black;
+                let points = signed_points(result, first_wins);
+
+                pairing.games += 1;
+                pairing.wins += first_wins as usize;
+                pairing.points += points;
+                record_game(&mut standings, i, j, first_wins, points, config.k_factor);
+            }
+            pairings.push(pairing);
+        }
+    }
+
+    standings.sort_by(|a, b| b.elo.total_cmp(&a.elo));
+    TournamentReport {
+        games_per_pairing: config.games.div_ceil(2) * 2,
+        seed: config.seed,
+        standings,
+        pairings,
+    }
+}
+
+fn signed_points(result: &GameResult, won: bool) -> i32 {
+    if won {
+        result.points()
+    } else {
+        -result.points()
+    }
+}
+
+/// Updates the standings of `i` and `j` with a game, `points` being the points of `i`.
+fn record_game(
+    standings: &mut [Standing],
+    i: usize,
+    j: usize,
+    i_wins: bool,
+    points: i32,
+    k_factor: f64,
+) {
+    let expected = 1.0 / (1.0 + 10f64.powf((standings[j].elo - standings[i].elo) / 400.0));
+    let change = k_factor * (if i_wins { 1.0 } else { 0.0 } - expected);
+
+    for (k, sign) in [(i, 1), (j, -1)] {
+        let standing = &mut standings[k];
+        standing.games += 1;
+        standing.wins += ((sign == 1) == i_wins) as usize;
+        standing.points += sign * points;
+        standing.elo += sign as f64 * change;
+    }
+}
+
+impl TournamentReport {
+    /// Writes the standings as CSV, one row per agent from the first.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(
+            writer,
+            "rank,agent,games,wins,win_rate,points,points_per_game,elo"
+        )?;
+        for (rank, standing) in self.standings.iter().enumerate() {
+            writeln!(
+                writer,
+                "{},{},{},{},{:.4},{},{:.4},{:.1}",
+                rank + 1,
+                csv_field(&standing.agent),
+                standing.games,
+                standing.wins,
+                standing.win_rate(),
+                standing.points,
+                standing.points_per_game(),
+                standing.elo
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+}
+
+/// Quotes the fields with commas or quotes.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Leaderboard of the tournament.
+impl fmt::Display for TournamentReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .standings
+            .iter()
+            .map(|standing| standing.agent.len())
+            .max()
+            .unwrap_or(0)
+            .max(5);
+
+        writeln!(
+            f,
+            "{:>4}  {:<width$}  {:>6}  {:>6}  {:>7}  {:>7}",
+            "rank", "agent", "games", "wins", "ppg", "elo"
+        )?;
+        for (rank, standing) in self.standings.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4}  {:<width$}  {:>6}  {:>5.1}%  {:>+7.3}  {:>7.1}",
+                rank + 1,
+                standing.agent,
+                standing.games,
+                standing.win_rate() * 100.0,
+                standing.points_per_game(),
+                standing.elo
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::agents::{agent::TestingAgent, logic::LogicAgent, random::RandomAgent};
+
+    use super::*;
+
+    fn entrant(name: &str, factory: fn() -> Box<dyn TestingAgent>) -> Entrant {
+        Entrant {
+            name: name.to_string(),
+            factory: Box::new(factory),
+        }
+    }
+
+    fn random() -> Box<dyn TestingAgent> {
+        Box::new(RandomAgent::new())
+    }
+
+    fn logic() -> Box<dyn TestingAgent> {
+        Box::new(LogicAgent::new_hard())
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let entrants = [
+            entrant("random", random),
+            entrant("logic", logic),
+            entrant("random2", random),
+        ];
+        let config = TournamentConfig {
+            games: 6,
+            seed: 4,
+            threads: 2,
+            ..Default::default()
+        };
+        let report = run_tournament(&entrants, &config);
+
+        assert_eq!(report.pairings.len(), 3);
+        assert!(report.pairings.iter().all(|pairing| pairing.games == 6));
+        assert!(report.standings.iter().all(|standing| standing.games == 12));
+        assert!(report
+            .standings
+            .windows(2)
+            .all(|pair| pair[0].elo >= pair[1].elo));
+        // the ratings and the points are zero-sum
+        let elo = report.standings.iter().map(|s| s.elo).sum::<f64>();
+        assert!((elo - 3.0 * INITIAL_ELO).abs() < 1e-6);
+        assert_eq!(report.standings.iter().map(|s| s.points).sum::<i32>(), 0);
+
+        let again = run_tournament(&entrants, &config);
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            serde_json::to_string(&again).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_csv_report() {
+        let report = TournamentReport {
+            games_per_pairing: 2,
+            seed: 0,
+            standings: vec![Standing {
+                agent: "logic:hard".to_string(),
+                games: 4,
+                wins: 3,
+                points: 3,
+                elo: 1512.24,
+            }],
+            pairings: Vec::new(),
+        };
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "rank,agent,games,wins,win_rate,points,points_per_game,elo\n\
+             1,logic:hard,4,3,0.7500,3,0.7500,1512.2\n"
+        );
+    }
+}
@@ -1,4 +1,7 @@
 pub mod agents;
+pub mod api;
 pub mod core;
 pub mod game;
-// mod models;
+pub mod models;
+pub mod net;
+pub mod tui;
@@ -1,39 +1,669 @@
 use backgammon::{
-    agents::{agent::TestingAgent, random::RandomAgent},
-    game::game::play_game,
+    agents::{
+        agent::TestingAgent,
+        human::{HumanAgent, NarratedAgent},
+        logic::LogicAgent,
+        model::ModelAgent,
+        random::RandomAgent,
+        search::{BoardEvaluator, SearchAgent, SearchConfig},
+    },
+    core::{
+        bearoff::{BearoffDatabase, DEFAULT_PATH, MAX_CHECKERS},
+        rules::Variant,
+    },
+    game::{
+        analysis::{analyze_game, AnalysisConfig},
+        game::{play_game_with_options, GameOptions},
+        gnubg_id::print_gnubg_id,
+        match_play::play_match,
+        record::GameRecord,
+        replay::replay,
+        rng::GameRng,
+        tournament::{run_tournament, Entrant, TournamentConfig},
+    },
+    models::{
+        backend::{DefaultBackend, TrainingBackend},
+        model::{Model, ModelConfig},
+        trainer::{train, ModelTrainingConfig},
+    },
+    net::{connect, serve_on},
+    tui::{play_tui, Seat, TuiOptions},
 };
-use std::{collections::HashMap, time::Instant};
-fn main() {
-    // train::<Autodiff<Wgpu>>(WgpuDevice::default());
-
-    // let device = WgpuDevice::default();
-    // println!("starting...");
-    // test(
-    //     ModelConfig::new(198, 80).init::<Autodiff<Wgpu>>(&device),
-    //     1,
-    //     1,
-    //     false,
-    // );
-    // println!("end...");
-    //
-    // // let black_agent: Box<dyn Agent> = Box::new(LogicAgent::new_hard());
-    // let red_agent: Box<dyn Agent> = Box::new(ModelAgent::<Autodiff<Wgpu>>::new(&device, 198, 80));
-
-    let total = 4000;
-    let mut red_wins = 0;
-    let mut red_score = 0;
-    let mut turns = 0;
+use clap::{builder::TypedValueParser, value_parser, Parser, Subcommand, ValueEnum};
+use std::{
+    collections::HashMap,
+    fmt, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Plays a batch of games between the agents and prints the stats (default)
+    Run {
+        #[arg(long, default_value = "random")]
+        black: AgentSpec,
+        #[arg(long, default_value = "random")]
+        red: AgentSpec,
+        /// number of games to play
+        #[arg(short = 'n', long, default_value_t = 4000, value_parser = value_parser!(u64).range(1..).map(|n| n as usize))]
+        games: usize,
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// standard, nackgammon, hypergammon or acey-deucey
+        #[arg(long, default_value = "standard")]
+        variant: Variant,
+    },
+    /// Plays a single game and saves its record
+    Record {
+        path: PathBuf,
+        #[arg(long, default_value = "random")]
+        black: AgentSpec,
+        #[arg(long, default_value = "random")]
+        red: AgentSpec,
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// standard, nackgammon, hypergammon or acey-deucey
+        #[arg(long, default_value = "standard")]
+        variant: Variant,
+    },
+    /// Steps through a saved game, forward and backward
+    Replay { path: PathBuf },
+    /// Prints a saved game in match notation
+    Export { path: PathBuf },
+    /// Rates each play of a saved game against the best play of its roll
+    Analyze {
+        path: PathBuf,
+        /// `logic[:<preset>]` or `model:<dir>` agent scoring the plays
+        #[arg(short, long, default_value = "logic")]
+        evaluator: AgentSpec,
+        /// equity loss from which a play is an error
+        #[arg(long, default_value_t = AnalysisConfig::default().error)]
+        error: f64,
+        /// equity loss from which a play is a blunder
+        #[arg(long, default_value_t = AnalysisConfig::default().blunder)]
+        blunder: f64,
+    },
+    /// Plays a game on the terminal, against a bot or hot-seat between two people
+    Play {
+        /// agent to play against, a hot-seat game is played when omitted
+        #[arg(short, long)]
+        bot: Option<AgentSpec>,
+        /// color of the person playing against the bot
+        #[arg(short, long, value_enum, default_value_t = Color::Black)]
+        color: Color,
+        /// agent giving the hints
+        #[arg(long, default_value = "logic")]
+        hints: AgentSpec,
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// saves the record of the game to this path
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// standard, nackgammon, hypergammon or acey-deucey
+        #[arg(long, default_value = "standard")]
+        variant: Variant,
+    },
+    /// Plays a game in a terminal UI: watching two bots, against a bot or hot-seat
+    Tui {
+        #[arg(long, default_value = "human")]
+        black: AgentSpec,
+        #[arg(long, default_value = "logic")]
+        red: AgentSpec,
+        /// agent giving the hints
+        #[arg(long, default_value = "logic")]
+        hints: AgentSpec,
+        /// milliseconds before each play of a bot
+        #[arg(short, long, default_value_t = 800)]
+        delay: u64,
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// saves the record of the game to this path
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// standard, nackgammon, hypergammon or acey-deucey
+        #[arg(long, default_value = "standard")]
+        variant: Variant,
+    },
+    /// Hosts a game for two `connect` clients, the first one to join plays black
+    Serve {
+        #[arg(default_value = "0.0.0.0:7878")]
+        addr: String,
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// saves the record of the game to this path
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// standard, nackgammon, hypergammon or acey-deucey
+        #[arg(long, default_value = "standard")]
+        variant: Variant,
+    },
+    /// Joins a game hosted with `serve`, played by a person on the terminal or a bot
+    Connect {
+        addr: String,
+        #[arg(short, long, default_value = "player")]
+        name: String,
+        #[arg(short, long, default_value = "human")]
+        agent: AgentSpec,
+    },
+    /// Plays a round robin between the agents and prints their Elo ratings
+    Tournament {
+        #[arg(required = true, num_args = 2..)]
+        agents: Vec<AgentSpec>,
+        /// games of each pairing, half with each agent playing black
+        #[arg(short = 'n', long, default_value_t = 100)]
+        games: usize,
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// threads playing the games, all the available ones by default
+        #[arg(short, long)]
+        threads: Option<usize>,
+        /// change of the Elo ratings after each game
+        #[arg(short, long, default_value_t = 16.0)]
+        k_factor: f64,
+        /// saves the standings as CSV to this path
+        #[arg(long)]
+        csv: Option<PathBuf>,
+        /// saves the standings and the pairings as JSON to this path
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// standard, nackgammon, hypergammon or acey-deucey
+        #[arg(long, default_value = "standard")]
+        variant: Variant,
+    },
+    /// Plays a match with doubling cube between two agents
+    Match {
+        /// points needed to win the match
+        #[arg(short, long, default_value_t = 7, value_parser = value_parser!(u32).range(1..))]
+        length: u32,
+        #[arg(long, default_value = "logic")]
+        black: AgentSpec,
+        #[arg(long, default_value = "logic")]
+        red: AgentSpec,
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// standard, nackgammon, hypergammon or acey-deucey
+        #[arg(long, default_value = "standard")]
+        variant: Variant,
+    },
+    /// Generates the bear-off database used by the agents in bear-off races
+    Bearoff {
+        #[arg(default_value = DEFAULT_PATH)]
+        path: PathBuf,
+        /// most checkers of a position in the database
+        #[arg(short, long, default_value_t = MAX_CHECKERS, value_parser = value_parser!(u8).range(..=MAX_CHECKERS as i64))]
+        checkers: u8,
+    },
+    /// Prints the position of a GNU Backgammon ID, `PositionID` or `PositionID:MatchID`
+    Position {
+        id: String,
+        /// player on roll when the ID has no Match ID
+        #[arg(short, long, value_enum, default_value_t = Color::Black)]
+        turn: Color,
+    },
+    /// Trains the model with TD(lambda) self-play, resuming from the checkpoint in `dir`
+    Train {
+        #[arg(default_value = "artifacts")]
+        dir: PathBuf,
+        #[arg(short, long, default_value_t = 1_000)]
+        epochs: usize,
+        /// games played against the random agent at each evaluation
+        #[arg(short, long, default_value_t = 100)]
+        tests: usize,
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+/// Agent given on the command line: `random`, `logic[:<preset>]`, `model:<dir>`,
+/// `search[:<plies>[:<evaluator>]]` or `human`.
+#[derive(Debug, Clone)]
+enum AgentSpec {
+    Random,
+    Logic(String),
+    /// directory of a saved model or of a training checkpoint
+    Model(PathBuf),
+    /// searches with a logic or model evaluator
+    Search(usize, Box<AgentSpec>),
+    Human,
+}
+
+impl FromStr for AgentSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':').unwrap_or((s, "")) {
+            ("random", "") => Ok(AgentSpec::Random),
+            ("logic", preset) => {
+                let preset = if preset.is_empty() { "hard" } else { preset };
+                match LogicAgent::from_preset(preset) {
+                    Some(_) => Ok(AgentSpec::Logic(preset.to_string())),
+                    None => Err(format!(
+                        "unknown logic preset `{}`, expected one of: {}",
+                        preset,
+                        LogicAgent::PRESETS.join(", ")
+                    )),
+                }
+            }
+            ("model", path) if !path.is_empty() => Ok(AgentSpec::Model(path.into())),
+            ("search", rest) => {
+                let (plies, evaluator) = rest.split_once(':').unwrap_or((rest, "logic"));
+                let plies = match plies {
+                    "" => 2,
+                    plies => plies
+                        .parse()
+                        .ok()
+                        .filter(|plies| (1..=3).contains(plies))
+                        .ok_or(format!("invalid search plies `{}`, expected 1 to 3", plies))?,
+                };
+                match evaluator.parse()? {
+                    evaluator @ (AgentSpec::Logic(_) | AgentSpec::Model(_)) => {
+                        Ok(AgentSpec::Search(plies, Box::new(evaluator)))
+                    }
+                    _ => Err(format!(
+                        "invalid search evaluator `{}`, expected logic[:<preset>] or model:<dir>",
+                        evaluator
+                    )),
+                }
+            }
+            ("human", "") => Ok(AgentSpec::Human),
+            _ => Err(format!(
+                "unknown agent `{}`, expected random, logic[:<preset>], model:<dir>, \
+                 search[:<plies>[:<evaluator>]] or human",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for AgentSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AgentSpec::Random => write!(f, "random"),
+            AgentSpec::Logic(preset) => write!(f, "logic:{}", preset),
+            AgentSpec::Model(dir) => write!(f, "model:{}", dir.display()),
+            AgentSpec::Search(plies, evaluator) => write!(f, "search:{}:{}", plies, evaluator),
+            AgentSpec::Human => write!(f, "human"),
+        }
+    }
+}
+
+impl AgentSpec {
+    /// `verbose` agents print how they reached their decisions.
+    fn agent(&self, verbose: bool) -> io::Result<Box<dyn TestingAgent>> {
+        Ok(match self {
+            AgentSpec::Random => Box::new(RandomAgent::new()),
+            AgentSpec::Logic(preset) => Box::new(LogicAgent::from_preset(preset).unwrap()),
+            AgentSpec::Model(dir) => Box::new(load_model_agent(dir)?),
+            AgentSpec::Search(plies, evaluator) => {
+                let evaluator = evaluator.evaluator()?;
+                let config = SearchConfig {
+                    plies: *plies,
+                    verbose,
+                    ..Default::default()
+                };
+                Box::new(SearchAgent::new(evaluator, config))
+            }
+            AgentSpec::Human => Box::new(HumanAgent::new()),
+        })
+    }
+
+    /// The logic and model agents, which can also score positions.
+    fn evaluator(&self) -> io::Result<Box<dyn BoardEvaluator>> {
+        Ok(match self {
+            AgentSpec::Logic(preset) => Box::new(LogicAgent::from_preset(preset).unwrap()),
+            AgentSpec::Model(dir) => Box::new(load_model_agent(dir)?),
+            spec => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "`{}` cannot evaluate positions, expected logic or model",
+                        spec
+                    ),
+                ))
+            }
+        })
+    }
+
+    fn seat(&self) -> io::Result<Seat> {
+        Ok(match self {
+            AgentSpec::Human => Seat::Human,
+            spec => Seat::Bot(spec.agent(false)?),
+        })
+    }
+}
+
+fn load_model_agent(dir: &Path) -> io::Result<ModelAgent<DefaultBackend>> {
+    let model = Model::load(dir, &Default::default()).map_err(|err| {
+        io::Error::other(format!(
+            "cannot load the model in {}: {:?}",
+            dir.display(),
+            err
+        ))
+    })?;
+    Ok(ModelAgent::with_model(model))
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Color {
+    Black,
+    Red,
+}
+
+impl Color {
+    fn player_color(self) -> i8 {
+        match self {
+            Color::Black => -1,
+            Color::Red => 1,
+        }
+    }
+}
 
-    let black_agent: Box<dyn TestingAgent> = Box::new(RandomAgent::new());
-    let red_agent: Box<dyn TestingAgent> = Box::new(RandomAgent::new());
+fn main() -> io::Result<()> {
+    let args = Args::parse();
 
+    let command = args.command.unwrap_or(Command::Run {
+        black: AgentSpec::Random,
+        red: AgentSpec::Random,
+        games: 4000,
+        seed: None,
+        variant: Variant::Standard,
+    });
+    match command {
+        Command::Run {
+            black,
+            red,
+            games,
+            seed,
+            variant,
+        } => {
+            let black_agent = black.agent(false)?;
+            let red_agent = red.agent(false)?;
+
+            let mut agents = HashMap::new();
+            agents.insert(1, red_agent.as_ref());
+            agents.insert(-1, black_agent.as_ref());
+
+            run(&agents, games, seed, variant)
+        }
+        Command::Record {
+            path,
+            black,
+            red,
+            seed,
+            variant,
+        } => {
+            let black_agent = black.agent(false)?;
+            let red_agent = red.agent(false)?;
+
+            let mut agents = HashMap::new();
+            agents.insert(1, red_agent.as_ref());
+            agents.insert(-1, black_agent.as_ref());
+
+            let mut rng = seed.map_or_else(GameRng::from_entropy, GameRng::from_seed);
+            let options = GameOptions {
+                variant,
+                ..Default::default()
+            };
+            let game_result = play_game_with_options(&agents, &mut rng, options, false);
+            game_result.record.save(&path)?;
+            println!("Game saved to {} (seed {})", path.display(), rng.seed());
+        }
+        Command::Replay { path } => replay(&GameRecord::load(path)?)?,
+        Command::Export { path } => print!("{}", GameRecord::load(path)?.to_match_notation()),
+        Command::Analyze {
+            path,
+            evaluator,
+            error,
+            blunder,
+        } => {
+            let record = GameRecord::load(path)?;
+            let config = AnalysisConfig { error, blunder };
+            print!(
+                "{}",
+                analyze_game(&record, evaluator.evaluator()?.as_ref(), &config)
+            );
+        }
+        Command::Play {
+            bot,
+            color,
+            hints,
+            seed,
+            save,
+            variant,
+        } => play(bot, color, hints, seed, save, variant)?,
+        Command::Tui {
+            black,
+            red,
+            hints,
+            delay,
+            seed,
+            save,
+            variant,
+        } => {
+            let options = TuiOptions {
+                delay: Duration::from_millis(delay),
+                hint_agent: Some(hints.agent(false)?),
+                variant,
+            };
+            let mut rng = seed.map_or_else(GameRng::from_entropy, GameRng::from_seed);
+            let record = play_tui(black.seat()?, red.seat()?, &mut rng, options)?;
+
+            if let (Some(winner), Some(victory_type)) = (record.winner, record.victory_type) {
+                println!(
+                    "{} wins: {:?} ({} points)",
+                    if winner == 1 { "Red" } else { "Black" },
+                    victory_type,
+                    victory_type.value()
+                );
+            }
+            if let Some(path) = save {
+                record.save(&path)?;
+                println!("Game saved to {} (seed {})", path.display(), rng.seed());
+            }
+        }
+        Command::Serve {
+            addr,
+            seed,
+            save,
+            variant,
+        } => {
+            let mut rng = seed.map_or_else(GameRng::from_entropy, GameRng::from_seed);
+            println!("Waiting for the players on {} (seed {})", addr, rng.seed());
+            let record = serve_on(&addr, &mut rng, variant)?;
+            println!("{}", record.to_match_notation());
+            if let Some(path) = save {
+                record.save(&path)?;
+                println!("Game saved to {}", path.display());
+            }
+        }
+        Command::Connect { addr, name, agent } => {
+            let agent = agent.agent(false)?;
+            let outcome = connect(&addr, &name, agent.as_ref(), &mut rand::thread_rng(), true)?;
+            println!(
+                "You {} {} points",
+                if outcome.winner == outcome.color {
+                    "win"
+                } else {
+                    "lose"
+                },
+                outcome.points
+            );
+        }
+        Command::Tournament {
+            agents,
+            games,
+            seed,
+            threads,
+            k_factor,
+            csv,
+            json,
+            variant,
+        } => {
+            let mut entrants = Vec::new();
+            for spec in agents {
+                if matches!(spec, AgentSpec::Human) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "people cannot take part in tournaments",
+                    ));
+                }
+                // loads the agent once to report the errors before any game
+                spec.agent(false)?;
+                entrants.push(Entrant {
+                    name: spec.to_string(),
+                    factory: Box::new(move || spec.agent(false).unwrap()),
+                });
+            }
+            let config = TournamentConfig {
+                games,
+                seed,
+                threads: threads.unwrap_or_else(|| {
+                    thread::available_parallelism().map_or(1, |threads| threads.get())
+                }),
+                k_factor,
+                variant,
+            };
+
+            let report = run_tournament(&entrants, &config);
+            print!("{}", report);
+            if let Some(path) = csv {
+                report.save_csv(&path)?;
+                println!("Standings saved to {}", path.display());
+            }
+            if let Some(path) = json {
+                report.save_json(&path)?;
+                println!("Report saved to {}", path.display());
+            }
+        }
+        Command::Match {
+            length,
+            black,
+            red,
+            seed,
+            variant,
+        } => {
+            let black_agent = black.agent(false)?;
+            let red_agent = red.agent(false)?;
+
+            let mut agents = HashMap::new();
+            agents.insert(1, red_agent.as_ref());
+            agents.insert(-1, black_agent.as_ref());
+
+            let mut rng = seed.map_or_else(GameRng::from_entropy, GameRng::from_seed);
+            let match_result = play_match(&agents, length, variant, &mut rng, true);
+            println!(
+                "{} wins the match {}-{} (seed {})",
+                if match_result.winner == 1 {
+                    "Red"
+                } else {
+                    "Black"
+                },
+                match_result.score.0,
+                match_result.score.1,
+                rng.seed()
+            );
+        }
+        Command::Bearoff { path, checkers } => {
+            let start = Instant::now();
+            BearoffDatabase::generate(checkers).save(&path)?;
+            println!(
+                "Bear-off database saved to {} in {:?}",
+                path.display(),
+                start.elapsed()
+            );
+        }
+        Command::Position { id, turn } => print_gnubg_id(&id, turn.player_color())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?,
+        Command::Train {
+            dir,
+            epochs,
+            tests,
+            seed,
+        } => {
+            let config = ModelTrainingConfig::new(ModelConfig::new(198, 80))
+                .with_num_epochs(epochs)
+                .with_num_tests(tests)
+                .with_seed(seed);
+            train::<TrainingBackend>(Default::default(), config, &dir)
+                .map_err(|err| io::Error::other(format!("{:?}", err)))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn play(
+    bot: Option<AgentSpec>,
+    color: Color,
+    hints: AgentSpec,
+    seed: Option<u64>,
+    save: Option<PathBuf>,
+    variant: Variant,
+) -> io::Result<()> {
+    let mut rng = seed.map_or_else(GameRng::from_entropy, GameRng::from_seed);
+    let human_agent: Box<dyn TestingAgent> = Box::new(HumanAgent::with_hints(
+        hints.agent(false)?,
+        rng.hint_rng().clone(),
+    ));
+    let opponent_agent: Box<dyn TestingAgent> = match bot {
+        Some(AgentSpec::Human) | None => Box::new(HumanAgent::with_hints(
+            hints.agent(false)?,
+            rng.hint_rng().clone(),
+        )),
+        Some(bot) => Box::new(NarratedAgent::new(bot.agent(true)?)),
+    };
+
+    let human_color = color.player_color();
     let mut agents = HashMap::new();
-    agents.insert(1, &red_agent);
-    agents.insert(-1, &black_agent);
+    agents.insert(human_color, human_agent.as_ref());
+    agents.insert(-human_color, opponent_agent.as_ref());
+
+    let options = GameOptions {
+        variant,
+        ..Default::default()
+    };
+    let game_result = play_game_with_options(&agents, &mut rng, options, false);
+
+    println!(
+        "{} wins: {:?} ({} points)",
+        if game_result.winner == 1 {
+            "Red"
+        } else {
+            "Black"
+        },
+        game_result.victory_type,
+        game_result.victory_type.value()
+    );
+    if let Some(path) = save {
+        game_result.record.save(&path)?;
+        println!("Game saved to {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn run(agents: &HashMap<i8, &dyn TestingAgent>, total: usize, seed: Option<u64>, variant: Variant) {
+    let mut rng = seed.map_or_else(GameRng::from_entropy, GameRng::from_seed);
+    let options = GameOptions {
+        variant,
+        ..Default::default()
+    };
+    let mut red_wins = 0;
+    let mut red_score = 0;
+    let mut turns = 0;
 
     let start = Instant::now();
     for _ in 0..total {
-        let game_result = play_game(&agents, false);
+        let game_result = play_game_with_options(agents, &mut rng.next_game_rng(), options, false);
         if game_result.winner == 1 {
             red_wins += 1;
             red_score += game_result.victory_type.value()
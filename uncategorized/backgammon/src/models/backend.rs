@@ -0,0 +1,14 @@
+use burn::backend::Autodiff;
+
+// The backend is picked with cargo features: `ndarray` (CPU, default) or `wgpu` (GPU).
+
+#[cfg(feature = "wgpu")]
+pub type DefaultBackend = burn::backend::Wgpu;
+
+#[cfg(all(feature = "ndarray", not(feature = "wgpu")))]
+pub type DefaultBackend = burn::backend::NdArray;
+
+#[cfg(not(any(feature = "ndarray", feature = "wgpu")))]
+compile_error!("a model backend is required, enable the `ndarray` or `wgpu` feature");
+
+pub type TrainingBackend = Autodiff<DefaultBackend>;
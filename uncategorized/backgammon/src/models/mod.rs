@@ -1,2 +1,3 @@
-pub(crate) mod model;
-pub(crate) mod trainer;
+pub mod backend;
+pub mod model;
+pub mod trainer;
@@ -1,16 +1,19 @@
+use std::{fs, path::Path};
+
 use burn::{
     config::Config,
     module::Module,
     nn::{Linear, LinearConfig},
-    tensor::{activation::sigmoid, backend::Backend, ElementConversion, Tensor},
+    record::{FullPrecisionSettings, NamedMpkFileRecorder, RecorderError},
+    tensor::{activation::sigmoid, backend::Backend, Tensor},
 };
 
 use crate::core::{
     board::BackgammonBoard,
     dice::DiceRoll,
     moves::{generate_all_move_sequences, MoveSequence},
-    players::{PlayerColor, BLACK_PLAYER, RED_PLAYER},
-    positions::bar_pos,
+    players::{get_opponent, PlayerColor, BLACK_PLAYER, RED_PLAYER},
+    positions::{bar_pos, norm_pos, BLACK_OFF},
 };
 
 #[derive(Module, Debug)]
@@ -36,54 +39,90 @@ impl ModelConfig {
 }
 
 impl<B: Backend> Model<B> {
+    pub fn config(&self) -> ModelConfig {
+        let [input_size, hidden_size] = self.input.weight.dims();
+        ModelConfig::new(input_size, hidden_size)
+    }
+
+    /// Saves the config (`model.json`) and the weights (`model.mpk`) of the model to `dir`.
+    pub fn save(&self, dir: &Path) -> Result<(), RecorderError> {
+        fs::create_dir_all(dir).map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        self.config()
+            .save(dir.join("model.json"))
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        self.clone().save_file(
+            dir.join("model"),
+            &NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+        )
+    }
+
+    /// Loads a model saved with [`Model::save`].
+    pub fn load(dir: &Path, device: &B::Device) -> Result<Self, RecorderError> {
+        let config = ModelConfig::load(dir.join("model.json"))
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        config.init(device).load_file(
+            dir.join("model"),
+            &NamedMpkFileRecorder::<FullPrecisionSettings>::new(),
+            device,
+        )
+    }
+
+    /// Probability of red winning for each position in the batch.
+    ///
     /// # Shapes
-    ///   - Images [batch_size, height, width]
-    ///   - Output [batch_size, num_classes]
+    ///   - Features [batch_size, 198]
+    ///   - Output [batch_size]
     pub fn forward(&self, features: Tensor<B, 2>) -> Tensor<B, 1> {
+        let [batch_size, _] = features.dims();
+
         let x = self.input.forward(features);
         let x = sigmoid(x);
 
         let x = self.output.forward(x);
-        sigmoid(x).reshape([1])
+        sigmoid(x).reshape([batch_size])
     }
 
+    /// Features of the position with `player_color` on roll, as a batch of one.
     pub(crate) fn get_board_features(
         &self,
         board: &BackgammonBoard,
         player_color: PlayerColor,
     ) -> Tensor<B, 2> {
-        let mut features_vector = Vec::with_capacity(198);
-
-        let mut player_feat = match player_color {
-            BLACK_PLAYER => vec![0.0, 1.0],
-            RED_PLAYER => vec![1.0, 0.0],
-            _ => panic!("invalid player color"),
-        };
+        self.features_tensor(board_features(board, player_color), 1)
+    }
 
-        for player_color in [BLACK_PLAYER, RED_PLAYER] {
-            for pos in 1..=24 {
-                let mut feats = match board.points_at(pos) {
-                    0 => vec![0.0, 0.0, 0.0, 0.0],
-                    1 => vec![1.0, 0.0, 0.0, 0.0],
-                    2 => vec![1.0, 1.0, 0.0, 0.0],
-                    3 => vec![1.0, 1.0, 1.0, 0.0],
-                    p => vec![1.0, 1.0, 1.0, ((p - 3) as f32) / 2.0],
-                };
-                features_vector.append(&mut feats)
-            }
-
-            let bar_points = board.checkers_at(player_color, bar_pos(player_color));
-            let non_bar_points = 15 - bar_points;
-
-            features_vector.push(bar_points as f32 / 2.0);
-            features_vector.push(non_bar_points as f32 / 15.0);
-        }
+    fn features_tensor(&self, features: Vec<f32>, batch_size: usize) -> Tensor<B, 2> {
+        Tensor::from_floats(features.as_slice(), &self.input.weight.device())
+            .reshape([batch_size, 198])
+    }
 
-        features_vector.append(&mut player_feat);
+    /// Probability of red winning each of the positions, all with `player_color` on roll.
+    pub(crate) fn red_win_probabilities(
+        &self,
+        boards: &[BackgammonBoard],
+        player_color: PlayerColor,
+    ) -> Vec<f32> {
+        let features = boards
+            .iter()
+            .flat_map(|board| board_features(board, player_color))
+            .collect();
+        let probabilities = self.forward(self.features_tensor(features, boards.len()));
 
-        assert_eq!(features_vector.len(), 198);
+        probabilities.into_data().convert::<f32>().value
+    }
 
-        Tensor::from_floats(features_vector.as_slice(), &B::Device::default()).reshape([2, 198])
+    /// Winning chances of `player_color` when it is on roll.
+    pub(crate) fn win_probability(
+        &self,
+        board: &BackgammonBoard,
+        player_color: PlayerColor,
+    ) -> f64 {
+        let red_win =
+            self.red_win_probabilities(std::slice::from_ref(board), player_color)[0] as f64;
+        match player_color {
+            RED_PLAYER => red_win,
+            _ => 1.0 - red_win,
+        }
     }
 
     pub(crate) fn get_action(
@@ -92,22 +131,155 @@ impl<B: Backend> Model<B> {
         player_color: PlayerColor,
         roll: &DiceRoll,
     ) -> Option<MoveSequence> {
-        let move_sequences = generate_all_move_sequences(board, player_color, roll, None);
+        let move_sequences = generate_all_move_sequences(board, player_color, roll);
 
-        if move_sequences.len() == 0 {
+        if move_sequences.is_empty() {
             return None;
         }
 
+        let boards: Vec<_> = move_sequences
+            .iter()
+            .map(|move_sequence| board.apply_move_sequence(move_sequence, player_color))
+            .collect();
+        // after the play it is the opponent who is on roll
+        let red_wins = self.red_win_probabilities(&boards, get_opponent(player_color));
+
         move_sequences
             .into_iter()
-            .map(|move_sequence| {
-                let temp_board = board.apply_move_sequence(&move_sequence, player_color);
-                let board_features = self.get_board_features(&temp_board, player_color);
-                let score = self.forward(board_features);
-                let score = score.sum().into_scalar().elem::<f32>();
-                (score, move_sequence)
+            .zip(red_wins)
+            .map(|(move_sequence, red_win)| match player_color {
+                RED_PLAYER => (red_win, move_sequence),
+                _ => (1.0 - red_win, move_sequence),
             })
             .max_by(|(x, _), (y, _)| x.partial_cmp(y).unwrap())
             .map(|val| val.1)
     }
 }
+
+/// The 198 inputs of TD-Gammon: for each player, 4 units for each of the 24 points, the
+/// checkers on the bar and the share of its checkers borne off; then 2 units for the
+/// player on roll.
+fn board_features(board: &BackgammonBoard, player_color: PlayerColor) -> Vec<f32> {
+    let mut features_vector = Vec::with_capacity(198);
+
+    let mut player_feat = match player_color {
+        BLACK_PLAYER => vec![0.0, 1.0],
+        RED_PLAYER => vec![1.0, 0.0],
+        _ => panic!("invalid player color"),
+    };
+
+    for color in [BLACK_PLAYER, RED_PLAYER] {
+        for pos in 1..=24 {
+            let mut feats = match board.checkers_at(color, pos) {
+                0 => vec![0.0, 0.0, 0.0, 0.0],
+                1 => vec![1.0, 0.0, 0.0, 0.0],
+                2 => vec![1.0, 1.0, 0.0, 0.0],
+                3 => vec![1.0, 1.0, 1.0, 0.0],
+                p => vec![1.0, 1.0, 1.0, ((p - 3) as f32) / 2.0],
+            };
+            features_vector.append(&mut feats)
+        }
+
+        let bar_points = board.checkers_at(color, bar_pos(color));
+        let off_points = board.checkers_at(color, norm_pos(BLACK_OFF, color));
+
+        features_vector.push(bar_points as f32 / 2.0);
+        features_vector.push(off_points as f32 / board.variant().checkers() as f32);
+    }
+
+    features_vector.append(&mut player_feat);
+
+    assert_eq!(features_vector.len(), 198);
+
+    features_vector
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            moves::generate_all_move_sequences,
+            players::{b, r, RED_PLAYER},
+            rules::Variant,
+        },
+        models::backend::DefaultBackend as TestBackend,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_board_features() {
+        let features = board_features(&BackgammonBoard::default_board(), RED_PLAYER);
+
+        // 15 checkers on the board for each player, none on the bar or borne off
+        let checkers = |units: &[f32]| -> f32 {
+            units
+                .chunks(4)
+                .map(|unit| unit[0] + unit[1] + unit[2] + unit[3] * 2.0)
+                .sum()
+        };
+        assert_eq!(checkers(&features[0..96]), 15.0);
+        assert_eq!(checkers(&features[98..194]), 15.0);
+        assert_eq!(&features[96..98], &[0.0, 0.0]);
+        assert_eq!(&features[194..196], &[0.0, 0.0]);
+        assert_eq!(&features[196..], &[1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_board_features_borne_off_share() {
+        // a Hypergammon position, with 2 of the 3 black checkers borne off
+        let board = BackgammonBoard::from_pairs(&[(BLACK_OFF, b(2)), (3, b(1)), (24, r(3))])
+            .with_variant(Variant::Hypergammon);
+        let features = board_features(&board, BLACK_PLAYER);
+
+        assert!((features[97] - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(features[195], 0.0);
+    }
+
+    #[test]
+    fn test_forward_pass() {
+        let model: Model<TestBackend> = ModelConfig::new(198, 20).init(&Default::default());
+        let board = BackgammonBoard::default_board();
+
+        let pred = model.forward(model.get_board_features(&board, BLACK_PLAYER));
+        assert_eq!(pred.dims(), [1]);
+
+        let boards = vec![board.clone(); 3];
+        let preds = model.red_win_probabilities(&boards, BLACK_PLAYER);
+        assert_eq!(preds.len(), 3);
+        assert!(preds.iter().all(|pred| (0.0..=1.0).contains(pred)));
+        assert!(preds.iter().all(|pred| *pred == preds[0]));
+
+        let black_win = model.win_probability(&board, BLACK_PLAYER);
+        assert!((black_win - (1.0 - preds[0] as f64)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("bg-model-{}", std::process::id()));
+        let device = Default::default();
+        let model: Model<TestBackend> = ModelConfig::new(198, 12).init(&device);
+        model.save(&dir).unwrap();
+
+        let loaded = Model::<TestBackend>::load(&dir, &device).unwrap();
+        assert_eq!(loaded.config().hidden_size, 12);
+
+        let board = BackgammonBoard::default_board();
+        assert_eq!(
+            loaded.win_probability(&board, RED_PLAYER),
+            model.win_probability(&board, RED_PLAYER)
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_get_action_is_legal() {
+        let model: Model<TestBackend> = ModelConfig::new(198, 20).init(&Default::default());
+        let board = BackgammonBoard::default_board();
+        let roll = DiceRoll::Single(3, 1);
+
+        let action = model.get_action(&board, RED_PLAYER, &roll).unwrap();
+        assert!(generate_all_move_sequences(&board, RED_PLAYER, &roll).contains(&action));
+    }
+}
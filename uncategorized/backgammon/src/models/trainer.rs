@@ -1,40 +1,32 @@
-use core::num;
-use std::collections::HashMap;
+use std::{collections::HashMap, marker::PhantomData, path::Path};
 
 use burn::{
     config::Config,
-    module::AutodiffModule,
-    optim::{AdamConfig, GradientsParams, Optimizer},
+    module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId},
+    optim::GradientsParams,
+    record::RecorderError,
     tensor::{
         backend::{AutodiffBackend, Backend},
-        Tensor,
+        ElementConversion, Tensor,
     },
 };
 
-use crate::core::{
-    board::BackgammonBoard,
-    dice::DiceRoll,
-    players::{get_opponent, get_random_player, BLACK_PLAYER, RED_PLAYER},
-    positions::{BLACK_OFF, RED_OFF},
+use crate::{
+    agents::{agent::TestingAgent, model::ModelAgent, random::RandomAgent},
+    core::{
+        board::BackgammonBoard,
+        players::{get_opponent, get_random_player, RED_PLAYER},
+    },
+    game::{
+        game::{get_winner, play_game},
+        rng::GameRng,
+    },
 };
 
 use super::model::{Model, ModelConfig};
 
-fn check_last_pred<B: Backend>(
-    board: &BackgammonBoard,
-    device: &B::Device,
-) -> Option<Tensor<B, 1>> {
-    if board.checkers_at(BLACK_PLAYER, BLACK_OFF) == 15 {
-        Some(Tensor::from_floats([0.0], device))
-    } else if board.checkers_at(RED_PLAYER, RED_OFF) == 15 {
-        Some(Tensor::from_floats([1.0], device))
-    } else {
-        None
-    }
-}
-
 #[derive(Config)]
-pub(crate) struct ModelTrainingConfig {
+pub struct ModelTrainingConfig {
     #[config(default = 1_000)]
     pub num_epochs: usize,
     #[config(default = 1_000)]
@@ -45,134 +37,310 @@ pub(crate) struct ModelTrainingConfig {
     pub alpha: f64,
     #[config(default = 0.7)]
     pub lambda: f64,
-    // pub eligibility_traces: []
+    /// the game of each epoch is played with `seed + epoch`
+    #[config(default = 0)]
+    pub seed: u64,
     pub model_config: ModelConfig,
-    pub optimizer: AdamConfig,
 }
 
-pub fn train<B: AutodiffBackend>(device: B::Device) {
-    // Create the configuration.
-    let config_model = ModelConfig::new(198, 80);
-    let config_optimizer = AdamConfig::new();
-    let config = ModelTrainingConfig::new(config_model, config_optimizer);
-
-    // Create the model and optimizer.
-    let mut model: Model<B> = config.model_config.init(&device);
-    let mut optim = config.optimizer.init::<B, Model<B>>();
-
-    // Iterate over our training and validation loop for X epochs.
-    for epoch in 1..config.num_epochs + 1 {
-        // Implement our training loop.
-        let mut board = BackgammonBoard::default_board();
-        let mut player_color = get_random_player();
-        let mut roll = DiceRoll::get_dice_roll();
-
-        let prev_pred = model.forward(model.get_board_features(&board, player_color));
-        let mut turn = 0;
-        while !won(&board) {
-            let move_sequence = model.get_action(&board, player_color, &roll);
-            if let Some(move_sequence) = move_sequence.clone() {
-                board = board.apply_move_sequence(&move_sequence, player_color);
-
-                let pred = check_last_pred(&board, &device)
-                    .unwrap_or(model.forward(model.get_board_features(&board, player_color)));
-
-                // println!("pred: {}", pred);
-
-                let loss = pred.clone() - prev_pred.clone();
-
-                // Gradients for the current backward pass
-                let grads = loss.backward();
-                // Gradients linked to each parameter of the model.
-                let grads = GradientsParams::from_grads(grads, &model);
-                // Update the model using the optimizer.
-                model = optim.step(config.alpha, model, grads);
+/// Last epoch saved in a checkpoint directory.
+#[derive(Config)]
+struct TrainingState {
+    epoch: usize,
+}
+
+/// Eligibility traces of the model parameters: `e = lambda * e + grad V(s)`.
+struct EligibilityTraces {
+    lambda: f64,
+    traces: GradientsParams,
+}
+
+impl EligibilityTraces {
+    fn new(lambda: f64) -> Self {
+        Self {
+            lambda,
+            traces: GradientsParams::new(),
+        }
+    }
+
+    fn accumulate<B: AutodiffBackend>(&mut self, model: &Model<B>, grads: GradientsParams) {
+        model.visit(&mut TracesAccumulator::<B> {
+            lambda: self.lambda,
+            traces: &mut self.traces,
+            grads,
+            backend: PhantomData,
+        });
+    }
+
+    /// Moves every parameter `step` times its trace.
+    fn apply<B: AutodiffBackend>(&self, model: Model<B>, step: f64) -> Model<B> {
+        model.map(&mut TracesMapper::<B> {
+            step,
+            traces: &self.traces,
+            backend: PhantomData,
+        })
+    }
+}
+
+struct TracesAccumulator<'a, B: AutodiffBackend> {
+    lambda: f64,
+    traces: &'a mut GradientsParams,
+    grads: GradientsParams,
+    backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for TracesAccumulator<'_, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
+            return;
+        };
+        let trace = match self.traces.remove::<B::InnerBackend, D>(id) {
+            Some(trace) => trace.mul_scalar(self.lambda) + grad,
+            None => grad,
+        };
+        self.traces.register(id.clone(), trace);
+    }
+}
+
+struct TracesMapper<'a, B: AutodiffBackend> {
+    step: f64,
+    traces: &'a GradientsParams,
+    backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleMapper<B> for TracesMapper<'_, B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.traces.get::<B::InnerBackend, D>(id) {
+            Some(trace) => {
+                Tensor::from_inner(tensor.inner() + trace.mul_scalar(self.step)).require_grad()
             }
+            None => tensor,
+        }
+    }
+}
 
-            player_color = get_opponent(player_color);
-            roll = DiceRoll::get_dice_roll();
-            turn += 1;
+/// Plays a game of the model against itself updating it after every play with TD(lambda).
+///
+/// The model estimates the probability of red winning, red picks the plays that
+/// maximize it and black the ones that minimize it. Returns the model and the number of
+/// turns played.
+fn train_game<B: AutodiffBackend>(
+    mut model: Model<B>,
+    config: &ModelTrainingConfig,
+    rng: &mut GameRng,
+) -> (Model<B>, usize) {
+    let mut traces = EligibilityTraces::new(config.lambda);
+    let mut board = BackgammonBoard::default_board();
+    let mut player_color = rng.first_player();
+
+    let mut turn = 0;
+    loop {
+        let roll = rng.roll_dice();
+
+        let pred = model.forward(model.get_board_features(&board, player_color));
+        let grads = GradientsParams::from_grads(pred.clone().backward(), &model);
+        traces.accumulate(&model, grads);
+        let pred = pred.into_scalar().elem::<f64>();
+
+        if let Some(move_sequence) = model.get_action(&board, player_color, &roll) {
+            board = board.apply_move_sequence(&move_sequence, player_color);
         }
+        player_color = get_opponent(player_color);
 
-        println!("Train - Epoch {} - Turns {}", epoch, turn);
+        let winner = get_winner(&board);
+        let next_pred = match winner {
+            Some(RED_PLAYER) => 1.0,
+            Some(_) => 0.0,
+            None => {
+                model.red_win_probabilities(std::slice::from_ref(&board), player_color)[0] as f64
+            }
+        };
 
-        if epoch != 0 && epoch % config.test_interval == 0 {
-            test(model.valid(), epoch, config.num_tests, false)
+        model = traces.apply(model, config.alpha * (next_pred - pred));
+        turn += 1;
+
+        if winner.is_some() {
+            return (model, turn);
         }
     }
 }
 
-pub fn test<B: Backend>(model: Model<B>, epoch: usize, num_tests: usize, parallel: bool) {
-    let logic_agent: Box<dyn Agent> = Box::new(LogicAgent::new_hard());
-    let model_agent: Box<dyn Agent> = Box::new(ModelAgent::with_model(model));
-
-    let mut avg_score = 0.0;
-    let mut win_ratio = 0.0;
-    let mut avg_turn = 0.0;
-
-    if parallel {
-        let (wins, score, turns) = (0..num_tests)
-            .map(|_| {
-                let mut agents = HashMap::new();
-                let model_color = get_random_player();
-                agents.insert(model_color, &model_agent);
-                agents.insert(get_opponent(model_color), &logic_agent);
-                (play_game(agents, false), model_color)
-            })
-            .fold(
-                (0, 0, 0),
-                |(wins, score, turns), (game_result, model_color)| {
-                    let mut wins = wins;
-                    let mut score = score;
-                    let mut turns = turns;
-
-                    if game_result.winner == model_color {
-                        wins += 1;
-                        score += game_result.victory_type.value()
-                    } else {
-                        score -= game_result.victory_type.value();
-                    }
-                    turns += game_result.turn;
-                    (wins, score, turns)
-                },
-            );
-
-        avg_score = score as f32 / num_tests as f32;
-        win_ratio = wins as f32 / num_tests as f32 * 100.0;
-        avg_turn = turns as f32 / num_tests as f32;
-    } else {
-        let (wins, score, turns) = (0..num_tests)
-            .map(|_| {
-                let mut agents = HashMap::new();
-                let model_color = get_random_player();
-                agents.insert(model_color, &model_agent);
-                agents.insert(get_opponent(model_color), &logic_agent);
-                (play_game(agents, false), model_color)
-            })
-            .fold(
-                (0, 0, 0),
-                |(wins, score, turns), (game_result, model_color)| {
-                    let mut wins = wins;
-                    let mut score = score;
-                    let mut turns = turns;
-
-                    if game_result.winner == model_color {
-                        wins += 1;
-                        score += game_result.victory_type.value()
-                    } else {
-                        score -= game_result.victory_type.value();
-                    }
-                    turns += game_result.turn;
-                    (wins, score, turns)
-                },
-            );
-
-        avg_score = score as f32 / num_tests as f32;
-        win_ratio = wins as f32 / num_tests as f32 * 100.0;
-        avg_turn = turns as f32 / num_tests as f32;
+/// Trains the model with self-play, saving a checkpoint to `artifact_dir` every
+/// `test_interval` epochs. Training resumes from the checkpoint found in `artifact_dir`,
+/// keeping the model config of the checkpoint.
+pub fn train<B: AutodiffBackend>(
+    device: B::Device,
+    config: ModelTrainingConfig,
+    artifact_dir: &Path,
+) -> Result<Model<B>, RecorderError> {
+    let (mut model, start_epoch) = match load_checkpoint(artifact_dir, &device)? {
+        Some((model, epoch)) => {
+            println!("Resuming training from epoch {}", epoch);
+            (model, epoch + 1)
+        }
+        None => (config.model_config.init(&device), 1),
+    };
+
+    for epoch in start_epoch..config.num_epochs + 1 {
+        let mut rng = GameRng::from_seed(config.seed.wrapping_add(epoch as u64));
+        let turn;
+        (model, turn) = train_game(model, &config, &mut rng);
+
+        println!("Train - Epoch {} - Turns {}", epoch, turn);
+
+        if epoch % config.test_interval == 0 || epoch == config.num_epochs {
+            test(model.valid(), epoch, config.num_tests);
+            save_checkpoint(&model, &config, epoch, artifact_dir)?;
+        }
     }
+
+    Ok(model)
+}
+
+fn save_checkpoint<B: Backend>(
+    model: &Model<B>,
+    config: &ModelTrainingConfig,
+    epoch: usize,
+    artifact_dir: &Path,
+) -> Result<(), RecorderError> {
+    model.save(artifact_dir)?;
+    config
+        .save(artifact_dir.join("training.json"))
+        .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+    TrainingState::new(epoch)
+        .save(artifact_dir.join("state.json"))
+        .map_err(|err| RecorderError::Unknown(err.to_string()))
+}
+
+fn load_checkpoint<B: Backend>(
+    artifact_dir: &Path,
+    device: &B::Device,
+) -> Result<Option<(Model<B>, usize)>, RecorderError> {
+    let Ok(state) = TrainingState::load(artifact_dir.join("state.json")) else {
+        return Ok(None);
+    };
+    let model = Model::load(artifact_dir, device)?;
+
+    Ok(Some((model, state.epoch)))
+}
+
+/// Plays `num_tests` games of the model against a `RandomAgent`, with the model taking a
+/// random color each game.
+pub fn test<B: Backend>(model: Model<B>, epoch: usize, num_tests: usize) {
+    let random_agent: Box<dyn TestingAgent> = Box::new(RandomAgent::new());
+    let model_agent: Box<dyn TestingAgent> = Box::new(ModelAgent::with_model(model));
+
+    let mut rng = GameRng::from_seed(epoch as u64);
+    let (wins, score, turns) = (0..num_tests)
+        .map(|_| {
+            let mut game_rng = rng.next_game_rng();
+            let model_color = get_random_player(game_rng.agent_rng());
+
+            let mut agents = HashMap::new();
+            agents.insert(model_color, model_agent.as_ref());
+            agents.insert(get_opponent(model_color), random_agent.as_ref());
+            (play_game(&agents, &mut game_rng, false), model_color)
+        })
+        .fold(
+            (0, 0, 0),
+            |(wins, score, turns), (game_result, model_color)| {
+                let mut wins = wins;
+                let mut score = score;
+                let mut turns = turns;
+
+                if game_result.winner == model_color {
+                    wins += 1;
+                    score += game_result.victory_type.value()
+                } else {
+                    score -= game_result.victory_type.value();
+                }
+                turns += game_result.turn;
+                (wins, score, turns)
+            },
+        );
+
+    let avg_score = score as f32 / num_tests as f32;
+    let win_ratio = wins as f32 / num_tests as f32 * 100.0;
+    let avg_turn = turns as f32 / num_tests as f32;
     println!(
         "Testing after {} epochs | avg score {:.3} | win ratio {:.3} | Avg turn {:.3}",
         epoch, avg_score, win_ratio, avg_turn
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{core::players::BLACK_PLAYER, models::backend::TrainingBackend as TestBackend};
+
+    use std::fs;
+
+    use super::*;
+
+    fn value(model: &Model<TestBackend>, board: &BackgammonBoard) -> f64 {
+        model.red_win_probabilities(std::slice::from_ref(board), BLACK_PLAYER)[0] as f64
+    }
+
+    #[test]
+    fn test_td_step_moves_value_towards_target() {
+        let device = Default::default();
+        let model: Model<TestBackend> = ModelConfig::new(198, 40).init(&device);
+        let board = BackgammonBoard::default_board();
+        let before = value(&model, &board);
+
+        let mut traces = EligibilityTraces::new(0.7);
+        let pred = model.forward(model.get_board_features(&board, BLACK_PLAYER));
+        traces.accumulate(&model, GradientsParams::from_grads(pred.backward(), &model));
+
+        let model = traces.apply(model, 0.1 * (1.0 - before));
+        assert!(value(&model, &board) > before);
+
+        let model = traces.apply(model, 0.1 * (0.0 - before));
+        let model = traces.apply(model, 0.1 * (0.0 - before));
+        assert!(value(&model, &board) < before);
+    }
+
+    #[test]
+    fn test_train_game_updates_the_model() {
+        let device = Default::default();
+        let config = ModelTrainingConfig::new(ModelConfig::new(198, 10));
+        let model: Model<TestBackend> = config.model_config.init(&device);
+        let board = BackgammonBoard::default_board();
+        let before = value(&model, &board);
+
+        let (model, turn) = train_game(model, &config, &mut GameRng::from_seed(1));
+        assert!(turn > 0);
+        assert_ne!(value(&model, &board), before);
+
+        let after = value(&model, &board);
+        let (model, _) = train_game(model, &config, &mut GameRng::from_seed(2));
+        assert_ne!(value(&model, &board), after);
+    }
+
+    #[test]
+    fn test_train_resumes_from_checkpoint() {
+        let device = Default::default();
+        let artifact_dir = std::env::temp_dir().join(format!("bg-train-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&artifact_dir);
+
+        let config = ModelTrainingConfig::new(ModelConfig::new(198, 10))
+            .with_num_epochs(2)
+            .with_test_interval(1)
+            .with_num_tests(1);
+        let model = train::<TestBackend>(device, config, &artifact_dir).unwrap();
+
+        let config = ModelTrainingConfig::new(ModelConfig::new(198, 10)).with_num_epochs(2);
+        let (resumed, epoch) = load_checkpoint::<TestBackend>(&artifact_dir, &device)
+            .unwrap()
+            .unwrap();
+        assert_eq!(epoch, 2);
+
+        let board = BackgammonBoard::default_board();
+        assert_eq!(value(&resumed, &board), value(&model, &board));
+
+        // nothing left to train
+        let model = train::<TestBackend>(device, config, &artifact_dir).unwrap();
+        assert_eq!(value(&model, &board), value(&resumed, &board));
+
+        fs::remove_dir_all(&artifact_dir).unwrap();
+    }
+}
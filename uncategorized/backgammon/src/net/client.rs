@@ -0,0 +1,134 @@
+use std::{
+    io,
+    net::{TcpStream, ToSocketAddrs},
+};
+
+use rand::RngCore;
+
+use crate::{
+    agents::agent::TestingAgent,
+    api::PlayerColor,
+    core::{board::BackgammonBoard, dice::DiceRoll},
+    game::{game::VictoryType, notation::format_move_sequence},
+};
+
+use super::protocol::{from_points, ClientMessage, Connection, ServerMessage};
+
+/// End of a game played over the network, as seen by a client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameOutcome {
+    pub color: PlayerColor,
+    pub winner: PlayerColor,
+    pub victory: VictoryType,
+    pub points: i32,
+}
+
+/// Joins the game hosted at `addr` as `name` and makes the plays of `agent`, a
+/// `HumanAgent` to play from the terminal. `print` prints the plays of both players.
+pub fn connect<A: ToSocketAddrs>(
+    addr: A,
+    name: &str,
+    agent: &dyn TestingAgent,
+    rng: &mut dyn RngCore,
+    print: bool,
+) -> io::Result<GameOutcome> {
+    let mut connection = Connection::new(TcpStream::connect(addr)?)?;
+    connection.send(&ClientMessage::Hello {
+        name: name.to_string(),
+    })?;
+
+    let (color, variant) = match connection.receive()? {
+        ServerMessage::Welcome {
+            color,
+            opponent,
+            variant,
+        } => {
+            if print {
+                println!("Playing {} against {} ({})", color, opponent, variant);
+            }
+            (color, variant)
+        }
+        message => return Err(unexpected(&message)),
+    };
+
+    // board and roll of the last turn of the client, played again when refused
+    let mut own_turn = None;
+    loop {
+        match connection.receive()? {
+            ServerMessage::Turn {
+                board,
+                turn,
+                dice: [die1, die2],
+                can_move,
+            } => {
+                if turn != color || !can_move {
+                    continue;
+                }
+                if !(1..=6).contains(&die1) || !(1..=6).contains(&die2) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid dice {die1} and {die2}"),
+                    ));
+                }
+                let turn = (from_points(&board, variant), DiceRoll::new(die1, die2));
+                let moves = choose_moves(agent, &turn, color, rng)?;
+                connection.send(&ClientMessage::Play { moves })?;
+                own_turn = Some(turn);
+            }
+            ServerMessage::Error { message } => {
+                if print {
+                    println!("The server refused the play: {}", message);
+                }
+                let Some(turn) = &own_turn else {
+                    return Err(io::Error::other(message));
+                };
+                let moves = choose_moves(agent, turn, color, rng)?;
+                connection.send(&ClientMessage::Play { moves })?;
+            }
+            ServerMessage::Played { color, moves } => {
+                if print {
+                    match moves.as_str() {
+                        "" => println!("{} cannot move", color),
+                        moves => println!("{} plays {}", color, moves),
+                    }
+                }
+            }
+            ServerMessage::GameOver {
+                winner,
+                victory,
+                points,
+                ..
+            } => {
+                if print {
+                    println!("{} wins: {:?} ({} points)", winner, victory, points);
+                }
+                return Ok(GameOutcome {
+                    color,
+                    winner,
+                    victory,
+                    points,
+                });
+            }
+            message @ ServerMessage::Welcome { .. } => return Err(unexpected(&message)),
+        }
+    }
+}
+
+fn choose_moves(
+    agent: &dyn TestingAgent,
+    (board, roll): &(BackgammonBoard, DiceRoll),
+    color: PlayerColor,
+    rng: &mut dyn RngCore,
+) -> io::Result<String> {
+    match agent.get_best_action(board, color.raw(), roll, rng) {
+        Some(move_sequence) => Ok(format_move_sequence(board, &move_sequence, color.raw())),
+        None => Err(io::Error::other("the agent has no play for the roll")),
+    }
+}
+
+fn unexpected(message: &ServerMessage) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unexpected message {:?}", message),
+    )
+}
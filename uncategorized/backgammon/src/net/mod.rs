@@ -0,0 +1,43 @@
+//! Games over TCP between two clients and a server owning the game: it rolls the dice
+//! and checks the plays, the clients only send the plays of their player.
+//!
+//! # Protocol
+//!
+//! Each message is a JSON object on its own line, with its kind in `type`. Colors are
+//! `"black"` or `"red"`, boards are the 28 numbers of checkers of `Points` and plays are
+//! written in standard notation, from the point of view of the player making them.
+//!
+//! A client connects and says hello, the first client to do so plays black:
+//!
+//! ```text
+//! -> {"type":"hello","name":"alice"}
+//! <- {"type":"welcome","color":"black","opponent":"bob"}
+//! ```
+//!
+//! Every turn, both clients get the roll of the player on turn and then its play. The
+//! player on turn answers the roll with its play, unless `can_move` is false and its turn
+//! passes with an empty play. A refused play gets an error and the player sends another
+//! one:
+//!
+//! ```text
+//! <- {"type":"turn","board":[0,2,0,...],"turn":"black","dice":[3,1],"can_move":true}
+//! -> {"type":"play","moves":"8/4"}
+//! <- {"type":"error","message":"'8/4' is not a legal play"}
+//! -> {"type":"play","moves":"8/5 6/5"}
+//! <- {"type":"played","color":"black","moves":"8/5 6/5"}
+//! ```
+//!
+//! When a player bears off its last checker both clients get the result and the server
+//! closes the connections:
+//!
+//! ```text
+//! <- {"type":"game_over","board":[...],"winner":"red","victory":"Gammon","points":2}
+//! ```
+
+mod client;
+mod protocol;
+mod server;
+
+pub use client::{connect, GameOutcome};
+pub use protocol::{ClientMessage, Points, ServerMessage};
+pub use server::{serve, serve_on};
@@ -0,0 +1,174 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::TcpStream,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    api::PlayerColor,
+    core::{
+        board::BackgammonBoard,
+        dice::DiceRoll,
+        players::{BLACK_PLAYER, RED_PLAYER},
+        rules::Variant,
+    },
+    game::game::VictoryType,
+};
+
+/// Checkers on each of the 28 positions of the board, negative for black and positive
+/// for red, numbered as in the doc comment of `BackgammonBoard`.
+pub type Points = [i8; 28];
+
+/// Messages sent by the clients.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// first message of a client after connecting
+    Hello { name: String },
+    /// play of the player on turn in standard notation, e.g. `8/5 6/5` or `bar/22*`
+    Play { moves: String },
+}
+
+/// Messages sent by the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// answer to `hello`, with the color of the client, the name of its opponent and the
+    /// variant played, `standard` when missing
+    Welcome {
+        color: PlayerColor,
+        opponent: String,
+        #[serde(default)]
+        variant: Variant,
+    },
+    /// the player on turn rolled, it must send a `play` unless it has no legal play
+    Turn {
+        board: Points,
+        turn: PlayerColor,
+        dice: [u8; 2],
+        /// the player has no legal play, its turn passes without a `play`
+        can_move: bool,
+    },
+    /// play made by a player, empty when it could not move
+    Played { color: PlayerColor, moves: String },
+    /// the `play` was refused, the player on turn must send another one
+    Error { message: String },
+    GameOver {
+        board: Points,
+        winner: PlayerColor,
+        victory: VictoryType,
+        points: i32,
+    },
+}
+
+pub(crate) fn to_points(board: &BackgammonBoard) -> Points {
+    let mut points = [0; 28];
+    for (pos, point) in points.iter_mut().enumerate() {
+        *point =
+            board.checkers_at(RED_PLAYER, pos) as i8 - board.checkers_at(BLACK_PLAYER, pos) as i8;
+    }
+    points
+}
+
+/// The board of `points` in a game of `variant`.
+pub(crate) fn from_points(points: &Points, variant: Variant) -> BackgammonBoard {
+    let pairs = points.iter().copied().enumerate().collect::<Vec<_>>();
+    BackgammonBoard::from_pairs(&pairs).with_variant(variant)
+}
+
+pub(crate) fn to_dice(roll: &DiceRoll) -> [u8; 2] {
+    match *roll {
+        DiceRoll::Single(die1, die2) => [die1, die2],
+        DiceRoll::Double(die) => [die, die],
+    }
+}
+
+/// A TCP stream exchanging one JSON message per line.
+pub(crate) struct Connection {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Connection {
+    pub(crate) fn new(stream: TcpStream) -> io::Result<Self> {
+        Ok(Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+        })
+    }
+
+    pub(crate) fn send<T: Serialize>(&mut self, message: &T) -> io::Result<()> {
+        let mut line = serde_json::to_string(message)?;
+        line.push('\n');
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.flush()
+    }
+
+    pub(crate) fn receive<T: DeserializeOwned>(&mut self) -> io::Result<T> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "the connection was closed",
+            ));
+        }
+        serde_json::from_str(&line).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid message {}: {}", line.trim(), err),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_format() {
+        let message = ServerMessage::Played {
+            color: PlayerColor::Red,
+            moves: "13/7 8/7".to_string(),
+        };
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"played","color":"red","moves":"13/7 8/7"}"#
+        );
+
+        let message: ServerMessage =
+            serde_json::from_str(r#"{"type":"welcome","color":"black","opponent":"bot"}"#).unwrap();
+        assert_eq!(
+            message,
+            ServerMessage::Welcome {
+                color: PlayerColor::Black,
+                opponent: "bot".to_string(),
+                variant: Variant::Standard,
+            }
+        );
+
+        let message: ClientMessage =
+            serde_json::from_str(r#"{"type":"play","moves":"8/5 6/5"}"#).unwrap();
+        assert_eq!(
+            message,
+            ClientMessage::Play {
+                moves: "8/5 6/5".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_points_round_trip() {
+        let board = BackgammonBoard::default_board();
+        let points = to_points(&board);
+
+        assert_eq!(points[6], -5);
+        assert_eq!(points[19], 5);
+        assert_eq!(from_points(&points, Variant::Standard), board);
+
+        let board = Variant::Hypergammon.starting_board();
+        assert_eq!(from_points(&to_points(&board), Variant::Hypergammon), board);
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    io,
+    net::{TcpListener, ToSocketAddrs},
+};
+
+use crate::{
+    agents::human::match_move_sequence,
+    api::PlayerColor,
+    core::{
+        board::BackgammonBoard,
+        moves::{generate_all_move_sequences, MoveSequence},
+        players::{get_opponent, BLACK_PLAYER},
+        rules::Variant,
+    },
+    game::{
+        game::{get_victory_type, get_winner},
+        notation::format_move_sequence,
+        record::{GameRecord, TurnRecord},
+        rng::GameRng,
+    },
+};
+
+use super::protocol::{to_dice, to_points, ClientMessage, Connection, ServerMessage};
+
+struct Player {
+    connection: Connection,
+    name: String,
+}
+
+/// Hosts a game on `addr`, see `serve`.
+pub fn serve_on<A: ToSocketAddrs>(
+    addr: A,
+    rng: &mut GameRng,
+    variant: Variant,
+) -> io::Result<GameRecord> {
+    serve(&TcpListener::bind(addr)?, rng, variant)
+}
+
+/// Hosts a game between the first two clients connecting to `listener`, the first one
+/// playing black, and returns its record. The server rolls the dice with `rng` and checks
+/// every play against the legal plays of the roll, starting as `variant` does.
+pub fn serve(
+    listener: &TcpListener,
+    rng: &mut GameRng,
+    variant: Variant,
+) -> io::Result<GameRecord> {
+    let mut black = accept(listener)?;
+    let mut red = accept(listener)?;
+    black.connection.send(&ServerMessage::Welcome {
+        color: PlayerColor::Black,
+        opponent: red.name.clone(),
+        variant,
+    })?;
+    red.connection.send(&ServerMessage::Welcome {
+        color: PlayerColor::Red,
+        opponent: black.name.clone(),
+        variant,
+    })?;
+
+    let mut board = variant.starting_board();
+    let mut player_color = rng.first_player();
+    let mut roll = rng.opening_roll(variant.opening_roll());
+    let mut record = GameRecord::new(rng.seed(), board.clone(), player_color);
+    loop {
+        let move_sequences = generate_all_move_sequences(&board, player_color, &roll);
+        let color = PlayerColor::from_raw(player_color);
+        let turn = ServerMessage::Turn {
+            board: to_points(&board),
+            turn: color,
+            dice: to_dice(&roll),
+            can_move: !move_sequences.is_empty(),
+        };
+        black.connection.send(&turn)?;
+        red.connection.send(&turn)?;
+
+        let player = if player_color == BLACK_PLAYER {
+            &mut black
+        } else {
+            &mut red
+        };
+        let move_sequence = if move_sequences.is_empty() {
+            None
+        } else {
+            Some(receive_play(player, &board, color, &move_sequences)?)
+        };
+
+        let played = ServerMessage::Played {
+            color,
+            moves: move_sequence
+                .as_ref()
+                .map(|ms| format_move_sequence(&board, ms, player_color))
+                .unwrap_or_default(),
+        };
+        black.connection.send(&played)?;
+        red.connection.send(&played)?;
+        record.turns.push(TurnRecord {
+            player_color,
+            doubled: false,
+            roll,
+            move_sequence: move_sequence.clone(),
+        });
+
+        if let Some(move_sequence) = &move_sequence {
+            board = board.apply_move_sequence(move_sequence, player_color);
+
+            if let Some(winner) = get_winner(&board) {
+                let victory_type = get_victory_type(&board, winner);
+                record.winner = Some(winner);
+                record.victory_type = Some(victory_type);
+
+                let game_over = ServerMessage::GameOver {
+                    board: to_points(&board),
+                    winner: PlayerColor::from_raw(winner),
+                    victory: victory_type,
+                    points: victory_type.value(),
+                };
+                black.connection.send(&game_over)?;
+                red.connection.send(&game_over)?;
+                return Ok(record);
+            }
+        }
+
+        player_color = get_opponent(player_color);
+        roll = rng.roll_dice();
+    }
+}
+
+/// Waits for a client saying hello, dropping the ones starting with anything else.
+fn accept(listener: &TcpListener) -> io::Result<Player> {
+    loop {
+        let (stream, _) = listener.accept()?;
+        let mut connection = Connection::new(stream)?;
+        match connection.receive() {
+            Ok(ClientMessage::Hello { name }) => return Ok(Player { connection, name }),
+            Ok(ClientMessage::Play { .. }) | Err(_) => {
+                let _ = connection.send(&ServerMessage::Error {
+                    message: "expected hello".to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Waits for a legal play of the player on turn, answering the other messages with errors.
+fn receive_play(
+    player: &mut Player,
+    board: &BackgammonBoard,
+    color: PlayerColor,
+    move_sequences: &[MoveSequence],
+) -> io::Result<MoveSequence> {
+    loop {
+        let message = match player.connection.receive()? {
+            ClientMessage::Play { moves } => {
+                match match_move_sequence(board, color.raw(), move_sequences, &moves) {
+                    Ok(move_sequence) => return Ok(move_sequence),
+                    Err(message) => message,
+                }
+            }
+            ClientMessage::Hello { .. } => format!("{} already joined", player.name),
+        };
+        player.connection.send(&ServerMessage::Error { message })?;
+    }
+}
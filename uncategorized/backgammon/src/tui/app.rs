@@ -0,0 +1,523 @@
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    Frame,
+};
+
+use crate::{
+    agents::agent::TestingAgent,
+    core::{
+        board::BackgammonBoard,
+        dice::DiceRoll,
+        moves::MoveSequence,
+        players::{get_opponent, PlayerColor, BLACK_PLAYER},
+        positions::{bar_pos, Position},
+        rules::Variant,
+        scores::get_pip_count,
+    },
+    game::{
+        game::{get_victory_type, get_winner},
+        notation::{format_move_sequence, format_roll},
+        record::{GameRecord, TurnRecord},
+        rng::GameRng,
+    },
+};
+
+use super::{
+    board_view::{BoardView, BOARD_HEIGHT, BOARD_WIDTH, BOTTOM_ROW, TOP_ROW},
+    input::PlayInput,
+};
+
+/// Who plays a color: a person at the keyboard or a bot.
+pub enum Seat {
+    Human,
+    Bot(Box<dyn TestingAgent>),
+}
+
+/// State of a game in the terminal, updated by the keys pressed and the plays of the bots.
+pub(crate) struct App<'a> {
+    black: Seat,
+    red: Seat,
+    hint_agent: Option<Box<dyn TestingAgent>>,
+    rng: &'a mut GameRng,
+    board: BackgammonBoard,
+    player_color: PlayerColor,
+    roll: DiceRoll,
+    record: GameRecord,
+    /// play being entered when a person is on turn
+    input: Option<PlayInput>,
+    /// row and column of the cursor in the layout of `BoardView`
+    cursor: (usize, usize),
+    log: Vec<Line<'static>>,
+    message: String,
+    paused: bool,
+    quit: bool,
+}
+
+impl<'a> App<'a> {
+    pub(crate) fn new(
+        black: Seat,
+        red: Seat,
+        hint_agent: Option<Box<dyn TestingAgent>>,
+        variant: Variant,
+        rng: &'a mut GameRng,
+    ) -> Self {
+        let board = variant.starting_board();
+        let player_color = rng.first_player();
+        let roll = rng.opening_roll(variant.opening_roll());
+        let record = GameRecord::new(rng.seed(), board.clone(), player_color);
+
+        let mut app = Self {
+            black,
+            red,
+            hint_agent,
+            rng,
+            board,
+            player_color,
+            roll,
+            record,
+            input: None,
+            cursor: (1, 4),
+            log: Vec::new(),
+            message: String::new(),
+            paused: false,
+            quit: false,
+        };
+        app.start_turn();
+        app
+    }
+
+    fn seat(&self, player_color: PlayerColor) -> &Seat {
+        if player_color == BLACK_PLAYER {
+            &self.black
+        } else {
+            &self.red
+        }
+    }
+
+    fn is_human(&self, player_color: PlayerColor) -> bool {
+        matches!(self.seat(player_color), Seat::Human)
+    }
+
+    pub(crate) fn is_over(&self) -> bool {
+        self.record.winner.is_some()
+    }
+
+    pub(crate) fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Whether nothing happens until a key is pressed: a person is entering a play, the
+    /// bots are paused or the game is over.
+    pub(crate) fn waits_for_key(&self) -> bool {
+        self.is_over()
+            || self.paused
+            || self
+                .input
+                .as_ref()
+                .is_some_and(|input| !input.plays().is_empty())
+    }
+
+    pub(crate) fn into_record(self) -> GameRecord {
+        self.record
+    }
+
+    fn start_turn(&mut self) {
+        self.input = if self.is_human(self.player_color) {
+            Some(PlayInput::new(&self.board, self.player_color, &self.roll))
+        } else {
+            None
+        };
+    }
+
+    /// Plays the turn of a bot, or passes the turn of a person without legal plays.
+    pub(crate) fn step(&mut self) {
+        if self.is_over() {
+            return;
+        }
+
+        let seat = if self.player_color == BLACK_PLAYER {
+            &self.black
+        } else {
+            &self.red
+        };
+        let move_sequence = match seat {
+            Seat::Human => None,
+            Seat::Bot(agent) => agent.get_best_action(
+                &self.board,
+                self.player_color,
+                &self.roll,
+                self.rng.agent_rng(),
+            ),
+        };
+        self.end_turn(move_sequence);
+    }
+
+    fn end_turn(&mut self, move_sequence: Option<MoveSequence>) {
+        self.log_turn(move_sequence.as_ref());
+        self.record.turns.push(TurnRecord {
+            player_color: self.player_color,
+            doubled: false,
+            roll: self.roll,
+            move_sequence: move_sequence.clone(),
+        });
+        self.input = None;
+
+        if let Some(move_sequence) = &move_sequence {
+            self.board = self
+                .board
+                .apply_move_sequence(move_sequence, self.player_color);
+
+            if let Some(winner) = get_winner(&self.board) {
+                let victory_type = get_victory_type(&self.board, winner);
+                self.record.winner = Some(winner);
+                self.record.victory_type = Some(victory_type);
+                self.message = format!(
+                    "{} wins: {:?} ({} points)",
+                    player_name(winner),
+                    victory_type,
+                    victory_type.value()
+                );
+                return;
+            }
+        }
+
+        self.player_color = get_opponent(self.player_color);
+        self.roll = self.rng.roll_dice();
+        self.start_turn();
+    }
+
+    fn log_turn(&mut self, move_sequence: Option<&MoveSequence>) {
+        let moves = match move_sequence {
+            Some(move_sequence) => {
+                format_move_sequence(&self.board, move_sequence, self.player_color)
+            }
+            None => "cannot move".to_string(),
+        };
+        self.log.push(Line::from(vec![
+            format!("{:>3} ", self.record.turns.len() + 1).dark_gray(),
+            player_span(self.player_color),
+            format!(" {}: {}", format_roll(&self.roll), moves).into(),
+        ]));
+    }
+
+    pub(crate) fn on_key(&mut self, key: KeyEvent) {
+        if self.is_over() {
+            self.quit = true;
+            return;
+        }
+        if let KeyCode::Char('q') = key.code {
+            self.quit = true;
+            return;
+        }
+
+        match self.input.take() {
+            Some(input) => self.on_play_key(input, key.code),
+            None => match key.code {
+                KeyCode::Char(' ') => self.paused = !self.paused,
+                KeyCode::Char('n') if self.paused => self.step(),
+                _ => {}
+            },
+        }
+    }
+
+    fn on_play_key(&mut self, mut input: PlayInput, code: KeyCode) {
+        self.message.clear();
+        let column = self.cursor.1;
+        match code {
+            KeyCode::Left => self.cursor.1 = column.saturating_sub(1),
+            KeyCode::Right => self.cursor.1 = (column + 1).min(TOP_ROW.len() - 1),
+            KeyCode::Up => self.cursor.0 = 0,
+            KeyCode::Down => self.cursor.0 = 1,
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                // the checkers on the bar are picked wherever the cursor is
+                let bar = bar_pos(self.player_color);
+                let pos = if input.selected().is_none()
+                    && input.board().checkers_at(self.player_color, bar) > 0
+                {
+                    bar
+                } else {
+                    self.cursor_position()
+                };
+                if let Err(err) = input.select(pos) {
+                    self.message = err;
+                }
+            }
+            KeyCode::Backspace | KeyCode::Char('u') => input.undo(),
+            KeyCode::Esc if input.selected().is_some() => input.undo(),
+            KeyCode::Char('?') | KeyCode::Char('h') => self.message = self.hint(),
+            _ => {}
+        }
+
+        match input.play().cloned() {
+            Some(play) => self.end_turn(Some(play)),
+            None => self.input = Some(input),
+        }
+    }
+
+    fn hint(&mut self) -> String {
+        let Some(hint_agent) = &self.hint_agent else {
+            return "No hints available".to_string();
+        };
+        match hint_agent.get_best_action(
+            &self.board,
+            self.player_color,
+            &self.roll,
+            self.rng.hint_rng(),
+        ) {
+            Some(hint) => format!(
+                "Hint: {}",
+                format_move_sequence(&self.board, &hint, self.player_color)
+            ),
+            None => "No legal play".to_string(),
+        }
+    }
+
+    fn cursor_position(&self) -> Position {
+        let (row, column) = self.cursor;
+        if row == 0 {
+            TOP_ROW[column]
+        } else {
+            BOTTOM_ROW[column]
+        }
+    }
+
+    /// Player the points are numbered for: the person on turn or else the one at the
+    /// keyboard, black when watching bots.
+    fn view_color(&self) -> PlayerColor {
+        let opponent = get_opponent(self.player_color);
+        if !self.is_human(self.player_color) && self.is_human(opponent) {
+            opponent
+        } else if self.is_human(self.player_color) {
+            self.player_color
+        } else {
+            BLACK_PLAYER
+        }
+    }
+
+    pub(crate) fn draw(&self, frame: &mut Frame) {
+        let [main, help] = Layout::vertical([Constraint::Min(BOARD_HEIGHT), Constraint::Length(1)])
+            .areas(frame.area());
+        let [left, right] =
+            Layout::horizontal([Constraint::Length(BOARD_WIDTH), Constraint::Min(30)]).areas(main);
+        let [board_area, message_area] =
+            Layout::vertical([Constraint::Length(BOARD_HEIGHT), Constraint::Min(0)]).areas(left);
+        let [status_area, log_area] =
+            Layout::vertical([Constraint::Length(6), Constraint::Min(3)]).areas(right);
+
+        let targets = self.targets();
+        let view = match &self.input {
+            Some(input) => BoardView {
+                cursor: Some(self.cursor_position()),
+                selected: input.selected(),
+                targets: &targets,
+                ..BoardView::new(input.board(), self.view_color())
+            },
+            None => BoardView::new(&self.board, self.view_color()),
+        };
+        frame.render_widget(view, board_area);
+        frame.render_widget(
+            Paragraph::new(self.message.as_str()).style(Style::default().bold()),
+            message_area,
+        );
+        frame.render_widget(self.status(), status_area);
+
+        // the latest plays that fit
+        let height = log_area.height.saturating_sub(2) as usize;
+        let log = self.log[self.log.len().saturating_sub(height)..].to_vec();
+        frame.render_widget(
+            Paragraph::new(log).block(Block::bordered().title(" Moves ")),
+            log_area,
+        );
+        frame.render_widget(Paragraph::new(self.help()).dark_gray(), help);
+    }
+
+    fn targets(&self) -> Vec<Position> {
+        match &self.input {
+            Some(input) => input
+                .selected()
+                .map(|from| input.destinations(from).iter().map(|m| m.dst).collect())
+                .unwrap_or_default(),
+            None => Vec::new(),
+        }
+    }
+
+    fn status(&self) -> Paragraph<'static> {
+        let turn = if self.is_over() {
+            Line::from("Game over")
+        } else {
+            Line::from(vec![player_span(self.player_color), " to play".into()])
+        };
+
+        // the dice played are dimmed
+        let mut left = self
+            .input
+            .as_ref()
+            .map(|input| input.dice().to_vec())
+            .unwrap_or_else(|| self.roll.dice());
+        let dice = self
+            .roll
+            .dice()
+            .into_iter()
+            .map(|die| {
+                let style = match left.iter().position(|&d| d == die) {
+                    Some(i) => {
+                        left.remove(i);
+                        Style::default().bold()
+                    }
+                    None => Style::default().add_modifier(Modifier::DIM | Modifier::CROSSED_OUT),
+                };
+                Span::styled(format!("[{die}]"), style)
+            })
+            .collect::<Vec<_>>();
+
+        let board = self
+            .input
+            .as_ref()
+            .map_or(&self.board, |input| input.board());
+        let pips = format!(
+            "Pips: black {}, red {}",
+            get_pip_count(board, BLACK_PLAYER),
+            get_pip_count(board, get_opponent(BLACK_PLAYER))
+        );
+        let state = if self.paused { " (paused)" } else { "" };
+
+        Paragraph::new(vec![
+            turn,
+            Line::from([vec!["Dice: ".into()], dice].concat()),
+            Line::from(pips),
+            Line::from(format!("Turn {}{}", self.record.turns.len() + 1, state)),
+        ])
+        .block(Block::bordered().title(" Game "))
+    }
+
+    fn help(&self) -> &'static str {
+        if self.is_over() {
+            "any key: quit"
+        } else if self.input.is_some() {
+            "arrows: move cursor | enter: select | u: undo | h: hint | q: quit"
+        } else if self.paused {
+            "space: resume | n: next play | q: quit"
+        } else {
+            "space: pause | q: quit"
+        }
+    }
+}
+
+fn player_name(player_color: PlayerColor) -> &'static str {
+    if player_color == BLACK_PLAYER {
+        "Black"
+    } else {
+        "Red"
+    }
+}
+
+fn player_span(player_color: PlayerColor) -> Span<'static> {
+    let color = if player_color == BLACK_PLAYER {
+        Color::Gray
+    } else {
+        Color::Red
+    };
+    Span::styled(
+        format!("● {}", player_name(player_color)),
+        Style::default().fg(color).bold(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyModifiers;
+
+    use crate::agents::random::RandomAgent;
+
+    use super::*;
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.on_key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_watches_bots_to_the_end() {
+        let mut rng = GameRng::from_seed(5);
+        let mut app = App::new(
+            Seat::Bot(Box::new(RandomAgent::new())),
+            Seat::Bot(Box::new(RandomAgent::new())),
+            None,
+            Variant::Standard,
+            &mut rng,
+        );
+
+        assert!(!app.waits_for_key());
+        press(&mut app, KeyCode::Char(' '));
+        assert!(app.waits_for_key());
+        press(&mut app, KeyCode::Char('n'));
+        assert_eq!(app.record.turns.len(), 1);
+        press(&mut app, KeyCode::Char(' '));
+
+        while !app.is_over() {
+            app.step();
+        }
+        assert!(app.waits_for_key());
+        assert_eq!(app.log.len(), app.record.turns.len());
+        let record = app.into_record();
+        let winner = record.winner.unwrap();
+        assert_eq!(get_winner(record.positions().last().unwrap()), Some(winner));
+    }
+
+    #[test]
+    fn test_starts_from_the_variant() {
+        let mut rng = GameRng::from_seed(5);
+        let app = App::new(
+            Seat::Human,
+            Seat::Human,
+            None,
+            Variant::Hypergammon,
+            &mut rng,
+        );
+
+        assert_eq!(app.board, Variant::Hypergammon.starting_board());
+        assert_eq!(app.record.initial_board, app.board);
+        assert!(matches!(app.roll, DiceRoll::Single(_, _)));
+    }
+
+    #[test]
+    fn test_plays_with_the_keyboard() {
+        let seed = (0..)
+            .find(|&seed| GameRng::from_seed(seed).first_player() == BLACK_PLAYER)
+            .unwrap();
+        let mut rng = GameRng::with_recorded_rolls(seed, vec![DiceRoll::Single(3, 1)]);
+        let mut app = App::new(Seat::Human, Seat::Human, None, Variant::Standard, &mut rng);
+        assert!(app.waits_for_key());
+
+        // the cursor starts on the 8 point, 8/5 6/5
+        let keys = [
+            [KeyCode::Enter].as_slice(),
+            &[KeyCode::Right; 4],
+            &[
+                KeyCode::Enter,
+                KeyCode::Left,
+                KeyCode::Enter,
+                KeyCode::Right,
+                KeyCode::Enter,
+            ],
+        ];
+        for &code in keys.concat().iter() {
+            press(&mut app, code);
+        }
+
+        assert_eq!(app.player_color, get_opponent(BLACK_PLAYER));
+        let turn = &app.record.turns[0];
+        assert_eq!(turn.player_color, BLACK_PLAYER);
+        assert_eq!(
+            format_move_sequence(
+                &BackgammonBoard::default_board(),
+                turn.move_sequence.as_ref().unwrap(),
+                BLACK_PLAYER
+            ),
+            "8/5 6/5"
+        );
+    }
+}
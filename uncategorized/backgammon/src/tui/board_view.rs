@@ -0,0 +1,228 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::{
+    core::{
+        board::BackgammonBoard,
+        players::{PlayerColor, BLACK_PLAYER, RED_PLAYER},
+        positions::{Position, BLACK_BAR, BLACK_OFF, RED_BAR, RED_OFF},
+    },
+    game::notation::point_number,
+};
+
+/// Columns of the board from left to right, laid out as in the doc comment of
+/// `BackgammonBoard`: the points, the bar in the middle and the bear-off tray on the right.
+pub(crate) const TOP_ROW: [Position; 14] = [
+    13, 14, 15, 16, 17, 18, RED_BAR, 19, 20, 21, 22, 23, 24, RED_OFF,
+];
+pub(crate) const BOTTOM_ROW: [Position; 14] =
+    [12, 11, 10, 9, 8, 7, BLACK_BAR, 6, 5, 4, 3, 2, 1, BLACK_OFF];
+
+/// Checkers drawn on a point, the last one shows the count of the taller stacks.
+const CHECKER_ROWS: usize = 5;
+const POINT_WIDTH: usize = 4;
+const TRAY_WIDTH: usize = 5;
+
+/// Width and height of the board, borders included.
+pub(crate) const BOARD_WIDTH: u16 = (12 * POINT_WIDTH + 2 * TRAY_WIDTH + 2) as u16;
+pub(crate) const BOARD_HEIGHT: u16 = (2 * CHECKER_ROWS + 3 + 2) as u16;
+
+const BOARD_COLOR: Color = Color::Indexed(180);
+const TRAY_COLOR: Color = Color::Indexed(94);
+const BLACK_CHECKER_COLOR: Color = Color::Black;
+const RED_CHECKER_COLOR: Color = Color::Red;
+const CURSOR_COLOR: Color = Color::Yellow;
+const SELECTED_COLOR: Color = Color::Green;
+const TARGET_COLOR: Color = Color::LightGreen;
+
+/// Widget drawing the checkers of a board, with the point under the cursor, the
+/// selected point and the points it can move to highlighted.
+pub(crate) struct BoardView<'a> {
+    pub board: &'a BackgammonBoard,
+    /// the points are numbered from the side of this player
+    pub player_color: PlayerColor,
+    pub cursor: Option<Position>,
+    pub selected: Option<Position>,
+    pub targets: &'a [Position],
+}
+
+impl<'a> BoardView<'a> {
+    pub(crate) fn new(board: &'a BackgammonBoard, player_color: PlayerColor) -> Self {
+        Self {
+            board,
+            player_color,
+            cursor: None,
+            selected: None,
+            targets: &[],
+        }
+    }
+
+    fn column_style(&self, pos: Position) -> Style {
+        let background = if self.selected == Some(pos) {
+            SELECTED_COLOR
+        } else if self.cursor == Some(pos) {
+            CURSOR_COLOR
+        } else if self.targets.contains(&pos) {
+            TARGET_COLOR
+        } else if is_tray(pos) {
+            TRAY_COLOR
+        } else {
+            BOARD_COLOR
+        };
+        Style::default().bg(background)
+    }
+
+    fn label(&self, pos: Position) -> Span<'static> {
+        let label = match pos {
+            BLACK_BAR | RED_BAR => "bar".to_string(),
+            BLACK_OFF | RED_OFF => "off".to_string(),
+            pos => point_number(pos, self.player_color).to_string(),
+        };
+        Span::styled(cell(&label, pos), self.column_style(pos).fg(Color::Black))
+    }
+
+    /// The checker of `pos` at `row`, counted from the edge of the board.
+    fn checker(&self, pos: Position, row: usize) -> Span<'static> {
+        let (count, color) = match self.board.checkers_at(BLACK_PLAYER, pos) {
+            0 => (self.board.checkers_at(RED_PLAYER, pos), RED_CHECKER_COLOR),
+            count => (count, BLACK_CHECKER_COLOR),
+        };
+
+        let text = if row + 1 == CHECKER_ROWS && count > CHECKER_ROWS {
+            count.to_string()
+        } else if row < count {
+            "●".to_string()
+        } else {
+            String::new()
+        };
+        Span::styled(
+            cell(&text, pos),
+            self.column_style(pos)
+                .fg(color)
+                .add_modifier(Modifier::BOLD),
+        )
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        let row = |columns: &[Position], span: &dyn Fn(Position) -> Span<'static>| {
+            Line::from(columns.iter().map(|&pos| span(pos)).collect::<Vec<_>>())
+        };
+
+        let mut lines = vec![row(&TOP_ROW, &|pos| self.label(pos))];
+        for i in 0..CHECKER_ROWS {
+            lines.push(row(&TOP_ROW, &|pos| self.checker(pos, i)));
+        }
+        lines.push(row(&TOP_ROW, &|pos| {
+            Span::styled(" ".repeat(width(pos)), self.column_style(pos))
+        }));
+        for i in (0..CHECKER_ROWS).rev() {
+            lines.push(row(&BOTTOM_ROW, &|pos| self.checker(pos, i)));
+        }
+        lines.push(row(&BOTTOM_ROW, &|pos| self.label(pos)));
+        lines
+    }
+}
+
+impl Widget for BoardView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Paragraph::new(self.lines())
+            .block(Block::bordered().title(" Board "))
+            .render(area, buf);
+    }
+}
+
+fn is_tray(pos: Position) -> bool {
+    matches!(pos, BLACK_BAR | RED_BAR | BLACK_OFF | RED_OFF)
+}
+
+/// `text` right-aligned in the column of `pos`, leaving a space before the next column.
+fn cell(text: &str, pos: Position) -> String {
+    format!("{:>1$} ", text, width(pos) - 1)
+}
+
+fn width(pos: Position) -> usize {
+    if is_tray(pos) {
+        TRAY_WIDTH
+    } else {
+        POINT_WIDTH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::players::{b, r};
+
+    use super::*;
+
+    fn render(view: BoardView) -> Buffer {
+        let area = Rect::new(0, 0, BOARD_WIDTH, BOARD_HEIGHT);
+        let mut buf = Buffer::empty(area);
+        view.render(area, &mut buf);
+        buf
+    }
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (1..BOARD_WIDTH - 1).map(|x| buf[(x, y)].symbol()).collect()
+    }
+
+    #[test]
+    fn test_draws_the_starting_position() {
+        let board = BackgammonBoard::default_board();
+        let buf = render(BoardView::new(&board, BLACK_PLAYER));
+
+        assert_eq!(
+            row(&buf, 1),
+            " 13  14  15  16  17  18  bar  19  20  21  22  23  24  off "
+        );
+        assert_eq!(
+            row(&buf, 2),
+            "  ●               ●            ●                   ●      "
+        );
+        assert_eq!(
+            row(&buf, BOARD_HEIGHT - 2),
+            " 12  11  10   9   8   7  bar   6   5   4   3   2   1  off "
+        );
+        // black on the 13 point, red on the 12 and 1 points
+        assert_eq!(buf[(3, 2)].fg, BLACK_CHECKER_COLOR);
+        assert_eq!(buf[(3, BOARD_HEIGHT - 3)].fg, RED_CHECKER_COLOR);
+        assert_eq!(buf[(52, BOARD_HEIGHT - 3)].fg, RED_CHECKER_COLOR);
+    }
+
+    #[test]
+    fn test_counts_the_tall_stacks() {
+        let board = BackgammonBoard::from_pairs(&[(6, b(9)), (BLACK_OFF, b(6)), (RED_OFF, r(15))]);
+        let buf = render(BoardView::new(&board, RED_PLAYER));
+
+        // the points are numbered from the side of red
+        assert!(row(&buf, 1).starts_with(" 12  11  10   9"));
+        assert_eq!(row(&buf, 6), format!("{}  15 ", " ".repeat(53)));
+        assert_eq!(
+            row(&buf, 8),
+            format!("{}  9 {}   6 ", " ".repeat(29), " ".repeat(20))
+        );
+    }
+
+    #[test]
+    fn test_highlights_the_selection() {
+        let board = BackgammonBoard::default_board();
+        let view = BoardView {
+            cursor: Some(13),
+            selected: Some(8),
+            targets: &[5, 7],
+            ..BoardView::new(&board, BLACK_PLAYER)
+        };
+        let buf = render(view);
+
+        assert_eq!(buf[(2, 3)].bg, CURSOR_COLOR);
+        assert_eq!(buf[(18, BOARD_HEIGHT - 3)].bg, SELECTED_COLOR);
+        assert_eq!(buf[(22, BOARD_HEIGHT - 3)].bg, TARGET_COLOR);
+        assert_eq!(buf[(35, 1)].bg, BOARD_COLOR);
+        assert_eq!(buf[(35, BOARD_HEIGHT - 3)].bg, TARGET_COLOR);
+        assert_eq!(buf[(6, 3)].bg, BOARD_COLOR);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    core::{
+        board::BackgammonBoard,
+        dice::{DiceRoll, Die},
+        movegen::reachable_positions,
+        moves::{generate_all_move_sequences, MoveSequence, MoveWithDie},
+        players::{get_opponent, PlayerColor},
+        positions::Position,
+    },
+    game::notation::{point_number, point_position},
+};
+
+/// Play entered by a person a checker at a time: a point with a checker is selected and
+/// then the point it moves to, a die at a time.
+///
+/// A move is accepted when the legal plays of the roll can still be reached with the
+/// dice left, whatever the order the person plays the dice in, and the play is done once
+/// the board is the one left by a legal play.
+pub(crate) struct PlayInput {
+    player_color: PlayerColor,
+    plays: Vec<MoveSequence>,
+    /// boards left by `plays`, in the same order
+    targets: Vec<BackgammonBoard>,
+    start: BackgammonBoard,
+    board: BackgammonBoard,
+    moves: Vec<MoveWithDie>,
+    dice: Vec<Die>,
+    selected: Option<Position>,
+}
+
+impl PlayInput {
+    pub(crate) fn new(board: &BackgammonBoard, player_color: PlayerColor, roll: &DiceRoll) -> Self {
+        let plays = generate_all_move_sequences(board, player_color, roll);
+        let targets = plays
+            .iter()
+            .map(|play| board.apply_move_sequence(play, player_color))
+            .collect();
+        let mut dice = roll.dice();
+        dice.sort_unstable();
+
+        Self {
+            player_color,
+            plays,
+            targets,
+            start: board.clone(),
+            board: board.clone(),
+            moves: Vec::new(),
+            dice,
+            selected: None,
+        }
+    }
+
+    pub(crate) fn plays(&self) -> &[MoveSequence] {
+        &self.plays
+    }
+
+    /// Board with the moves entered so far.
+    pub(crate) fn board(&self) -> &BackgammonBoard {
+        &self.board
+    }
+
+    /// Dice not played yet, from the lowest.
+    pub(crate) fn dice(&self) -> &[Die] {
+        &self.dice
+    }
+
+    pub(crate) fn selected(&self) -> Option<Position> {
+        self.selected
+    }
+
+    /// Moves of the checker on `from`, with the lowest die when several bear it off.
+    pub(crate) fn destinations(&self, from: Position) -> Vec<MoveWithDie> {
+        // the checkers borne off are done
+        if point_number(from, self.player_color) == 0
+            || self.board.checkers_at(self.player_color, from) == 0
+        {
+            return Vec::new();
+        }
+
+        let mut dice = self.dice.clone();
+        dice.dedup();
+
+        let mut moves: Vec<MoveWithDie> = Vec::new();
+        for die in dice {
+            let m = MoveWithDie {
+                src: from,
+                dst: point_position(
+                    point_number(from, self.player_color).saturating_sub(die as Position),
+                    self.player_color,
+                ),
+                die,
+            };
+            if !moves.iter().any(|other| other.dst == m.dst) && self.is_on_track(&m) {
+                moves.push(m);
+            }
+        }
+        moves
+    }
+
+    /// Whether a legal play can still be reached after `m`.
+    fn is_on_track(&self, m: &MoveWithDie) -> bool {
+        let opponent = get_opponent(self.player_color);
+        if point_number(m.dst, self.player_color) > 0 && self.board.checkers_at(opponent, m.dst) > 1
+        {
+            return false;
+        }
+
+        let board = self.board.apply_n_moves(m, self.player_color, 1);
+        let dice = self.dice_without(m.die);
+        reachable_positions(&board, self.player_color, &dice)
+            .iter()
+            .any(|position| self.targets.contains(position))
+    }
+
+    fn dice_without(&self, die: Die) -> Vec<Die> {
+        let mut dice = self.dice.clone();
+        if let Some(i) = dice.iter().position(|&d| d == die) {
+            dice.remove(i);
+        }
+        dice
+    }
+
+    /// Selects the point with the checker to move, or moves the selected checker to `pos`.
+    /// Selecting the selected point again unselects it.
+    pub(crate) fn select(&mut self, pos: Position) -> Result<(), String> {
+        match self.selected {
+            Some(from) if from == pos => {
+                self.selected = None;
+                Ok(())
+            }
+            Some(from) => match self.destinations(from).into_iter().find(|m| m.dst == pos) {
+                Some(m) => {
+                    self.board = self.board.apply_n_moves(&m, self.player_color, 1);
+                    self.dice = self.dice_without(m.die);
+                    self.moves.push(m);
+                    self.selected = None;
+                    Ok(())
+                }
+                None => Err("the checker cannot move there".to_string()),
+            },
+            None if self.destinations(pos).is_empty() => {
+                Err("no checker can be moved from there".to_string())
+            }
+            None => {
+                self.selected = Some(pos);
+                Ok(())
+            }
+        }
+    }
+
+    /// Takes back the selection or else the last move.
+    pub(crate) fn undo(&mut self) {
+        if self.selected.take().is_some() {
+            return;
+        }
+        if let Some(m) = self.moves.pop() {
+            self.board = self.moves.iter().fold(self.start.clone(), |board, m| {
+                board.apply_n_moves(m, self.player_color, 1)
+            });
+            self.dice.push(m.die);
+            self.dice.sort_unstable();
+        }
+    }
+
+    /// The legal play entered, once it is complete.
+    pub(crate) fn play(&self) -> Option<&MoveSequence> {
+        self.targets
+            .iter()
+            .position(|target| *target == self.board)
+            .map(|i| &self.plays[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{
+        players::{b, r, BLACK_PLAYER, RED_PLAYER},
+        positions::{BLACK_OFF, RED_BAR, RED_OFF},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_enters_a_play_in_any_order() {
+        let board = BackgammonBoard::default_board();
+        let mut input = PlayInput::new(&board, BLACK_PLAYER, &DiceRoll::Single(3, 1));
+
+        // 6/5 8/5, while the play is generated as 8/5 6/5
+        for pos in [6, 5, 8] {
+            input.select(pos).unwrap();
+            assert!(input.play().is_none());
+        }
+        assert_eq!(input.dice(), &[3]);
+        input.select(5).unwrap();
+
+        let play = input.play().unwrap();
+        assert_eq!(
+            &board.apply_move_sequence(play, BLACK_PLAYER),
+            input.board()
+        );
+        assert_eq!(input.board().checkers_at(BLACK_PLAYER, 5), 2);
+    }
+
+    #[test]
+    fn test_only_moves_leading_to_a_legal_play() {
+        // black must play the 6 with its last checker, red holds the 1 point
+        let board = BackgammonBoard::from_pairs(&[
+            (9, b(1)),
+            (BLACK_OFF, b(14)),
+            (1, r(2)),
+            (RED_OFF, r(13)),
+        ]);
+        let mut input = PlayInput::new(&board, BLACK_PLAYER, &DiceRoll::Single(6, 2));
+
+        let destinations = input.destinations(9);
+        assert_eq!(destinations.len(), 1);
+        assert_eq!(destinations[0].dst, 3);
+        assert!(input.select(1).is_err());
+        assert!(input.destinations(BLACK_OFF).is_empty());
+
+        input.select(9).unwrap();
+        assert!(input.select(7).is_err());
+        input.select(3).unwrap();
+        assert!(input.play().is_some());
+    }
+
+    #[test]
+    fn test_bears_off_with_the_lowest_die() {
+        let board = BackgammonBoard::from_pairs(&[(2, b(2)), (BLACK_OFF, b(13)), (RED_OFF, r(15))]);
+        let input = PlayInput::new(&board, BLACK_PLAYER, &DiceRoll::Single(6, 5));
+
+        let destinations = input.destinations(2);
+        assert_eq!(destinations.len(), 1);
+        assert_eq!((destinations[0].dst, destinations[0].die), (BLACK_OFF, 5));
+    }
+
+    #[test]
+    fn test_undo_puts_back_the_hit_checker() {
+        let board = BackgammonBoard::from_pairs(&[
+            (8, b(1)),
+            (BLACK_OFF, b(14)),
+            (5, r(1)),
+            (RED_OFF, r(14)),
+        ]);
+        let mut input = PlayInput::new(&board, BLACK_PLAYER, &DiceRoll::Single(3, 1));
+
+        input.select(8).unwrap();
+        input.select(5).unwrap();
+        assert_eq!(input.board().checkers_at(RED_PLAYER, RED_BAR), 1);
+
+        input.select(5).unwrap();
+        input.undo();
+        assert_eq!(input.selected(), None);
+        input.undo();
+        assert_eq!(input.board(), &board);
+        assert_eq!(input.dice(), &[1, 3]);
+    }
+}
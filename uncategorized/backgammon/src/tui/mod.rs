@@ -0,0 +1,73 @@
+//! Terminal front-end drawing the board with ratatui, to watch bots play or to play
+//! against them or hot-seat, selecting the checkers to move with the keyboard.
+
+mod app;
+mod board_view;
+mod input;
+
+use std::{io, time::Duration};
+
+use ratatui::{
+    crossterm::event::{self, Event, KeyEventKind},
+    DefaultTerminal,
+};
+
+use crate::{
+    agents::agent::TestingAgent,
+    core::rules::Variant,
+    game::{record::GameRecord, rng::GameRng},
+};
+
+use app::App;
+pub use app::Seat;
+
+pub struct TuiOptions {
+    /// time before each play of a bot, so it can be followed
+    pub delay: Duration,
+    /// agent giving the hints to the people playing
+    pub hint_agent: Option<Box<dyn TestingAgent>>,
+    /// starting position and opening roll
+    pub variant: Variant,
+}
+
+impl Default for TuiOptions {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(800),
+            hint_agent: None,
+            variant: Variant::Standard,
+        }
+    }
+}
+
+/// Plays a game between `black` and `red` in the terminal and returns its record, without
+/// winner when it is quit before the end.
+pub fn play_tui(
+    black: Seat,
+    red: Seat,
+    rng: &mut GameRng,
+    options: TuiOptions,
+) -> io::Result<GameRecord> {
+    let mut terminal = ratatui::init();
+    let app = App::new(black, red, options.hint_agent, options.variant, rng);
+    let result = run(&mut terminal, app, options.delay);
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal, mut app: App, delay: Duration) -> io::Result<GameRecord> {
+    while !app.should_quit() {
+        terminal.draw(|frame| app.draw(frame))?;
+
+        if app.waits_for_key() || event::poll(delay)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.on_key(key);
+                }
+            }
+        } else {
+            app.step();
+        }
+    }
+    Ok(app.into_record())
+}
@@ -0,0 +1,150 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+use backgammon::{
+    agents::{logic::LogicAgent, random::RandomAgent},
+    api::PlayerColor,
+    core::rules::Variant,
+    game::rng::GameRng,
+    net::{connect, serve, ClientMessage, ServerMessage},
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn listen() -> (TcpListener, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    (listener, addr)
+}
+
+#[test]
+fn test_two_bots_play_against_the_server() {
+    let (listener, addr) = listen();
+    // a short Hypergammon game, the clients only know the positions the server sends
+    let server =
+        thread::spawn(move || serve(&listener, &mut GameRng::from_seed(11), Variant::Hypergammon));
+
+    // the first client to join plays black, the second waits for it to be accepted
+    let black_addr = addr.clone();
+    let black = thread::spawn(move || {
+        connect(
+            black_addr,
+            "logic",
+            &LogicAgent::from_preset("hard").unwrap(),
+            &mut StdRng::seed_from_u64(1),
+            false,
+        )
+    });
+    thread::sleep(Duration::from_millis(100));
+    let red = thread::spawn(move || {
+        connect(
+            addr,
+            "random",
+            &RandomAgent::new(),
+            &mut StdRng::seed_from_u64(2),
+            false,
+        )
+    });
+
+    let record = server.join().unwrap().unwrap();
+    let black = black.join().unwrap().unwrap();
+    let red = red.join().unwrap().unwrap();
+
+    assert_eq!(black.color, PlayerColor::Black);
+    assert_eq!(red.color, PlayerColor::Red);
+    assert_eq!(black.winner, red.winner);
+    assert_eq!(black.points, red.points);
+
+    let winner = if black.winner == PlayerColor::Black {
+        -1
+    } else {
+        1
+    };
+    assert_eq!(record.winner, Some(winner));
+    assert_eq!(record.victory_type, Some(black.victory));
+    assert!(record.turns.iter().any(|turn| turn.move_sequence.is_some()));
+}
+
+struct RawClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl RawClient {
+    fn connect(addr: &str, name: &str) -> Self {
+        let writer = TcpStream::connect(addr).unwrap();
+        let mut client = Self {
+            reader: BufReader::new(writer.try_clone().unwrap()),
+            writer,
+        };
+        client.send(&ClientMessage::Hello {
+            name: name.to_string(),
+        });
+        client
+    }
+
+    fn send(&mut self, message: &ClientMessage) {
+        writeln!(self.writer, "{}", serde_json::to_string(message).unwrap()).unwrap();
+    }
+
+    fn receive(&mut self) -> ServerMessage {
+        let mut line = String::new();
+        self.reader.read_line(&mut line).unwrap();
+        serde_json::from_str(&line).unwrap()
+    }
+}
+
+#[test]
+fn test_the_server_refuses_illegal_plays() {
+    let (listener, addr) = listen();
+    let server =
+        thread::spawn(move || serve(&listener, &mut GameRng::from_seed(3), Variant::Standard));
+
+    let mut alice = RawClient::connect(&addr, "alice");
+    thread::sleep(Duration::from_millis(100));
+    let mut bob = RawClient::connect(&addr, "bob");
+    assert_eq!(
+        alice.receive(),
+        ServerMessage::Welcome {
+            color: PlayerColor::Black,
+            opponent: "bob".to_string(),
+            variant: Variant::Standard,
+        }
+    );
+    assert!(matches!(
+        bob.receive(),
+        ServerMessage::Welcome {
+            color: PlayerColor::Red,
+            ..
+        }
+    ));
+
+    let ServerMessage::Turn { turn, .. } = alice.receive() else {
+        panic!("expected the first turn");
+    };
+    assert!(matches!(bob.receive(), ServerMessage::Turn { .. }));
+    let player = if turn == PlayerColor::Black {
+        &mut alice
+    } else {
+        &mut bob
+    };
+
+    // moving a checker from an empty point is never legal
+    player.send(&ClientMessage::Play {
+        moves: "7/1".to_string(),
+    });
+    assert_eq!(
+        player.receive(),
+        ServerMessage::Error {
+            message: "'7/1' is not a legal play".to_string()
+        }
+    );
+
+    // the server gives up on a player leaving the game
+    drop(alice);
+    drop(bob);
+    assert!(server.join().unwrap().is_err());
+}